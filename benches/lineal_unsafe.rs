@@ -0,0 +1,94 @@
+use criterion::{criterion_group , criterion_main , Criterion , black_box};
+use data_structures::lineal::unsafety::vector::MyVec;
+use data_structures::lineal::unsafety::open_hash_map::{OpenHashMap, Probing};
+
+fn bench_push_my_vec_vs_std_vec(c : &mut Criterion){
+    c.bench_function("push 10_000 MyVec", |b: &mut criterion::Bencher|{
+        b.iter(||{
+            let mut vec : MyVec<i32> = MyVec::new();
+            for i in 0..10_000{
+                vec.push(black_box(i));
+            }
+            black_box(vec.len());
+        })
+    });
+    c.bench_function("push 10_000 Vec", |b: &mut criterion::Bencher|{
+        b.iter(||{
+            let mut vec : Vec<i32> = Vec::new();
+            for i in 0..10_000{
+                vec.push(black_box(i));
+            }
+            black_box(vec.len());
+        })
+    });
+}
+fn bench_insert_remove_my_vec_vs_std_vec(c : &mut Criterion){
+    c.bench_function("insert and remove front MyVec", |b: &mut criterion::Bencher|{
+        b.iter(||{
+            let mut vec : MyVec<i32> = MyVec::new();
+            for i in 0..1_000{
+                vec.insert(0, black_box(i));
+            }
+            while !vec.empty(){
+                black_box(vec.remove(0));
+            }
+        })
+    });
+    c.bench_function("insert and remove front Vec", |b: &mut criterion::Bencher|{
+        b.iter(||{
+            let mut vec : Vec<i32> = Vec::new();
+            for i in 0..1_000{
+                vec.insert(0, black_box(i));
+            }
+            while !vec.is_empty(){
+                black_box(vec.remove(0));
+            }
+        })
+    });
+}
+fn bench_iteration_my_vec_vs_std_vec(c : &mut Criterion){
+    let my_vec : MyVec<i64> = (0..10_000).collect();
+    let vec : Vec<i64> = (0..10_000).collect();
+    c.bench_function("sum 10_000 MyVec", |b: &mut criterion::Bencher|{
+        b.iter(|| black_box(my_vec.iter().sum::<i64>()))
+    });
+    c.bench_function("sum 10_000 Vec", |b: &mut criterion::Bencher|{
+        b.iter(|| black_box(vec.iter().sum::<i64>()))
+    });
+}
+fn bench_open_hash_map_probing(c : &mut Criterion){
+    let keys : Vec<u64> = (0..10_000u64).map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15)).collect();
+    for probing in [Probing::Linear , Probing::Quadratic , Probing::DoubleHashing , Probing::RobinHood]{
+        let mut map : OpenHashMap<u64, u64> = OpenHashMap::new(probing);
+        for &key in keys.iter(){
+            map.insert(key, key);
+        }
+        c.bench_function(&format!("successful lookups {:?}", probing), |b: &mut criterion::Bencher|{
+            b.iter(|| {
+                for key in keys.iter(){
+                    black_box(map.get(key));
+                }
+            })
+        });
+        c.bench_function(&format!("failed lookups {:?}", probing), |b: &mut criterion::Bencher|{
+            b.iter(|| {
+                for key in keys.iter(){
+                    black_box(map.get(&(key + 1)));
+                }
+            })
+        });
+        //Los contadores se reinician para reportar solo una pasada de cada tipo de busqueda
+        map.reset_stats();
+        keys.iter().for_each(|key| { map.get(key); });
+        let hits = map.stats();
+        map.reset_stats();
+        keys.iter().for_each(|key| { map.get(&(key + 1)); });
+        let misses = map.stats();
+        println!(
+            "{:?}: busquedas exitosas promedio {:.2} (max {}), fallidas promedio {:.2} (max {}), capacidad {}",
+            probing, hits.average_probe_length(), hits.max_probe_length, misses.average_probe_length(), misses.max_probe_length, map.capacity()
+        );
+    }
+}
+criterion_group!(bench , bench_push_my_vec_vs_std_vec , bench_insert_remove_my_vec_vs_std_vec , bench_iteration_my_vec_vs_std_vec , bench_open_hash_map_probing);
+criterion_main!(bench);
@@ -1,7 +1,14 @@
 use data_structures::no_lineal::safety::{
     binary_search_tree::BinarySearchTree,
-    avl_tree::AVLTree
+    avl_tree::AVLTree,
+    priority_queue::PriorityQueue,
+    binomial_heap::BinomialHeap,
+    pairing_heap::PairingHeap,
+    fibonacci_heap::FibonacciHeap,
+    b_tree::BTree,
+    b_plus_tree::BPlusTree
 };
+use data_structures::lineal::{safety::skip_list::SkipList, unsafety::skip_list::SkipList as RawSkipList};
 use criterion::{criterion_group , criterion_main , Criterion , black_box};
 use std::sync::{Arc , Mutex , MutexGuard};
 
@@ -68,6 +75,118 @@ fn bench_operation_recursive_deletion_avl(c : &mut Criterion){
         })
     });
 }
+fn bench_priority_queue<H : PriorityQueue<i64> + Default>(c : &mut Criterion , name : &str){
+    c.bench_function(&format!("push and pop_min {}", name), |b: &mut criterion::Bencher|{
+        b.iter(||{
+            let mut heap: H = H::default();
+            for i in 0..1_000{
+                heap.push(black_box((i * 7_919) % 1_000));
+            }
+            while let Some(value) = heap.pop_min(){
+                black_box(value);
+            }
+        })
+    });
+    c.bench_function(&format!("meld {}", name), |b: &mut criterion::Bencher|{
+        b.iter(||{
+            let mut heap: H = H::default();
+            for chunk in 0..32{
+                let mut other: H = H::default();
+                for i in 0..32{
+                    other.push(black_box(chunk * 32 + i));
+                }
+                heap.meld(other);
+            }
+            black_box(heap.pop_min());
+        })
+    });
+    c.bench_function(&format!("decrease_key {}", name), |b: &mut criterion::Bencher|{
+        b.iter(||{
+            let mut heap: H = H::default();
+            let handles: Vec<H::Handle> = (0..1_000).map(|i| heap.push(black_box(1_000_000 + i))).collect();
+            heap.pop_min();
+            for (i, handle) in handles.into_iter().enumerate().skip(1){
+                heap.decrease_key(handle, black_box(1_000 - i as i64)).unwrap();
+            }
+            black_box(heap.pop_min());
+        })
+    });
+}
+fn bench_mergeable_heaps(c : &mut Criterion){
+    bench_priority_queue::<BinomialHeap<i64>>(c, "binomial heap");
+    bench_priority_queue::<PairingHeap<i64>>(c, "pairing heap");
+    bench_priority_queue::<FibonacciHeap<i64>>(c, "fibonacci heap");
+}
+fn bench_skip_lists_vs_avl(c : &mut Criterion){
+    //Valores desordenados para que la skip list no se beneficie de insertar siempre al final
+    let values: Vec<i32> = (0..1_000).map(|i| (i * 7_919) % 1_000).collect();
+    c.bench_function("insert_node AVL", |b: &mut criterion::Bencher|{
+        b.iter(||{
+            let mut avl_tree: AVLTree<i32> = AVLTree::new();
+            for &value in &values{
+                avl_tree.insert_node(black_box(value));
+            }
+            black_box(avl_tree.len());
+        })
+    });
+    c.bench_function("insert skip list safe", |b: &mut criterion::Bencher|{
+        b.iter(||{
+            let mut list: SkipList<i32> = SkipList::with_seed(1);
+            for &value in &values{
+                list.insert(black_box(value));
+            }
+            black_box(list.len());
+        })
+    });
+    c.bench_function("insert skip list unsafe", |b: &mut criterion::Bencher|{
+        b.iter(||{
+            let mut list: RawSkipList<i32> = RawSkipList::with_seed(1);
+            for &value in &values{
+                list.insert(black_box(value));
+            }
+            black_box(list.len());
+        })
+    });
+}
+fn bench_b_tree<const B : usize>(c : &mut Criterion , values : &[i32]){
+    c.bench_function(&format!("insert BTree orden {}", B), |b: &mut criterion::Bencher|{
+        b.iter(||{
+            let mut tree: BTree<i32, B> = BTree::new();
+            for &value in values{
+                tree.insert(black_box(value));
+            }
+            black_box(tree.height());
+        })
+    });
+    c.bench_function(&format!("insert BPlusTree orden {}", B), |b: &mut criterion::Bencher|{
+        b.iter(||{
+            let mut tree: BPlusTree<i32, (), B> = BPlusTree::new();
+            for &value in values{
+                tree.insert(black_box(value), ());
+            }
+            black_box(tree.height());
+        })
+    });
+}
+///El mismo conjunto de valores con distintos ordenes, para ver el efecto del fan-out contra el AVL de un valor por nodo.
+fn bench_b_trees_fan_out(c : &mut Criterion){
+    let values: Vec<i32> = (0..10_000).map(|i| (i * 7_919) % 10_000).collect();
+    c.bench_function("insert_node AVL 10_000", |b: &mut criterion::Bencher|{
+        b.iter(||{
+            let mut avl_tree: AVLTree<i32> = AVLTree::new();
+            for &value in &values{
+                avl_tree.insert_node(black_box(value));
+            }
+            black_box(avl_tree.len());
+        })
+    });
+    bench_b_tree::<4>(c, &values);
+    bench_b_tree::<16>(c, &values);
+    bench_b_tree::<64>(c, &values);
+}
 criterion_group!(bench , bench_insertion_operation_recursive_bst,bench_deletion_operations_recursives_bst);
 criterion_group!(benching , bench_operation_recursive_insertion_avl , bench_operation_recursive_deletion_avl);
-criterion_main!(benching);
+criterion_group!(heaps , bench_mergeable_heaps);
+criterion_group!(skip_lists , bench_skip_lists_vs_avl);
+criterion_group!(b_trees , bench_b_trees_fan_out);
+criterion_main!(benching , heaps , skip_lists , b_trees);
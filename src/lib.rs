@@ -119,12 +119,44 @@ pub mod lineal{
         pub mod double_linked_list;
         /// Una implementación safe de un stack!!
         pub mod stack;
+        ///Una cache LRU construida sobre la lista doblemente enlazada!!
+        pub mod lru_cache;
+        ///Una implementación safe de una cola doble sobre la lista doblemente enlazada!!
+        pub mod deque;
+        ///Una implementación safe de una cola FIFO!!
+        pub mod queue;
+        ///Un buffer circular de capacidad fija en tiempo de compilación!!
+        pub mod ring_buffer;
+        ///Una tabla hash con encadenamiento sobre los nodos de la lista simplemente enlazada!!
+        pub mod chained_hash_map;
+        ///El trait común de los conjuntos, lo comparten los `HashSet` y el `AVLTree`!!
+        pub mod set;
+        ///Un conjunto por hash sobre la tabla con encadenamiento!!
+        pub mod hash_set;
+        ///Una skip list sobre una arena de nodos indexados!!
+        pub mod skip_list;
     }
     pub mod unsafety{
         pub mod linked_list;
         pub mod double_linked_list;
         pub mod stack;
+        ///Una cola doble sobre un buffer circular con reserva manual de memoria!!
+        pub mod deque;
+        ///Una cola FIFO sobre el buffer circular!!
+        pub mod queue;
+        ///Un buffer circular de capacidad fija reservado en el heap!!
+        pub mod ring_buffer;
+        ///Un vector dinamico construido desde cero con `std::alloc`!!
+        pub mod vector;
+        ///Una tabla hash de direccionamiento abierto con distintas estrategias de sondeo!!
+        pub mod open_hash_map;
+        ///Un conjunto por hash sobre la tabla de direccionamiento abierto!!
+        pub mod hash_set;
+        ///Una skip list con torres de punteros crudos!!
+        pub mod skip_list;
     }
+    #[cfg(test)]
+    pub(crate) mod queue_tests;
 }
 /// Un modulo netamente enfocado a estructuras de datos que no son lineales!
 pub mod no_lineal{
@@ -210,10 +242,66 @@ pub mod no_lineal{
         pub mod avl_tree;
         ///Una implementación safe de un red black tree
         pub mod rb_tree;
+        ///Un montículo binario de minimos o maximos con una variante indexada
+        pub mod binary_heap;
+        ///El trait común de los montículos fusionables
+        pub mod priority_queue;
+        ///Una implementación safe de un montículo binomial
+        pub mod binomial_heap;
+        ///Una implementación safe de un pairing heap
+        pub mod pairing_heap;
+        ///Una implementación safe de un montículo de Fibonacci
+        pub mod fibonacci_heap;
+        ///Una implementación safe de un splay tree con splay top-down
+        pub mod splay_tree;
+        ///Un treap con split y merge, y su variante de clave implicita
+        pub mod treap;
+        ///Un arbol B de orden configurable en tiempo de compilación
+        pub mod b_tree;
+        ///Un arbol B+ con las hojas enlazadas para recorridos por rango
+        pub mod b_plus_tree;
+        ///Un grafo dirigido o no dirigido con IDs estables y representación intercambiable
+        pub mod graph;
+        ///Representación de un grafo como lista de adyacencia
+        pub mod adjacency_list;
+        ///Representación de un grafo como matriz de adyacencia
+        pub mod adjacency_matrix;
+        ///Recorridos en anchura y profundidad sobre `Graph`
+        pub mod graph_traversal;
+        ///Caminos mínimos: Dijkstra, Bellman-Ford, Floyd-Warshall y A*
+        pub mod shortest_paths;
+        ///Orden topológico, componentes, arboles de expansión, puentes y bipartición
+        pub mod graph_algorithms;
+        ///Flujo máximo con corte mínimo y emparejamiento bipartito máximo
+        pub mod max_flow;
+        ///Conjuntos disjuntos (union-find) con compresión de caminos, claves genéricas y uniones reversibles
+        pub mod disjoint_set;
+        ///Un trie (arbol de prefijos) de cadenas a valores
+        pub mod trie;
+        ///Un trie comprimido con aristas de varios caracteres
+        pub mod radix_tree;
+        ///Un segment tree genérico sobre un monoide, con una variante perezosa para actualizaciones de rango
+        pub mod segment_tree;
+        ///Un arbol de Fenwick para sumas de prefijos
+        pub mod fenwick_tree;
+        ///Una sparse table para consultas de rango en O(1) sobre operaciones idempotentes
+        pub mod sparse_table;
+        ///Un arbol cartesiano que responde LCA y mínimos de rango
+        pub mod cartesian_tree;
+        ///Un arbol de intervalos sobre el AVL para consultas de solapamiento
+        pub mod interval_tree;
+        ///Un k-d tree para búsquedas de vecinos y rangos en varias dimensiones
+        pub mod kd_tree;
     }
     pub mod unsafety{
         pub mod binary_search_tree;
         pub mod avl_tree;
         pub mod rb_tree;
+        ///Un montículo de Fibonacci con punteros crudos
+        pub mod fibonacci_heap;
     }
-}
\ No newline at end of file
+    #[cfg(test)]
+    pub(crate) mod priority_queue_tests;
+}
+///Generador pseudoaleatorio reproducible que comparten las estructuras aleatorizadas
+pub(crate) mod random;
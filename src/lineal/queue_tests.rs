@@ -0,0 +1,150 @@
+//! Pruebas compartidas por las colas y buffers circulares de `safety` y `unsafety`, cada implementación invoca la macro
+//! dentro de su propio modulo de pruebas para verificar el mismo comportamiento sin importar cómo almacena sus elementos.
+use std::borrow::Borrow;
+
+///Normaliza lo que entrega un iterador (`T` en la version safe, `&T` en la unsafe) a un `Vec<i32>`.
+pub(crate) fn values<I>(iter : I) -> Vec<i32>
+where I : Iterator, I::Item : Borrow<i32>{
+    iter.map(|value| *value.borrow()).collect()
+}
+
+///Pruebas de orden FIFO para cualquier estructura con capacidad para al menos 64 elementos.
+/// - `new`: expresión que construye la estructura vacia, por ejemplo `Queue::new`.
+/// - `push` y `pop`: nombres de los metodos que insertan al final y eliminan del frente.
+///
+///Además la estructura debe exponer `front`, `back`, `len`, `empty`, `iter` e `IntoIterator`.
+macro_rules! fifo_tests {
+    (new: $new:expr, push: $push:ident, pop: $pop:ident) => {
+        #[test]
+        fn fifo_order(){
+            let mut queue = $new();
+            assert!(queue.empty());
+            for i in 1..=5{
+                let _ = queue.$push(i);
+            }
+            assert_eq!(queue.len() , 5);
+            for i in 1..=5{
+                assert_eq!(queue.$pop() , Some(i));
+            }
+            assert_eq!(queue.$pop() , None);
+            assert!(queue.empty());
+        }
+        #[test]
+        fn front_and_back(){
+            let mut queue = $new();
+            assert!(queue.front().is_none());
+            assert!(queue.back().is_none());
+            let _ = queue.$push(10);
+            let _ = queue.$push(20);
+            assert_eq!(*queue.front().unwrap() , 10);
+            assert_eq!(*queue.back().unwrap() , 20);
+            queue.$pop();
+            assert_eq!(*queue.front().unwrap() , 20);
+            assert_eq!(*queue.back().unwrap() , 20);
+        }
+        #[test]
+        fn fifo_order_while_wrapping(){
+            let mut queue = $new();
+            let mut expected : i32 = 0;
+            for i in 0..200{
+                let _ = queue.$push(i);
+                //Mantener pocos elementos vivos hace que el frente de la vuelta varias veces
+                if queue.len() > 10{
+                    assert_eq!(queue.$pop() , Some(expected));
+                    expected += 1;
+                }
+            }
+            assert_eq!($crate::lineal::queue_tests::values(queue.iter()) , (expected..200).collect::<Vec<i32>>());
+            assert_eq!($crate::lineal::queue_tests::values(queue.iter().rev()) , (expected..200).rev().collect::<Vec<i32>>());
+        }
+        #[test]
+        fn iteration_in_fifo_order(){
+            let mut queue = $new();
+            for i in [3 , 1 , 2]{
+                let _ = queue.$push(i);
+            }
+            assert_eq!($crate::lineal::queue_tests::values(queue.iter()) , vec![3 , 1 , 2]);
+            assert_eq!(queue.into_iter().collect::<Vec<i32>>() , vec![3 , 1 , 2]);
+        }
+        #[test]
+        fn owned_values_are_dropped(){
+            let mut queue = $new();
+            for i in 0..50{
+                let _ = queue.$push(i.to_string());
+            }
+            assert_eq!(queue.$pop() , Some(String::from("0")));
+            assert_eq!(*queue.back().unwrap() , "49");
+        }
+    };
+}
+pub(crate) use fifo_tests;
+
+///Pruebas de una cola FIFO de crecimiento dinamico, el tipo debe exponer `new`, `with_capacity`, `push_back`, `pop_front`
+///y `FromIterator` además de lo requerido por `fifo_tests`.
+macro_rules! queue_tests {
+    ($queue:ident) => {
+        $crate::lineal::queue_tests::fifo_tests!(new: $queue::new, push: push_back, pop: pop_front);
+        #[test]
+        fn grows_past_capacity_while_wrapping(){
+            let mut queue : $queue<i32> = $queue::with_capacity(2);
+            let mut expected : i32 = 0;
+            for i in 0..100{
+                queue.push_back(i);
+                //Eliminar de a uno cada tres inserciones hace que el frente avance mientras la cola crece
+                if i % 3 == 0{
+                    assert_eq!(queue.pop_front() , Some(expected));
+                    expected += 1;
+                }
+            }
+            assert_eq!($crate::lineal::queue_tests::values(queue.iter()) , (expected..100).collect::<Vec<i32>>());
+            assert_eq!(queue.len() , (100 - expected) as usize);
+        }
+        #[test]
+        fn collect_from_iterator(){
+            let collected : $queue<i32> = (1..=3).collect();
+            assert_eq!(collected.len() , 3);
+            assert_eq!(collected.into_iter().collect::<Vec<i32>>() , vec![1 , 2 , 3]);
+        }
+    };
+}
+pub(crate) use queue_tests;
+
+///Pruebas de una cola doble, además de las de `queue_tests` el tipo debe exponer `push_front` y `pop_back`.
+macro_rules! deque_tests {
+    ($deque:ident) => {
+        $crate::lineal::queue_tests::queue_tests!($deque);
+        #[test]
+        fn both_ends(){
+            let mut deque : $deque<i32> = $deque::new();
+            deque.push_back(2);
+            deque.push_front(1);
+            deque.push_back(3);
+            deque.push_front(0);
+            assert_eq!($crate::lineal::queue_tests::values(deque.iter()) , vec![0 , 1 , 2 , 3]);
+            assert_eq!($crate::lineal::queue_tests::values(deque.iter().rev()) , vec![3 , 2 , 1 , 0]);
+            assert_eq!(deque.pop_back() , Some(3));
+            assert_eq!(deque.pop_front() , Some(0));
+            assert_eq!(deque.pop_back() , Some(2));
+            assert_eq!(deque.pop_back() , Some(1));
+            assert_eq!(deque.pop_back() , None);
+        }
+        #[test]
+        fn matches_std_vec_deque(){
+            let mut deque : $deque<i32> = $deque::with_capacity(3);
+            let mut model : std::collections::VecDeque<i32> = std::collections::VecDeque::new();
+            let mut seed : i32 = 17;
+            for i in 0..1_000{
+                seed = (seed * 1_103 + 12_345) % 1_009;
+                match seed % 4 {
+                    0 => { deque.push_back(i); model.push_back(i); },
+                    1 => { deque.push_front(i); model.push_front(i); },
+                    2 => assert_eq!(deque.pop_front() , model.pop_front()),
+                    _ => assert_eq!(deque.pop_back() , model.pop_back())
+                }
+                assert_eq!(deque.len() , model.len());
+            }
+            assert_eq!($crate::lineal::queue_tests::values(deque.iter()) , model.into_iter().collect::<Vec<i32>>());
+        }
+    };
+}
+pub(crate) use deque_tests;
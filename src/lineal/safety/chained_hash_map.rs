@@ -0,0 +1,552 @@
+//! ### Sobre las tablas hash con encadenamiento (Separate Chaining)
+//! Una tabla hash guarda pares clave-valor en un arreglo de buckets, el bucket de una clave se obtiene de su hash.
+//! Cuando dos claves caen en el mismo bucket (colisión) ambas se guardan en una cadena de `NodeLink`, la misma
+//! estructura de nodos de `SinglyLinkedList`.
+//! ```text
+//!    buckets
+//!   +-------+       ---------------          ---------------
+//!   |   0   | ---> | ("a",1) | NEXT | ---> | ("q",7) | NEXT | ---> None
+//!   +-------+       ---------------          ---------------
+//!   |   1   | ---> None
+//!   +-------+       ---------------
+//!   |   2   | ---> | ("c",3) | NEXT | ---> None
+//!   +-------+       ---------------
+//!   |  ...  |
+//! ```
+//! El factor de carga es `len / buckets`, cuando una inserción lo dejaría sobre `MAX_LOAD_FACTOR` la cantidad de buckets
+//! se duplica y cada nodo se mueve a su nuevo bucket sin volver a reservarlo, así las cadenas se mantienen cortas y las
+//! operaciones cuestan O(1) en promedio.
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::fmt::{self, Debug};
+use std::hash::{BuildHasher, Hash};
+use std::slice;
+use std::vec;
+use super::singly_linked_list::NodeLink;
+
+type Bucket<K, V> = Option<Box<NodeLink<(K, V)>>>;
+///### ChainedHashMap
+/// Tabla hash con encadenamiento, el `BuildHasher` es configurable igual que en `std::collections::HashMap`.
+pub struct ChainedHashMap<K, V, S = RandomState>{
+    buckets : Vec<Bucket<K, V>>,
+    len : usize,
+    hash_builder : S
+}
+impl<K, V> ChainedHashMap<K, V, RandomState>{
+    pub fn new() -> Self{
+        Self::with_hasher(RandomState::new())
+    }
+    ///Reserva buckets suficientes para `capacity` pares sin superar el factor de carga.
+    pub fn with_capacity(capacity : usize) -> Self{
+        Self::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+}
+impl<K, V, S : Default> Default for ChainedHashMap<K, V, S>{
+    fn default() -> Self{
+        Self::with_hasher(S::default())
+    }
+}
+impl<K, V, S> ChainedHashMap<K, V, S>{
+    const MIN_BUCKETS : usize = 8;
+    ///Factor de carga maximo expresado como fraccion (3/4).
+    const MAX_LOAD_FACTOR : (usize, usize) = (3, 4);
+    pub fn with_hasher(hash_builder : S) -> Self{
+        Self{ buckets : Vec::new(), len : 0, hash_builder }
+    }
+    pub fn with_capacity_and_hasher(capacity : usize , hash_builder : S) -> Self{
+        let mut map: ChainedHashMap<K, V, S> = Self::with_hasher(hash_builder);
+        if capacity > 0{
+            map.buckets = Self::empty_buckets(Self::buckets_for(capacity));
+        }
+        map
+    }
+    pub fn len(&self) -> usize{
+        self.len
+    }
+    pub fn empty(&self) -> bool{
+        self.len == 0
+    }
+    ///Cantidad de buckets reservados.
+    pub fn buckets(&self) -> usize{
+        self.buckets.len()
+    }
+    pub fn load_factor(&self) -> f64{
+        if self.buckets.is_empty(){
+            return 0.0;
+        }
+        self.len as f64 / self.buckets.len() as f64
+    }
+    pub fn hasher(&self) -> &S{
+        &self.hash_builder
+    }
+    ///Largo de la cadena más larga, sirve para observar la calidad del hasher.
+    pub fn longest_chain(&self) -> usize{
+        self.buckets.iter().map(|bucket| {
+            let mut length: usize = 0;
+            let mut current: Option<&NodeLink<(K, V)>> = bucket.as_deref();
+            while let Some(node) = current{
+                length += 1;
+                current = node.next();
+            }
+            length
+        }).max().unwrap_or(0)
+    }
+    pub fn clear(&mut self){
+        for bucket in self.buckets.iter_mut(){
+            Self::drop_chain(bucket.take());
+        }
+        self.len = 0;
+    }
+    pub fn iter(&self) -> Iter<'_, K, V>{
+        Iter{ buckets : self.buckets.iter(), current : None, remaining : self.len }
+    }
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V>{
+        IterMut{ buckets : self.buckets.iter_mut(), current : None, remaining : self.len }
+    }
+    pub fn keys(&self) -> impl Iterator<Item = &K>{
+        self.iter().map(|(key, _)| key)
+    }
+    pub fn values(&self) -> impl Iterator<Item = &V>{
+        self.iter().map(|(_, value)| value)
+    }
+    fn empty_buckets(count : usize) -> Vec<Bucket<K, V>>{
+        (0..count).map(|_| None).collect()
+    }
+    ///Menor potencia de dos de buckets que guarda `len` pares sin superar el factor de carga.
+    fn buckets_for(len : usize) -> usize{
+        let (numerator, denominator): (usize, usize) = Self::MAX_LOAD_FACTOR;
+        (len * denominator).div_ceil(numerator).next_power_of_two().max(Self::MIN_BUCKETS)
+    }
+    ///Libera una cadena de manera iterativa, una cadena larga (un mal hasher) desbordaría el stack al liberarse recursivamente.
+    fn drop_chain(mut current : Bucket<K, V>){
+        while let Some(node) = current{
+            current = node.into_parts().1;
+        }
+    }
+}
+impl<K : Hash + Eq, V, S : BuildHasher> ChainedHashMap<K, V, S>{
+    fn bucket_of<Q>(&self , key : &Q) -> usize
+    where Q : Hash + ?Sized{
+        //La cantidad de buckets es potencia de dos, la mascara equivale al modulo
+        (self.hash_builder.hash_one(key) as usize) & (self.buckets.len() - 1)
+    }
+    fn find<Q>(&self , key : &Q) -> Option<&NodeLink<(K, V)>>
+    where K : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        if self.buckets.is_empty(){
+            return None;
+        }
+        let mut current: Option<&NodeLink<(K, V)>> = self.buckets[self.bucket_of(key)].as_deref();
+        while let Some(node) = current{
+            if node.value().0.borrow() == key{
+                return Some(node);
+            }
+            current = node.next();
+        }
+        None
+    }
+    fn find_mut<Q>(&mut self , key : &Q) -> Option<&mut NodeLink<(K, V)>>
+    where K : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        if self.buckets.is_empty(){
+            return None;
+        }
+        let index: usize = self.bucket_of(key);
+        let mut current: Option<&mut NodeLink<(K, V)>> = self.buckets[index].as_deref_mut();
+        while let Some(node) = current{
+            if node.value().0.borrow() == key{
+                return Some(node);
+            }
+            current = node.next_mut().as_deref_mut();
+        }
+        None
+    }
+    ///### Resize
+    /// Duplica los buckets si insertar un par más superaría el factor de carga, los nodos se mueven a la cabeza de su
+    /// nuevo bucket.
+    fn reserve_one(&mut self){
+        let (numerator, denominator): (usize, usize) = Self::MAX_LOAD_FACTOR;
+        if (self.len + 1) * denominator <= self.buckets.len() * numerator{
+            return;
+        }
+        let new_count: usize = (self.buckets.len() * 2).max(Self::MIN_BUCKETS);
+        let old: Vec<Bucket<K, V>> = std::mem::replace(&mut self.buckets, Self::empty_buckets(new_count));
+        for mut current in old{
+            while let Some(mut node) = current{
+                current = node.next_mut().take();
+                let index: usize = self.bucket_of(&node.value().0);
+                *node.next_mut() = self.buckets[index].take();
+                self.buckets[index] = Some(node);
+            }
+        }
+    }
+    ///Enlaza un par nuevo a la cabeza de su bucket, la clave no debe existir.
+    fn push_new(&mut self , key : K , value : V) -> &mut V{
+        self.reserve_one();
+        let index: usize = self.bucket_of(&key);
+        let next: Bucket<K, V> = self.buckets[index].take();
+        self.buckets[index] = Some(Box::new(NodeLink::new((key, value), next)));
+        self.len += 1;
+        &mut self.buckets[index].as_mut().expect("El bucket recien enlazado tiene nodo").value_mut().1
+    }
+    ///Inserta el par, si la clave ya existía reemplaza su valor y retorna el anterior.
+    pub fn insert(&mut self , key : K , value : V) -> Option<V>{
+        if let Some(node) = self.find_mut(&key){
+            return Some(std::mem::replace(&mut node.value_mut().1, value));
+        }
+        self.push_new(key, value);
+        None
+    }
+    pub fn get<Q>(&self , key : &Q) -> Option<&V>
+    where K : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        self.find(key).map(|node| &node.value().1)
+    }
+    pub fn get_mut<Q>(&mut self , key : &Q) -> Option<&mut V>
+    where K : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        self.find_mut(key).map(|node| &mut node.value_mut().1)
+    }
+    pub fn contains_key<Q>(&self , key : &Q) -> bool
+    where K : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        self.find(key).is_some()
+    }
+    ///Desenlaza el nodo de la clave y retorna su valor.
+    pub fn remove<Q>(&mut self , key : &Q) -> Option<V>
+    where K : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+    pub fn remove_entry<Q>(&mut self , key : &Q) -> Option<(K, V)>
+    where K : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        if self.buckets.is_empty(){
+            return None;
+        }
+        let index: usize = self.bucket_of(key);
+        let mut link: &mut Bucket<K, V> = &mut self.buckets[index];
+        while link.as_ref().is_some_and(|node| node.value().0.borrow() != key){
+            link = link.as_mut().expect("El enlace fue verificado").next_mut();
+        }
+        let (pair, next): ((K, V), Bucket<K, V>) = link.take()?.into_parts();
+        *link = next;
+        self.len -= 1;
+        Some(pair)
+    }
+    ///### Entry
+    /// Acceso al lugar de una clave para consultarla o insertarla con una sola llamada.
+    /// ```text
+    ///   *map.entry("a").or_insert(0) += 1;
+    /// ```
+    pub fn entry(&mut self , key : K) -> Entry<'_, K, V, S>{
+        if self.find(&key).is_some(){
+            let node: &mut NodeLink<(K, V)> = self.find_mut(&key).expect("La clave fue encontrada");
+            Entry::Occupied(OccupiedEntry{ node })
+        }else {
+            Entry::Vacant(VacantEntry{ map : self, key })
+        }
+    }
+}
+impl<K, V, S> Drop for ChainedHashMap<K, V, S>{
+    fn drop(&mut self){
+        for bucket in self.buckets.iter_mut(){
+            Self::drop_chain(bucket.take());
+        }
+    }
+}
+impl<K : Debug, V : Debug, S> Debug for ChainedHashMap<K, V, S>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+impl<K : Hash + Eq, V, S : BuildHasher + Default> FromIterator<(K, V)> for ChainedHashMap<K, V, S>{
+    fn from_iter<I : IntoIterator<Item = (K, V)>>(iter : I) -> Self{
+        let mut map: ChainedHashMap<K, V, S> = Self::with_hasher(S::default());
+        map.extend(iter);
+        map
+    }
+}
+impl<K : Hash + Eq, V, S : BuildHasher> Extend<(K, V)> for ChainedHashMap<K, V, S>{
+    fn extend<I : IntoIterator<Item = (K, V)>>(&mut self , iter : I){
+        for (key, value) in iter{
+            self.insert(key, value);
+        }
+    }
+}
+///Lugar de una clave dentro del mapa, ver `ChainedHashMap::entry`.
+pub enum Entry<'a, K, V, S>{
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V, S>)
+}
+pub struct OccupiedEntry<'a, K, V>{
+    node : &'a mut NodeLink<(K, V)>
+}
+pub struct VacantEntry<'a, K, V, S>{
+    map : &'a mut ChainedHashMap<K, V, S>,
+    key : K
+}
+impl<'a, K : Hash + Eq, V, S : BuildHasher> Entry<'a, K, V, S>{
+    pub fn key(&self) -> &K{
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => &entry.key
+        }
+    }
+    pub fn or_insert(self , default : V) -> &'a mut V{
+        self.or_insert_with(|| default)
+    }
+    pub fn or_insert_with<F : FnOnce() -> V>(self , default : F) -> &'a mut V{
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default())
+        }
+    }
+    pub fn or_default(self) -> &'a mut V
+    where V : Default{
+        self.or_insert_with(V::default)
+    }
+    pub fn and_modify<F : FnOnce(&mut V)>(mut self , modify : F) -> Self{
+        if let Entry::Occupied(entry) = &mut self{
+            modify(entry.get_mut());
+        }
+        self
+    }
+}
+impl<'a, K, V> OccupiedEntry<'a, K, V>{
+    pub fn key(&self) -> &K{
+        &self.node.value().0
+    }
+    pub fn get(&self) -> &V{
+        &self.node.value().1
+    }
+    pub fn get_mut(&mut self) -> &mut V{
+        &mut self.node.value_mut().1
+    }
+    pub fn into_mut(self) -> &'a mut V{
+        &mut self.node.value_mut().1
+    }
+    ///Reemplaza el valor y retorna el anterior.
+    pub fn insert(&mut self , value : V) -> V{
+        std::mem::replace(self.get_mut(), value)
+    }
+}
+impl<'a, K : Hash + Eq, V, S : BuildHasher> VacantEntry<'a, K, V, S>{
+    pub fn key(&self) -> &K{
+        &self.key
+    }
+    pub fn insert(self , value : V) -> &'a mut V{
+        self.map.push_new(self.key, value)
+    }
+}
+///Iterador por referencia, recorre los buckets en orden y cada cadena desde su cabeza.
+pub struct Iter<'a, K, V>{
+    buckets : slice::Iter<'a, Bucket<K, V>>,
+    current : Option<&'a NodeLink<(K, V)>>,
+    remaining : usize
+}
+impl<'a, K, V> Iterator for Iter<'a, K, V>{
+    type Item = (&'a K, &'a V);
+    fn next(&mut self) -> Option<Self::Item>{
+        while self.current.is_none(){
+            self.current = self.buckets.next()?.as_deref();
+        }
+        let node: &'a NodeLink<(K, V)> = self.current?;
+        self.current = node.next();
+        self.remaining -= 1;
+        Some((&node.value().0, &node.value().1))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>){
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<K, V> ExactSizeIterator for Iter<'_, K, V>{}
+pub struct IterMut<'a, K, V>{
+    buckets : slice::IterMut<'a, Bucket<K, V>>,
+    current : Option<&'a mut NodeLink<(K, V)>>,
+    remaining : usize
+}
+impl<'a, K, V> Iterator for IterMut<'a, K, V>{
+    type Item = (&'a K, &'a mut V);
+    fn next(&mut self) -> Option<Self::Item>{
+        while self.current.is_none(){
+            self.current = self.buckets.next()?.as_deref_mut();
+        }
+        let (pair, next) = self.current.take()?.parts_mut();
+        self.current = next;
+        self.remaining -= 1;
+        Some((&pair.0, &mut pair.1))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>){
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V>{}
+///Iterador que consume el mapa.
+pub struct IntoIter<K, V>{
+    buckets : vec::IntoIter<Bucket<K, V>>,
+    current : Bucket<K, V>,
+    remaining : usize
+}
+impl<K, V> Iterator for IntoIter<K, V>{
+    type Item = (K, V);
+    fn next(&mut self) -> Option<(K, V)>{
+        while self.current.is_none(){
+            self.current = self.buckets.next()?;
+        }
+        let (pair, next): ((K, V), Bucket<K, V>) = self.current.take()?.into_parts();
+        self.current = next;
+        self.remaining -= 1;
+        Some(pair)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>){
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<K, V> ExactSizeIterator for IntoIter<K, V>{}
+impl<K, V> Drop for IntoIter<K, V>{
+    fn drop(&mut self){
+        //Las cadenas pendientes se liberan de manera iterativa igual que en el mapa
+        while self.next().is_some(){}
+    }
+}
+impl<K, V, S> IntoIterator for ChainedHashMap<K, V, S>{
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+    fn into_iter(mut self) -> IntoIter<K, V>{
+        let remaining: usize = self.len;
+        self.len = 0;
+        IntoIter{ buckets : std::mem::take(&mut self.buckets).into_iter(), current : None, remaining }
+    }
+}
+impl<'a, K, V, S> IntoIterator for &'a ChainedHashMap<K, V, S>{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+    fn into_iter(self) -> Iter<'a, K, V>{
+        self.iter()
+    }
+}
+impl<'a, K, V, S> IntoIterator for &'a mut ChainedHashMap<K, V, S>{
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+    fn into_iter(self) -> IterMut<'a, K, V>{
+        self.iter_mut()
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use std::collections::HashMap;
+    use std::hash::{BuildHasherDefault, Hasher};
+
+    ///Hasher que envia todas las claves al mismo bucket para forzar colisiones.
+    #[derive(Default)]
+    struct ConstantHasher;
+    impl Hasher for ConstantHasher{
+        fn finish(&self) -> u64{
+            0
+        }
+        fn write(&mut self , _bytes : &[u8]){}
+    }
+    type Colliding = BuildHasherDefault<ConstantHasher>;
+
+    #[test]
+    fn insert_get_and_replace(){
+        let mut map : ChainedHashMap<String, i32> = ChainedHashMap::new();
+        assert!(map.empty());
+        assert_eq!(map.insert(String::from("a"), 1) , None);
+        assert_eq!(map.insert(String::from("b"), 2) , None);
+        assert_eq!(map.insert(String::from("a"), 10) , Some(1));
+        assert_eq!(map.len() , 2);
+        //Borrow permite consultar con &str
+        assert_eq!(map.get("a") , Some(&10));
+        assert_eq!(map.get("z") , None);
+        *map.get_mut("b").unwrap() += 5;
+        assert_eq!(map.get("b") , Some(&7));
+        assert!(map.contains_key("b"));
+    }
+    #[test]
+    fn resize_keeps_load_factor_and_entries(){
+        let mut map : ChainedHashMap<i32, i32> = ChainedHashMap::new();
+        for i in 0..10_000{
+            map.insert(i, i * 2);
+            assert!(map.load_factor() <= 0.75);
+        }
+        assert_eq!(map.buckets() , 16_384);
+        assert!((0..10_000).all(|i| map.get(&i) == Some(&(i * 2))));
+        let presized : ChainedHashMap<i32, i32> = ChainedHashMap::with_capacity(12);
+        assert_eq!(presized.buckets() , 16);
+    }
+    #[test]
+    fn remove_from_every_position_of_a_chain(){
+        let mut map : ChainedHashMap<i32, &str, Colliding> = ChainedHashMap::default();
+        for (key, value) in [(1 , "a") , (2 , "b") , (3 , "c") , (4 , "d")]{
+            map.insert(key, value);
+        }
+        assert_eq!(map.longest_chain() , 4);
+        //La cabeza de la cadena es el ultimo insertado
+        assert_eq!(map.remove(&4) , Some("d"));
+        assert_eq!(map.remove(&2) , Some("b"));
+        assert_eq!(map.remove(&1) , Some("a"));
+        assert_eq!(map.remove(&1) , None);
+        assert_eq!(map.remove_entry(&3) , Some((3 , "c")));
+        assert!(map.empty());
+    }
+    #[test]
+    fn entry_api(){
+        let mut counts : ChainedHashMap<&str, usize> = ChainedHashMap::new();
+        for word in "la casa la puerta la casa".split(' '){
+            *counts.entry(word).or_insert(0) += 1;
+        }
+        assert_eq!(counts.get("la") , Some(&3));
+        assert_eq!(counts.get("casa") , Some(&2));
+        counts.entry("puerta").and_modify(|count| *count *= 10).or_default();
+        counts.entry("ventana").and_modify(|count| *count *= 10).or_default();
+        assert_eq!(counts.get("puerta") , Some(&10));
+        assert_eq!(counts.get("ventana") , Some(&0));
+        if let Entry::Occupied(mut entry) = counts.entry("casa"){
+            assert_eq!(entry.insert(7) , 2);
+        }
+        assert_eq!(counts.entry("casa").key() , &"casa");
+        assert_eq!(counts.get("casa") , Some(&7));
+    }
+    #[test]
+    fn iteration_visits_every_pair_once(){
+        let mut map : ChainedHashMap<i32, i32> = (0..100).map(|i| (i, i)).collect();
+        for (_, value) in map.iter_mut(){
+            *value += 1;
+        }
+        let mut pairs : Vec<(i32, i32)> = map.iter().map(|(key, value)| (*key, *value)).collect();
+        pairs.sort();
+        assert_eq!(pairs , (0..100).map(|i| (i, i + 1)).collect::<Vec<(i32, i32)>>());
+        assert_eq!(map.iter().len() , 100);
+        assert_eq!(map.keys().count() , 100);
+        assert_eq!(map.values().sum::<i32>() , 5_050);
+        let mut owned : Vec<(i32, i32)> = map.into_iter().collect();
+        owned.sort();
+        assert_eq!(owned.len() , 100);
+    }
+    #[test]
+    fn matches_std_hash_map(){
+        let mut map : ChainedHashMap<u64, u64> = ChainedHashMap::new();
+        let mut model : HashMap<u64, u64> = HashMap::new();
+        let mut seed : u64 = 42;
+        for step in 0..5_000u64{
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            let key : u64 = (seed >> 33) % 500;
+            match step % 3 {
+                0 | 1 => assert_eq!(map.insert(key, step) , model.insert(key, step)),
+                _ => assert_eq!(map.remove(&key) , model.remove(&key))
+            }
+            assert_eq!(map.len() , model.len());
+        }
+        assert!(model.iter().all(|(key, value)| map.get(key) == Some(value)));
+    }
+    #[test]
+    fn long_chain_drops_without_overflow(){
+        let mut map : ChainedHashMap<u32, u32, Colliding> = ChainedHashMap::default();
+        //Insertar con colisiones es O(n) por clave, se arma la cadena directamente para no tardar O(n²)
+        map.buckets = ChainedHashMap::<u32, u32, Colliding>::empty_buckets(8);
+        let mut chain : Bucket<u32, u32> = None;
+        for i in 0..1_000_000{
+            chain = Some(Box::new(NodeLink::new((i, i), chain)));
+        }
+        map.buckets[0] = chain;
+        map.len = 1_000_000;
+        assert_eq!(map.get(&0) , Some(&0));
+        drop(map);
+    }
+}
@@ -0,0 +1,120 @@
+//! ### Sobre las colas dobles (Deque)
+//! Una cola doble permite insertar y eliminar elementos por ambos extremos en O(1), la version safe se construye sobre
+//! `DoublyLinkedList`, donde `head` es el frente de la cola y `tail` el final.
+//! ```text
+//!                   front ↓                                                         back ↓
+//!              ----------------------          ----------------------          ----------------------
+//!  push_front  |      |       |       | <----- |      |       |       | <----- |      |       |       |  push_back
+//!  pop_front   | PREV |  10   |  NEXT | -----> | PREV |   20  |  NEXT | -----> | PREV |   30  |  NEXT |  pop_back
+//!              |      |       |       |        |      |       |       |        |      |       |       |
+//!               ----------------------          ----------------------          ----------------------
+//! ```
+use std::cell::{Ref, RefMut};
+use super::double_linked_list::{DoublyLinkedList, Iter};
+
+///### Deque
+/// Cola doble safe, los valores viven dentro de los `RefCell` de los nodos de la lista, por eso `front` y `back`
+/// retornan un `Ref<T>` en lugar de una referencia directa.
+#[derive(Debug)]
+pub struct Deque<T>{
+    list : DoublyLinkedList<T>
+}
+impl<T> Default for Deque<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T> Deque<T>{
+    pub fn new() -> Self{
+        Self{
+            list : DoublyLinkedList::new()
+        }
+    }
+    ///Una lista enlazada reserva cada nodo al insertarlo, la capacidad solo existe por compatibilidad con la version unsafe.
+    pub fn with_capacity(_capacity : usize) -> Self{
+        Self::new()
+    }
+    pub fn len(&self) -> usize{
+        self.list.len() as usize
+    }
+    pub fn empty(&self) -> bool{
+        self.list.empty()
+    }
+    pub fn push_back(&mut self , value : T){
+        self.list.push_back_node(value);
+    }
+    pub fn push_front(&mut self , value : T){
+        self.list.push_front_node(value);
+    }
+    pub fn pop_front(&mut self) -> Option<T>{
+        self.list.pop_front_node().map(DoublyLinkedList::into_value)
+    }
+    pub fn pop_back(&mut self) -> Option<T>{
+        self.list.pop_back_node().map(DoublyLinkedList::into_value)
+    }
+    pub fn front(&self) -> Option<Ref<'_, T>>{
+        self.list.front_node().map(|node| Ref::map(node.borrow(), |n| n.value()))
+    }
+    pub fn back(&self) -> Option<Ref<'_, T>>{
+        self.list.back_node().map(|node| Ref::map(node.borrow(), |n| n.value()))
+    }
+    pub fn front_mut(&mut self) -> Option<RefMut<'_, T>>{
+        self.list.front_node().map(|node| RefMut::map(node.borrow_mut(), |n| n.value_mut()))
+    }
+    pub fn back_mut(&mut self) -> Option<RefMut<'_, T>>{
+        self.list.back_node().map(|node| RefMut::map(node.borrow_mut(), |n| n.value_mut()))
+    }
+    ///Recorre la cola desde el frente hasta el final entregando una copia de cada valor.
+    pub fn iter(&self) -> Iter<'_, T>
+    where T : Clone{
+        self.list.iter()
+    }
+}
+///Iterador que consume la cola desde el frente.
+pub struct IntoIter<T>{
+    deque : Deque<T>
+}
+impl<T> Iterator for IntoIter<T>{
+    type Item = T;
+    fn next(&mut self) -> Option<T>{
+        self.deque.pop_front()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>){
+        (self.deque.len(), Some(self.deque.len()))
+    }
+}
+impl<T> DoubleEndedIterator for IntoIter<T>{
+    fn next_back(&mut self) -> Option<T>{
+        self.deque.pop_back()
+    }
+}
+impl<T> IntoIterator for Deque<T>{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> IntoIter<T>{
+        IntoIter{ deque : self }
+    }
+}
+impl<T> FromIterator<T> for Deque<T>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        let mut deque: Deque<T> = Deque::new();
+        for value in iter{
+            deque.push_back(value);
+        }
+        deque
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    crate::lineal::queue_tests::deque_tests!(Deque);
+    #[test]
+    fn front_and_back_mut(){
+        let mut deque : Deque<String> = Deque::new();
+        deque.push_back(String::from("a"));
+        deque.push_back(String::from("b"));
+        deque.front_mut().unwrap().push('x');
+        deque.back_mut().unwrap().push('y');
+        assert_eq!(deque.into_iter().collect::<Vec<String>>() , vec![String::from("ax") , String::from("by")]);
+    }
+}
@@ -1,4 +1,5 @@
 use std::fmt::Display;
+use std::marker::PhantomData;
 use std::rc::{Rc, Weak};
 use std::cell::{Ref, RefCell, RefMut};
 use num::Integer;
@@ -58,8 +59,7 @@ pub struct DoublyNode<T> {
     next: Option<Rc<RefCell<DoublyNode<T>>>> //Referencia en heap compartida, aumenta strong_count a medida que aumenta la lista enlazada hacia el siguiente nodo y disminuye al eliminar nodos
 }
 
-impl<T> DoublyNode<T>
-where T : Integer + Clone + Copy + Display {
+impl<T> DoublyNode<T>{
     fn new(value : T) -> Self{
         Self{
             prev : None,
@@ -67,7 +67,15 @@ where T : Integer + Clone + Copy + Display {
             next : None
         }
     }
-    
+    pub fn value(&self) -> &T{
+        &self.value
+    }
+    pub fn value_mut(&mut self) -> &mut T{
+        &mut self.value
+    }
+    pub fn into_value(self) -> T{
+        self.value
+    }
 }
 
 ///### Nodo doble
@@ -88,8 +96,7 @@ pub struct DoublyLinkedList<T> {
     tail: Option<Rc<RefCell<DoublyNode<T>>>>,
     size: i32
 }
-impl<T> DoublyLinkedList<T>
-where T : Integer + Clone + Copy + Display{
+impl<T> DoublyLinkedList<T>{
     pub fn new() -> Self{
         Self{
             head : None , 
@@ -103,6 +110,9 @@ where T : Integer + Clone + Copy + Display{
     pub fn len(&self) -> i32{
        self.size
     }
+}
+impl<T> DoublyLinkedList<T>
+where T : Integer + Clone + Copy + Display{
     ///## Push Back Doblemente Enlazado
     /// Bueno hacer Push Back en una lista Doblemente enlazada es mucho más sencillo debido a que no tenemos que recorrer constantemente el array hasta el ultimo
     /// nodo porque tenemos un puntero haciendo referencia al ultimo, por ello solamente actualizamos el puntero .next al siguiente nodo, dicha inserción tiene
@@ -486,6 +496,172 @@ where T : Integer + Clone + Copy + Display{
         }
     }
 }
+///### Manejadores de nodos
+/// Estas operaciones trabajan directamente sobre el `Rc<RefCell<DoublyNode<T>>>` de un nodo de la lista, quien conserve una copia del `Rc`
+/// puede desenlazar o mover ese nodo en O(1) sin recorrer la lista, es la base para estructuras como `LruCache` que indexan los nodos
+/// desde fuera de la lista. No exigen ninguna restricción sobre `T`, ya que nunca copian el valor almacenado.
+/// - _El nodo recibido debe pertenecer a esta lista y encontrarse enlazado, por eso solo son visibles dentro del crate._
+impl<T> DoublyLinkedList<T>{
+    ///Enlaza un nuevo nodo al frente de la lista y retorna una copia del `Rc` que lo contiene.
+    pub(crate) fn push_front_node(&mut self , value : T) -> Rc<RefCell<DoublyNode<T>>>{
+        let new_node: Rc<RefCell<DoublyNode<T>>> = Rc::new(RefCell::new(DoublyNode::new(value)));
+        self.link_front(&new_node);
+        new_node
+    }
+    ///Enlaza al frente un nodo que no se encuentra en la lista.
+    fn link_front(&mut self , node : &Rc<RefCell<DoublyNode<T>>>){
+        match self.head.take() {
+            None => {
+                self.tail = Some(node.clone());
+            },
+            Some(old_head) => {
+                old_head.borrow_mut().prev = Some(Rc::downgrade(node));
+                node.borrow_mut().next = Some(old_head);
+            }
+        }
+        self.head = Some(node.clone());
+        self.size += 1;
+    }
+    ///### Unlink_Node
+    /// Desenlaza un nodo en O(1), el previo y el siguiente del nodo quedan enlazados entre si.
+    /// ```text
+    ///              ----------------------          ----------------------          ----------------------
+    ///             |      |       |       | <----- |      |       |       | <----- |      |       |       |
+    /// None <----  | PREV |  10   |  NEXT | -----> | PREV |   20  |  NEXT | -----> | PREV |   30  |  NEXT | ---->  None
+    ///             |      |       |       |        |      |       |       |        |      |       |       |
+    ///              ----------------------          ----------------------          ----------------------
+    ///                         |   ↑_________________________________________________________|  |
+    ///                         |________________________________________________________________↑
+    /// ```
+    pub(crate) fn unlink_node(&mut self , node : &Rc<RefCell<DoublyNode<T>>>){
+        let (prev, next) = {
+            let mut node_borrow: RefMut<DoublyNode<T>> = node.borrow_mut();
+            (node_borrow.prev.take().and_then(|weak| weak.upgrade()), node_borrow.next.take())
+        };
+        match prev {
+            None => self.head = next.clone(),
+            Some(ref prev_node) => prev_node.borrow_mut().next = next.clone()
+        }
+        match next {
+            None => self.tail = prev,
+            Some(ref next_node) => next_node.borrow_mut().prev = prev.as_ref().map(Rc::downgrade)
+        }
+        self.size -= 1;
+    }
+    ///Mueve un nodo de la lista a la cabeza, si ya es la cabeza no hace nada.
+    pub(crate) fn move_to_front(&mut self , node : &Rc<RefCell<DoublyNode<T>>>){
+        if self.head.as_ref().is_some_and(|head| Rc::ptr_eq(head, node)){
+            return;
+        }
+        self.unlink_node(node);
+        self.link_front(node);
+    }
+    ///Desenlaza el nodo de `tail` y retorna el `Rc` que lo contiene.
+    pub(crate) fn pop_back_node(&mut self) -> Option<Rc<RefCell<DoublyNode<T>>>>{
+        let tail: Rc<RefCell<DoublyNode<T>>> = self.tail.clone()?;
+        self.unlink_node(&tail);
+        Some(tail)
+    }
+    ///Una referencia al nodo de `tail` sin desenlazarlo.
+    pub(crate) fn back_node(&self) -> Option<&Rc<RefCell<DoublyNode<T>>>>{
+        self.tail.as_ref()
+    }
+    ///Enlaza un nuevo nodo al final de la lista y retorna una copia del `Rc` que lo contiene.
+    pub(crate) fn push_back_node(&mut self , value : T) -> Rc<RefCell<DoublyNode<T>>>{
+        let new_node: Rc<RefCell<DoublyNode<T>>> = Rc::new(RefCell::new(DoublyNode::new(value)));
+        match self.tail.take() {
+            None => {
+                self.head = Some(new_node.clone());
+            },
+            Some(old_tail) => {
+                new_node.borrow_mut().prev = Some(Rc::downgrade(&old_tail));
+                old_tail.borrow_mut().next = Some(new_node.clone());
+            }
+        }
+        self.tail = Some(new_node.clone());
+        self.size += 1;
+        new_node
+    }
+    ///Desenlaza el nodo de `head` y retorna el `Rc` que lo contiene.
+    pub(crate) fn pop_front_node(&mut self) -> Option<Rc<RefCell<DoublyNode<T>>>>{
+        let head: Rc<RefCell<DoublyNode<T>>> = self.head.clone()?;
+        self.unlink_node(&head);
+        Some(head)
+    }
+    ///Una referencia al nodo de `head` sin desenlazarlo.
+    pub(crate) fn front_node(&self) -> Option<&Rc<RefCell<DoublyNode<T>>>>{
+        self.head.as_ref()
+    }
+    ///Recupera el valor de un nodo ya desenlazado sin copiarlo, nadie más debe conservar una copia de su `Rc`.
+    pub(crate) fn into_value(node : Rc<RefCell<DoublyNode<T>>>) -> T{
+        match Rc::try_unwrap(node) {
+            Ok(cell) => cell.into_inner().into_value(),
+            Err(_) => unreachable!("El nodo desenlazado aun posee propietarios")
+        }
+    }
+    ///### Iter
+    /// Recorre la lista desde `head` hasta `tail` (o en reversa con `.rev()`), como los valores viven dentro de un `RefCell`
+    /// el iterador entrega una copia de cada valor.
+    pub fn iter(&self) -> Iter<'_, T>
+    where T : Clone{
+        Iter{
+            front : self.head.clone(),
+            back : self.tail.clone(),
+            remaining : self.size as usize,
+            list : PhantomData
+        }
+    }
+}
+///Iterador de `DoublyLinkedList`, avanza desde ambos extremos y se detiene cuando los cursores se cruzan.
+/// Toma prestada la lista para que no pueda modificarse mientras el iterador conserva copias de los `Rc` de sus nodos.
+pub struct Iter<'a, T>{
+    front : Option<Rc<RefCell<DoublyNode<T>>>>,
+    back : Option<Rc<RefCell<DoublyNode<T>>>>,
+    remaining : usize,
+    list : PhantomData<&'a DoublyLinkedList<T>>
+}
+impl<T : Clone> Iterator for Iter<'_, T>{
+    type Item = T;
+    fn next(&mut self) -> Option<T>{
+        if self.remaining == 0{
+            return None;
+        }
+        let node: Rc<RefCell<DoublyNode<T>>> = self.front.take()?;
+        let node_borrow: Ref<DoublyNode<T>> = node.borrow();
+        self.front = node_borrow.next.clone();
+        self.remaining -= 1;
+        Some(node_borrow.value.clone())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>){
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<T : Clone> DoubleEndedIterator for Iter<'_, T>{
+    fn next_back(&mut self) -> Option<T>{
+        if self.remaining == 0{
+            return None;
+        }
+        let node: Rc<RefCell<DoublyNode<T>>> = self.back.take()?;
+        let node_borrow: Ref<DoublyNode<T>> = node.borrow();
+        self.back = node_borrow.prev.as_ref().and_then(|weak| weak.upgrade());
+        self.remaining -= 1;
+        Some(node_borrow.value.clone())
+    }
+}
+impl<T : Clone> ExactSizeIterator for Iter<'_, T>{}
+///### Drop
+/// Cada nodo mantiene vivo a su siguiente mediante `Rc<T>`, por lo que al liberar `head` se liberaria toda la cadena de manera
+/// recursiva y una lista lo suficientemente larga desbordaria el stack. Se suelta primero `tail` y luego se toma el `next` de cada
+/// nodo antes de liberarlo, asi el `strong_count` de cada nodo llega a cero sin arrastrar al resto de la lista.
+impl<T> Drop for DoublyLinkedList<T>{
+    fn drop(&mut self){
+        self.tail.take();
+        let mut current: Option<Rc<RefCell<DoublyNode<T>>>> = self.head.take();
+        while let Some(node) = current{
+            current = node.borrow_mut().next.take();
+        }
+    }
+}
 #[cfg(test)]
 mod tests{
     use super::*;
@@ -598,4 +774,45 @@ mod tests{
         }
         println!("{:?}" , doubly);
     }
+    #[test]
+    fn drop_million_nodes(){
+        let mut doubly : DoublyLinkedList<i32> = DoublyLinkedList::new();
+        for i in 0..1_000_000{
+            doubly.push_back(i);
+        }
+        assert_eq!(doubly.len() , 1_000_000);
+        drop(doubly);
+    }
+    #[test]
+    fn iter_both_directions(){
+        let mut doubly : DoublyLinkedList<i32> = DoublyLinkedList::new();
+        doubly.push_back(20);
+        doubly.push_back(30);
+        doubly.push_front(10);
+        assert_eq!(doubly.iter().collect::<Vec<i32>>() , vec![10 , 20 , 30]);
+        assert_eq!(doubly.iter().rev().collect::<Vec<i32>>() , vec![30 , 20 , 10]);
+        let mut iter: Iter<'_, i32> = doubly.iter();
+        assert_eq!(iter.next() , Some(10));
+        assert_eq!(iter.next_back() , Some(30));
+        assert_eq!(iter.next() , Some(20));
+        assert_eq!(iter.next_back() , None);
+    }
+    #[test]
+    fn node_handles_unlink_and_move(){
+        let mut doubly : DoublyLinkedList<i32> = DoublyLinkedList::new();
+        let node_30: Rc<RefCell<DoublyNode<i32>>> = doubly.push_front_node(30);
+        let node_20: Rc<RefCell<DoublyNode<i32>>> = doubly.push_front_node(20);
+        let node_10: Rc<RefCell<DoublyNode<i32>>> = doubly.push_front_node(10);
+        assert_eq!(doubly.print_list_directly().unwrap() , "Head->  10 -> 20 -> 30 -> <- Tail");
+        doubly.unlink_node(&node_20);
+        assert_eq!(doubly.len() , 2);
+        assert_eq!(doubly.print_list_directly().unwrap() , "Head->  10 -> 30 -> <- Tail");
+        doubly.move_to_front(&node_30);
+        assert_eq!(doubly.print_list_directly().unwrap() , "Head->  30 -> 10 -> <- Tail");
+        let back: Rc<RefCell<DoublyNode<i32>>> = doubly.pop_back_node().unwrap();
+        assert!(Rc::ptr_eq(&back , &node_10));
+        assert_eq!(doubly.pop_back().unwrap() , 30);
+        assert!(doubly.empty());
+        assert!(doubly.pop_back_node().is_none());
+    }
 }
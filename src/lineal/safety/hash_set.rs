@@ -0,0 +1,160 @@
+//! ### HashSet sobre `ChainedHashMap`
+//! Un conjunto por hash es un mapa cuyas claves son los elementos y cuyo valor es `()`, no ocupa memoria extra por par.
+//! Implementa `Set` para poder intercambiarse con los conjuntos ordenados como `AVLTree`.
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::fmt::{self, Debug};
+use std::hash::{BuildHasher, Hash};
+use super::chained_hash_map::{self, ChainedHashMap};
+use super::set::Set;
+
+///### HashSet
+/// Conjunto por hash con encadenamiento.
+pub struct HashSet<T, S = RandomState>{
+    map : ChainedHashMap<T, (), S>
+}
+impl<T> HashSet<T, RandomState>{
+    pub fn new() -> Self{
+        Self{ map : ChainedHashMap::new() }
+    }
+    pub fn with_capacity(capacity : usize) -> Self{
+        Self{ map : ChainedHashMap::with_capacity(capacity) }
+    }
+}
+impl<T, S : Default> Default for HashSet<T, S>{
+    fn default() -> Self{
+        Self{ map : ChainedHashMap::default() }
+    }
+}
+impl<T, S> HashSet<T, S>{
+    pub fn with_hasher(hash_builder : S) -> Self{
+        Self{ map : ChainedHashMap::with_hasher(hash_builder) }
+    }
+    pub fn len(&self) -> usize{
+        self.map.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.map.empty()
+    }
+    pub fn iter(&self) -> Iter<'_, T>{
+        Iter{ inner : self.map.iter() }
+    }
+    pub fn clear(&mut self){
+        self.map.clear();
+    }
+}
+impl<T : Hash + Eq, S : BuildHasher> HashSet<T, S>{
+    ///Inserta el valor, retorna `false` si ya existía.
+    pub fn insert(&mut self , value : T) -> bool{
+        self.map.insert(value, ()).is_none()
+    }
+    pub fn contains<Q>(&self , value : &Q) -> bool
+    where T : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        self.map.contains_key(value)
+    }
+    ///Elimina el valor, retorna `false` si no existía.
+    pub fn remove<Q>(&mut self , value : &Q) -> bool
+    where T : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        self.map.remove(value).is_some()
+    }
+    ///Elimina y retorna el valor guardado en el conjunto.
+    pub fn take<Q>(&mut self , value : &Q) -> Option<T>
+    where T : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        self.map.remove_entry(value).map(|(value, _)| value)
+    }
+}
+impl<T : Hash + Eq , S : BuildHasher + Default> Set<T> for HashSet<T, S>{
+    type Iter<'a> = Iter<'a, T> where Self : 'a, T : 'a;
+    fn new_set() -> Self{
+        Self::default()
+    }
+    fn insert(&mut self , value : T) -> bool{
+        HashSet::insert(self, value)
+    }
+    fn contains(&self , value : &T) -> bool{
+        HashSet::contains(self, value)
+    }
+    fn remove(&mut self , value : &T) -> bool{
+        HashSet::remove(self, value)
+    }
+    fn len(&self) -> usize{
+        HashSet::len(self)
+    }
+    fn iter(&self) -> Iter<'_, T>{
+        HashSet::iter(self)
+    }
+}
+impl<T : Hash + Eq, S : BuildHasher> PartialEq for HashSet<T, S>{
+    fn eq(&self , other : &Self) -> bool{
+        self.len() == other.len() && self.iter().all(|value| other.contains(value))
+    }
+}
+impl<T : Debug, S> Debug for HashSet<T, S>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+impl<T : Hash + Eq, S : BuildHasher + Default> FromIterator<T> for HashSet<T, S>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        Self{ map : iter.into_iter().map(|value| (value, ())).collect() }
+    }
+}
+impl<T : Hash + Eq, S : BuildHasher> Extend<T> for HashSet<T, S>{
+    fn extend<I : IntoIterator<Item = T>>(&mut self , iter : I){
+        self.map.extend(iter.into_iter().map(|value| (value, ())));
+    }
+}
+///Iterador por referencia de `HashSet`.
+pub struct Iter<'a, T>{
+    inner : chained_hash_map::Iter<'a, T, ()>
+}
+impl<'a, T> Iterator for Iter<'a, T>{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T>{
+        self.inner.next().map(|(value, _)| value)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>){
+        self.inner.size_hint()
+    }
+}
+impl<T> ExactSizeIterator for Iter<'_, T>{}
+impl<'a, T, S> IntoIterator for &'a HashSet<T, S>{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Iter<'a, T>{
+        self.iter()
+    }
+}
+impl<T, S> IntoIterator for HashSet<T, S>{
+    type Item = T;
+    type IntoIter = std::iter::Map<chained_hash_map::IntoIter<T, ()>, fn((T, ())) -> T>;
+    fn into_iter(self) -> Self::IntoIter{
+        self.map.into_iter().map(|(value, _)| value)
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    #[test]
+    fn insert_contains_remove_with_borrowed_lookups(){
+        let mut set : HashSet<String> = HashSet::new();
+        assert!(set.insert(String::from("rojo")));
+        assert!(set.insert(String::from("azul")));
+        assert!(!set.insert(String::from("rojo")));
+        assert!(set.contains("azul"));
+        assert_eq!(set.take("azul") , Some(String::from("azul")));
+        assert!(!set.remove("azul"));
+        assert_eq!(set.len() , 1);
+    }
+    #[test]
+    fn collect_extend_and_compare(){
+        let mut set : HashSet<i32> = (0..10).chain(0..5).collect();
+        assert_eq!(set.len() , 10);
+        set.extend(5..15);
+        let expected : HashSet<i32> = (0..15).rev().collect();
+        assert_eq!(set , expected);
+        let mut owned : Vec<i32> = set.into_iter().collect();
+        owned.sort();
+        assert_eq!(owned , (0..15).collect::<Vec<i32>>());
+    }
+}
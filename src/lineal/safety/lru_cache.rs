@@ -0,0 +1,227 @@
+//! ### Sobre la cache LRU (Least Recently Used)
+//! Una cache LRU almacena a lo más `capacity` pares clave-valor, cuando se inserta un par nuevo con la cache llena
+//! se descarta el par que lleva más tiempo sin ser utilizado.
+//! ### ¿Por qué una lista doblemente enlazada?
+//! Se combinan dos estructuras.
+//! - Un `HashMap<K, Rc<RefCell<DoublyNode<(K, V)>>>>` que encuentra el nodo de una clave en O(1).
+//! - Una `DoublyLinkedList<(K, V)>` ordenada desde el más reciente (`head`) al menos reciente (`tail`).
+//!
+//! Como el mapa guarda una copia del `Rc` de cada nodo, mover un nodo a la cabeza o desenlazarlo es un reajuste de punteros
+//! en O(1), sin recorrer la lista.
+//! ```text
+//!        HashMap                      head ↓                                                tail ↓
+//!     +---------+              ----------------------          ----------------------          ----------------------
+//!     | "a" | ---------------> |      |       |       | <----- |      |       |       | <----- |      |       |       |
+//!     | "b" | -------|         | PREV | (a,1) |  NEXT | -----> | PREV | (b,2) |  NEXT | -----> | PREV | (c,3) |  NEXT |
+//!     | "c" | ----|  |-------> |      |       |       |        |      |       |       |   |--> |      |       |       |
+//!     +---------+ |             ----------------------          ----------------------    |     ----------------------
+//!                 |_______________________________________________________________________|
+//! ```
+//! `get("c")` mueve `(c,3)` a la cabeza y `put` con la cache llena descarta `tail`, en este caso `(b,2)`.
+use std::cell::{Ref, RefCell, RefMut};
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::hash::Hash;
+use std::rc::Rc;
+use super::double_linked_list::{DoublyLinkedList, DoublyNode};
+
+type EvictionCallback<K, V> = Box<dyn FnMut(K, V)>;
+type CacheNode<K, V> = Rc<RefCell<DoublyNode<(K, V)>>>;
+///### LruCache
+/// Cache de capacidad fija con descarte del elemento menos recientemente utilizado, todas sus operaciones son O(1).
+pub struct LruCache<K, V>{
+    map : HashMap<K, CacheNode<K, V>>,
+    list : DoublyLinkedList<(K, V)>,
+    capacity : usize,
+    on_evict : Option<EvictionCallback<K, V>>
+}
+impl<K, V> LruCache<K, V>
+where K : Hash + Eq + Clone{
+    ///Crea una cache vacia, la capacidad debe ser mayor a cero.
+    pub fn new(capacity : usize) -> Self{
+        assert!(capacity > 0, "La capacidad de la cache debe ser mayor a cero");
+        Self{
+            map : HashMap::with_capacity(capacity),
+            list : DoublyLinkedList::new(),
+            capacity,
+            on_evict : None
+        }
+    }
+    ///Crea una cache vacia que invoca `callback` con cada par descartado por falta de capacidad (en `put` y `resize`).
+    pub fn with_eviction_callback<F>(capacity : usize , callback : F) -> Self
+    where F : FnMut(K, V) + 'static{
+        let mut cache: LruCache<K, V> = Self::new(capacity);
+        cache.on_evict = Some(Box::new(callback));
+        cache
+    }
+    pub fn len(&self) -> usize{
+        self.map.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.map.is_empty()
+    }
+    pub fn capacity(&self) -> usize{
+        self.capacity
+    }
+    pub fn contains(&self , key : &K) -> bool{
+        self.map.contains_key(key)
+    }
+    ///### Get
+    /// Retorna el valor asociado a la clave y lo marca como el más reciente, moviendo su nodo a la cabeza de la lista.
+    pub fn get(&mut self , key : &K) -> Option<Ref<'_, V>>{
+        let node: &CacheNode<K, V> = self.map.get(key)?;
+        self.list.move_to_front(node);
+        Some(Ref::map(node.borrow(), |n| &n.value().1))
+    }
+    ///Igual que `get` pero con una referencia mutable al valor.
+    pub fn get_mut(&mut self , key : &K) -> Option<RefMut<'_, V>>{
+        let node: &CacheNode<K, V> = self.map.get(key)?;
+        self.list.move_to_front(node);
+        Some(RefMut::map(node.borrow_mut(), |n| &mut n.value_mut().1))
+    }
+    ///### Peek
+    /// Retorna el valor asociado a la clave sin modificar el orden de uso.
+    pub fn peek(&self , key : &K) -> Option<Ref<'_, V>>{
+        let node: &CacheNode<K, V> = self.map.get(key)?;
+        Some(Ref::map(node.borrow(), |n| &n.value().1))
+    }
+    ///Retorna la clave menos recientemente utilizada sin modificar el orden de uso.
+    pub fn peek_lru(&self) -> Option<Ref<'_, K>>{
+        let node: &CacheNode<K, V> = self.list.back_node()?;
+        Some(Ref::map(node.borrow(), |n| &n.value().0))
+    }
+    ///### Put
+    /// Inserta o actualiza un par y lo marca como el más reciente.
+    /// - `Caso 1`: La clave ya existe, se reemplaza el valor y se retorna el anterior.
+    /// - `Caso 2`: La clave no existe y la cache está llena, se descarta `tail` invocando la función de descarte.
+    pub fn put(&mut self , key : K , value : V) -> Option<V>{
+        if let Some(node) = self.map.get(&key){
+            self.list.move_to_front(node);
+            let old_value: V = std::mem::replace(&mut node.borrow_mut().value_mut().1, value);
+            return Some(old_value);
+        }
+        if self.map.len() == self.capacity{
+            self.evict_lru();
+        }
+        let node: CacheNode<K, V> = self.list.push_front_node((key.clone(), value));
+        self.map.insert(key, node);
+        None
+    }
+    ///Elimina la clave de la cache retornando su valor.
+    pub fn remove(&mut self , key : &K) -> Option<V>{
+        let node: CacheNode<K, V> = self.map.remove(key)?;
+        self.list.unlink_node(&node);
+        Some(DoublyLinkedList::into_value(node).1)
+    }
+    ///### Pop_Lru
+    /// Elimina y retorna el par menos recientemente utilizado, no invoca la función de descarte.
+    pub fn pop_lru(&mut self) -> Option<(K, V)>{
+        let node: CacheNode<K, V> = self.list.pop_back_node()?;
+        self.map.remove(&node.borrow().value().0);
+        Some(DoublyLinkedList::into_value(node))
+    }
+    ///### Resize
+    /// Cambia la capacidad de la cache, si la nueva capacidad es menor a la cantidad de pares se descartan los menos recientes
+    /// invocando la función de descarte por cada uno.
+    pub fn resize(&mut self , capacity : usize){
+        assert!(capacity > 0, "La capacidad de la cache debe ser mayor a cero");
+        while self.map.len() > capacity{
+            self.evict_lru();
+        }
+        self.capacity = capacity;
+    }
+    fn evict_lru(&mut self){
+        if let Some((key, value)) = self.pop_lru(){
+            if let Some(callback) = self.on_evict.as_mut(){
+                callback(key, value);
+            }
+        }
+    }
+}
+impl<K, V> Debug for LruCache<K, V>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_struct("LruCache")
+            .field("len", &self.map.len())
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    #[test]
+    fn put_and_get(){
+        let mut cache : LruCache<&str, i32> = LruCache::new(2);
+        assert!(cache.empty());
+        assert_eq!(cache.put("a", 1), None);
+        assert_eq!(cache.put("b", 2), None);
+        assert_eq!(*cache.get(&"a").unwrap(), 1);
+        assert_eq!(*cache.get(&"b").unwrap(), 2);
+        assert!(cache.get(&"c").is_none());
+        assert_eq!(cache.len(), 2);
+    }
+    #[test]
+    fn put_evicts_least_recently_used(){
+        let mut cache : LruCache<&str, i32> = LruCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        cache.get(&"a");
+        cache.put("c", 3); // "b" es el menos reciente
+        assert!(!cache.contains(&"b"));
+        assert!(cache.contains(&"a"));
+        assert!(cache.contains(&"c"));
+        assert_eq!(*cache.peek_lru().unwrap(), "a");
+    }
+    #[test]
+    fn put_existing_key_updates_value(){
+        let mut cache : LruCache<&str, i32> = LruCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        assert_eq!(cache.put("a", 10), Some(1));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.pop_lru(), Some(("b", 2)));
+        assert_eq!(cache.pop_lru(), Some(("a", 10)));
+        assert_eq!(cache.pop_lru(), None);
+    }
+    #[test]
+    fn peek_does_not_change_order(){
+        let mut cache : LruCache<i32, String> = LruCache::new(3);
+        cache.put(1, String::from("uno"));
+        cache.put(2, String::from("dos"));
+        cache.put(3, String::from("tres"));
+        assert_eq!(cache.peek(&1).unwrap().as_str(), "uno");
+        assert_eq!(*cache.peek_lru().unwrap(), 1);
+        cache.put(4, String::from("cuatro"));
+        assert!(cache.peek(&1).is_none());
+    }
+    #[test]
+    fn get_mut_and_remove(){
+        let mut cache : LruCache<i32, Vec<i32>> = LruCache::new(2);
+        cache.put(1, vec![1]);
+        cache.put(2, vec![2]);
+        cache.get_mut(&1).unwrap().push(10);
+        assert_eq!(cache.remove(&1), Some(vec![1, 10]));
+        assert_eq!(cache.remove(&1), None);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.pop_lru(), Some((2, vec![2])));
+        assert!(cache.empty());
+    }
+    #[test]
+    fn resize_evicts_with_callback(){
+        let evicted: Rc<RefCell<Vec<(i32, i32)>>> = Rc::new(RefCell::new(Vec::new()));
+        let log: Rc<RefCell<Vec<(i32, i32)>>> = evicted.clone();
+        let mut cache : LruCache<i32, i32> = LruCache::with_eviction_callback(4, move |k, v| log.borrow_mut().push((k, v)));
+        for i in 1..=4{
+            cache.put(i, i * 10);
+        }
+        cache.get(&1);
+        cache.resize(2);
+        assert_eq!(*evicted.borrow(), vec![(2, 20), (3, 30)]);
+        cache.put(5, 50);
+        assert_eq!(*evicted.borrow(), vec![(2, 20), (3, 30), (4, 40)]);
+        assert_eq!(cache.capacity(), 2);
+        //pop_lru no es un descarte por capacidad
+        cache.pop_lru();
+        assert_eq!(evicted.borrow().len(), 3);
+    }
+}
@@ -0,0 +1,245 @@
+//! ### Sobre los buffers circulares (Ring Buffer)
+//! Un buffer circular es una cola FIFO de capacidad fija, al llegar al final del arreglo las posiciones dan la vuelta al inicio.
+//! A diferencia de `Queue` nunca reserva más memoria, cuando está lleno la inserción se resuelve segun su `Overflow`.
+//! - `Overflow::Reject`: la inserción falla y el valor se devuelve a quien lo inserto.
+//! - `Overflow::Overwrite`: se descarta el elemento más antiguo para dar lugar al nuevo.
+//! ```text
+//!   N = 6 , head = 4 , len = 4                     push(e) en modo Overwrite con el buffer lleno
+//!     0     1     2     3     4     5                 0     1     2     3     4     5
+//!  +-----+-----+-----+-----+-----+-----+           +-----+-----+-----+-----+-----+-----+
+//!  |  c  |  d  |     |     |  a  |  b  |   ....    |  g  |  h  |  i  |  j  |  e  |  f  |  ----> descarta a `e`
+//!  +-----+-----+-----+-----+-----+-----+           +-----+-----+-----+-----+-----+-----+
+//!           ↑ back          ↑ front
+//! ```
+//! Los elementos se guardan en un `[MaybeUninit<T>; N]` dentro de la misma estructura, así no se le pide `Default` a
+//! `T` para rellenar las posiciones libres. Solo las `len` posiciones desde `head` están inicializadas, cada bloque
+//! `unsafe` depende de esa invariante y por eso `push` y `pop` actualizan `head` y `len` junto con la posición tocada.
+use std::fmt::{self, Debug};
+use std::iter::Chain;
+use std::mem::{self, MaybeUninit};
+use std::slice;
+
+///Qué hacer cuando se inserta en un buffer circular lleno.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow{
+    ///La inserción falla y devuelve el valor.
+    Reject,
+    ///Se descarta el elemento más antiguo.
+    Overwrite
+}
+///### RingBuffer
+/// Buffer circular de capacidad `N` fijada en tiempo de compilación.
+pub struct RingBuffer<T, const N : usize>{
+    ///Inicializadas solo las posiciones `head..head + len` módulo `N`.
+    buffer : [MaybeUninit<T>; N],
+    head : usize,
+    len : usize,
+    mode : Overflow
+}
+impl<T, const N : usize> Default for RingBuffer<T, N>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T, const N : usize> RingBuffer<T, N>{
+    ///Crea un buffer vacio que rechaza las inserciones cuando está lleno.
+    pub fn new() -> Self{
+        Self::with_mode(Overflow::Reject)
+    }
+    pub fn with_mode(mode : Overflow) -> Self{
+        assert!(N > 0, "La capacidad del buffer debe ser mayor a cero");
+        Self{
+            buffer : std::array::from_fn(|_| MaybeUninit::uninit()),
+            head : 0,
+            len : 0,
+            mode
+        }
+    }
+    pub fn len(&self) -> usize{
+        self.len
+    }
+    pub fn empty(&self) -> bool{
+        self.len == 0
+    }
+    pub fn is_full(&self) -> bool{
+        self.len == N
+    }
+    pub fn capacity(&self) -> usize{
+        N
+    }
+    pub fn mode(&self) -> Overflow{
+        self.mode
+    }
+    fn physical(&self , offset : usize) -> usize{
+        (self.head + offset) % N
+    }
+    ///### Push
+    /// Inserta al final del buffer.
+    /// - `Ok(None)`: habia espacio para el valor.
+    /// - `Ok(Some(oldest))`: el buffer estaba lleno en modo `Overwrite`, se retorna el elemento descartado.
+    /// - `Err(value)`: el buffer estaba lleno en modo `Reject`, se devuelve el valor.
+    pub fn push(&mut self , value : T) -> Result<Option<T>, T>{
+        if self.len < N{
+            let index: usize = self.physical(self.len);
+            self.buffer[index].write(value);
+            self.len += 1;
+            return Ok(None);
+        }
+        match self.mode {
+            Overflow::Reject => Err(value),
+            Overflow::Overwrite => {
+                //El frente es el más antiguo, al reemplazarlo el frente avanza y el nuevo valor queda al final
+                let oldest: MaybeUninit<T> = mem::replace(&mut self.buffer[self.head], MaybeUninit::new(value));
+                self.head = self.physical(1);
+                //SAFETY: con el buffer lleno todas las posiciones están inicializadas, incluida la del frente
+                Ok(Some(unsafe { oldest.assume_init() }))
+            }
+        }
+    }
+    ///Elimina y retorna el elemento más antiguo.
+    pub fn pop(&mut self) -> Option<T>{
+        if self.len == 0{
+            return None;
+        }
+        //SAFETY: el frente está inicializado porque len > 0, al avanzar head la posición queda fuera del rango ocupado
+        let value: T = unsafe { self.buffer[self.head].assume_init_read() };
+        self.head = self.physical(1);
+        self.len -= 1;
+        Some(value)
+    }
+    pub fn front(&self) -> Option<&T>{
+        self.as_slices().0.first()
+    }
+    pub fn back(&self) -> Option<&T>{
+        let (first, second): (&[T], &[T]) = self.as_slices();
+        second.last().or(first.last())
+    }
+    ///Los elementos en orden lógico divididos en el tramo que va desde el frente hasta el final del arreglo y el
+    ///tramo que dio la vuelta al inicio.
+    pub fn as_slices(&self) -> (&[T], &[T]){
+        let until_end: usize = N - self.head;
+        let (first, second): (&[MaybeUninit<T>], &[MaybeUninit<T>]) = if self.len <= until_end{
+            (&self.buffer[self.head..self.head + self.len], &[])
+        }else {
+            (&self.buffer[self.head..], &self.buffer[..self.len - until_end])
+        };
+        //SAFETY: ambos tramos están dentro de las posiciones inicializadas y `MaybeUninit<T>` tiene la misma
+        //representación que `T`, los tramos viven lo mismo que `&self`
+        unsafe { (&*(first as *const [MaybeUninit<T>] as *const [T]), &*(second as *const [MaybeUninit<T>] as *const [T])) }
+    }
+    ///Recorre los elementos desde el más antiguo al más reciente.
+    pub fn iter(&self) -> Chain<slice::Iter<'_, T>, slice::Iter<'_, T>>{
+        let (first, second): (&[T], &[T]) = self.as_slices();
+        first.iter().chain(second.iter())
+    }
+    pub fn clear(&mut self){
+        while self.pop().is_some(){}
+        self.head = 0;
+    }
+}
+impl<T, const N : usize> Drop for RingBuffer<T, N>{
+    fn drop(&mut self){
+        self.clear();
+    }
+}
+impl<T : Debug, const N : usize> Debug for RingBuffer<T, N>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+///Iterador que consume el buffer desde el elemento más antiguo.
+pub struct IntoIter<T, const N : usize>{
+    buffer : RingBuffer<T, N>
+}
+impl<T, const N : usize> Iterator for IntoIter<T, N>{
+    type Item = T;
+    fn next(&mut self) -> Option<T>{
+        self.buffer.pop()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>){
+        (self.buffer.len(), Some(self.buffer.len()))
+    }
+}
+impl<T, const N : usize> IntoIterator for RingBuffer<T, N>{
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+    fn into_iter(self) -> IntoIter<T, N>{
+        IntoIter{ buffer : self }
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    crate::lineal::queue_tests::fifo_tests!(new: RingBuffer::<_, 64>::new, push: push, pop: pop);
+    #[test]
+    fn reject_when_full(){
+        let mut buffer : RingBuffer<i32, 3> = RingBuffer::new();
+        assert_eq!(buffer.push(1) , Ok(None));
+        assert_eq!(buffer.push(2) , Ok(None));
+        assert_eq!(buffer.push(3) , Ok(None));
+        assert!(buffer.is_full());
+        assert_eq!(buffer.push(4) , Err(4));
+        assert_eq!(buffer.iter().copied().collect::<Vec<i32>>() , vec![1 , 2 , 3]);
+    }
+    #[test]
+    fn overwrite_oldest_when_full(){
+        let mut buffer : RingBuffer<i32, 3> = RingBuffer::with_mode(Overflow::Overwrite);
+        for i in 1..=3{
+            assert_eq!(buffer.push(i) , Ok(None));
+        }
+        assert_eq!(buffer.push(4) , Ok(Some(1)));
+        assert_eq!(buffer.push(5) , Ok(Some(2)));
+        assert!(buffer.is_full());
+        assert_eq!(buffer.as_slices() , (&[3][..] , &[4 , 5][..]));
+        assert_eq!(buffer.iter().copied().collect::<Vec<i32>>() , vec![3 , 4 , 5]);
+        assert_eq!(format!("{:?}" , buffer) , "[3, 4, 5]");
+        assert_eq!(buffer.pop() , Some(3));
+        assert_eq!(*buffer.back().unwrap() , 5);
+    }
+    #[test]
+    fn as_slices_follow_logical_order(){
+        let mut buffer : RingBuffer<i32, 4> = RingBuffer::new();
+        assert_eq!(buffer.as_slices() , (&[][..] , &[][..]));
+        for i in 0..4{
+            buffer.push(i).unwrap();
+        }
+        buffer.pop();
+        buffer.pop();
+        buffer.push(4).unwrap();
+        assert_eq!(buffer.as_slices() , (&[2 , 3][..] , &[4][..]));
+        assert_eq!(buffer.iter().copied().collect::<Vec<i32>>() , vec![2 , 3 , 4]);
+        assert_eq!((buffer.front() , buffer.back()) , (Some(&2) , Some(&4)));
+        buffer.clear();
+        assert!(buffer.empty());
+        assert_eq!((buffer.front() , buffer.back()) , (None , None));
+        assert_eq!(buffer.as_slices() , (&[][..] , &[][..]));
+    }
+    #[test]
+    fn values_without_default(){
+        //`T` no necesita `Default`, las posiciones libres quedan sin inicializar
+        #[derive(Debug)]
+        struct Token(u32);
+        let mut buffer : RingBuffer<Token, 2> = RingBuffer::with_mode(Overflow::Overwrite);
+        for id in 0..5{
+            buffer.push(Token(id)).unwrap();
+        }
+        assert_eq!(buffer.iter().map(|token| token.0).collect::<Vec<u32>>() , vec![3 , 4]);
+        assert_eq!(buffer.pop().map(|token| token.0) , Some(3));
+    }
+    #[test]
+    fn every_value_is_dropped_once(){
+        use std::rc::Rc;
+        let tracker: Rc<()> = Rc::new(());
+        let mut buffer : RingBuffer<Rc<()>, 3> = RingBuffer::with_mode(Overflow::Overwrite);
+        for _ in 0..5{
+            drop(buffer.push(Rc::clone(&tracker)));
+        }
+        assert_eq!(Rc::strong_count(&tracker) , 4);
+        let mut iter: IntoIter<Rc<()>, 3> = buffer.into_iter();
+        drop(iter.next());
+        assert_eq!(Rc::strong_count(&tracker) , 3);
+        //Los que no se consumieron se liberan con el iterador
+        drop(iter);
+        assert_eq!(Rc::strong_count(&tracker) , 1);
+    }
+}
@@ -0,0 +1,156 @@
+//! ### Conjuntos (Set)
+//! Contrato común de los conjuntos del crate, lo implementan los `HashSet` de `safety` y `unsafety` y también `AVLTree`,
+//! así un algoritmo escrito sobre `Set<T>` puede cambiar entre un conjunto ordenado y uno por hash sin modificarse.
+//!
+//! | Operación                | AVLTree   | HashSet (promedio) |
+//! |--------------------------|-----------|--------------------|
+//! | `insert` / `remove`      | O(log n)  | O(1)               |
+//! | `contains`               | O(log n)  | O(1)               |
+//! | `to_vec`                 | ordenado  | sin orden          |
+//!
+//! El algebra de conjuntos (`union`, `intersection`, `difference`) tiene una implementación por defecto que solo usa
+//! las operaciones basicas, recorre por referencia el conjunto más pequeño cuando el resultado lo permite y clona solo
+//! los elementos que terminan en el resultado.
+
+///### Set
+/// Conjunto sin elementos repetidos.
+pub trait Set<T> : Sized{
+    ///Iterador por referencia de los elementos.
+    type Iter<'a> : Iterator<Item = &'a T> where Self : 'a, T : 'a;
+    ///Un conjunto vacio del mismo tipo.
+    fn new_set() -> Self;
+    ///Inserta el valor, retorna `false` si ya existía.
+    fn insert(&mut self , value : T) -> bool;
+    fn contains(&self , value : &T) -> bool;
+    ///Elimina el valor, retorna `false` si no existía.
+    fn remove(&mut self , value : &T) -> bool;
+    fn len(&self) -> usize;
+    fn empty(&self) -> bool{
+        self.len() == 0
+    }
+    ///Recorre los elementos sin copiarlos, en orden si el conjunto es ordenado.
+    fn iter(&self) -> Self::Iter<'_>;
+    ///Copia de los elementos, en orden si el conjunto es ordenado.
+    fn to_vec(&self) -> Vec<T>
+    where T : Clone{
+        self.iter().cloned().collect()
+    }
+    ///Elementos que están en alguno de los dos conjuntos.
+    fn union(&self , other : &Self) -> Self
+    where T : Clone{
+        let (larger, smaller): (&Self, &Self) = if self.len() >= other.len() { (self, other) } else { (other, self) };
+        let mut result: Self = Self::new_set();
+        for value in larger.iter(){
+            result.insert(value.clone());
+        }
+        for value in smaller.iter(){
+            if !larger.contains(value){
+                result.insert(value.clone());
+            }
+        }
+        result
+    }
+    ///Elementos que están en ambos conjuntos.
+    fn intersection(&self , other : &Self) -> Self
+    where T : Clone{
+        let (larger, smaller): (&Self, &Self) = if self.len() >= other.len() { (self, other) } else { (other, self) };
+        let mut result: Self = Self::new_set();
+        for value in smaller.iter(){
+            if larger.contains(value){
+                result.insert(value.clone());
+            }
+        }
+        result
+    }
+    ///Elementos de `self` que no están en `other`.
+    fn difference(&self , other : &Self) -> Self
+    where T : Clone{
+        let mut result: Self = Self::new_set();
+        for value in self.iter(){
+            if !other.contains(value){
+                result.insert(value.clone());
+            }
+        }
+        result
+    }
+    fn is_subset(&self , other : &Self) -> bool{
+        self.len() <= other.len() && self.iter().all(|value| other.contains(value))
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::Set;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::BuildHasherDefault;
+    use crate::lineal::safety::hash_set::HashSet as SafeHashSet;
+    use crate::lineal::unsafety::hash_set::HashSet as UnsafeHashSet;
+    use crate::no_lineal::safety::avl_tree::AVLTree;
+
+    fn build<S : Set<i32>>(values : &[i32]) -> S{
+        let mut set: S = S::new_set();
+        for &value in values{
+            set.insert(value);
+        }
+        set
+    }
+    fn sorted<S : Set<i32>>(set : &S) -> Vec<i32>{
+        let mut values: Vec<i32> = set.to_vec();
+        values.sort();
+        values
+    }
+    ///Las mismas operaciones deben dar el mismo resultado sin importar la implementación.
+    fn check_set<S : Set<i32>>(){
+        let mut set: S = build(&[5 , 1 , 3]);
+        assert!(!set.insert(3));
+        assert!(set.insert(4));
+        assert_eq!(set.len() , 4);
+        assert!(set.contains(&1));
+        assert!(!set.contains(&2));
+        assert!(set.remove(&1));
+        assert!(!set.remove(&1));
+        assert_eq!(sorted(&set) , vec![3 , 4 , 5]);
+        let other: S = build(&[4 , 5 , 6 , 7]);
+        assert_eq!(sorted(&set.union(&other)) , vec![3 , 4 , 5 , 6 , 7]);
+        assert_eq!(sorted(&set.intersection(&other)) , vec![4 , 5]);
+        assert_eq!(sorted(&other.intersection(&set)) , vec![4 , 5]);
+        assert_eq!(sorted(&set.difference(&other)) , vec![3]);
+        assert_eq!(sorted(&other.difference(&set)) , vec![6 , 7]);
+        assert!(build::<S>(&[4 , 5]).is_subset(&set));
+        assert!(!set.is_subset(&other));
+        assert!(S::new_set().empty());
+    }
+    #[test]
+    fn avl_tree_as_set(){
+        check_set::<AVLTree<i32>>();
+        //El AVL entrega sus elementos en orden
+        let tree: AVLTree<i32> = build(&[9 , 2 , 7 , 2 , 4]);
+        assert_eq!(tree.to_vec() , vec![2 , 4 , 7 , 9]);
+    }
+    #[test]
+    fn safe_hash_set_as_set(){
+        check_set::<SafeHashSet<i32>>();
+        //Cualquier hasher construible por defecto sirve, no solo `RandomState`
+        check_set::<SafeHashSet<i32, BuildHasherDefault<DefaultHasher>>>();
+    }
+    #[test]
+    fn unsafe_hash_set_as_set(){
+        check_set::<UnsafeHashSet<i32>>();
+        check_set::<UnsafeHashSet<i32, BuildHasherDefault<DefaultHasher>>>();
+    }
+    #[test]
+    fn implementations_agree_on_random_operations(){
+        let mut tree: AVLTree<i32> = AVLTree::new();
+        let mut safe: SafeHashSet<i32> = SafeHashSet::new();
+        let mut seed: i32 = 7;
+        for step in 0..2_000{
+            seed = (seed * 1_103 + 12_345) % 1_009;
+            let value: i32 = seed % 200;
+            if step % 3 == 2{
+                assert_eq!(Set::remove(&mut tree, &value) , Set::remove(&mut safe, &value));
+            }else {
+                assert_eq!(Set::insert(&mut tree, value) , Set::insert(&mut safe, value));
+            }
+        }
+        assert_eq!(tree.to_vec() , sorted(&safe));
+    }
+}
@@ -16,6 +16,34 @@ pub struct NodeLink<T> {
     value: T,
     next: Option<Box<NodeLink<T>>>,
 }
+///### Manejo de cadenas de nodos
+/// Permite que otras estructuras del crate (como los buckets de `ChainedHashMap`) armen sus propias cadenas de nodos
+/// sin las restricciones numericas de `SinglyLinkedList`.
+impl<T> NodeLink<T>{
+    pub(crate) fn new(value : T , next : Option<Box<NodeLink<T>>>) -> Self{
+        Self{ value, next }
+    }
+    pub fn value(&self) -> &T{
+        &self.value
+    }
+    pub fn value_mut(&mut self) -> &mut T{
+        &mut self.value
+    }
+    pub(crate) fn next(&self) -> Option<&NodeLink<T>>{
+        self.next.as_deref()
+    }
+    pub(crate) fn next_mut(&mut self) -> &mut Option<Box<NodeLink<T>>>{
+        &mut self.next
+    }
+    ///El valor y el siguiente nodo prestados al mismo tiempo.
+    pub(crate) fn parts_mut(&mut self) -> (&mut T, Option<&mut NodeLink<T>>){
+        (&mut self.value, self.next.as_deref_mut())
+    }
+    ///Desarma el nodo en su valor y el resto de la cadena.
+    pub(crate) fn into_parts(self) -> (T, Option<Box<NodeLink<T>>>){
+        (self.value, self.next)
+    }
+}
 ///### SinglyLinkedList
 /// Esta estructura compone la opcionalidad de un nodo almacenado en heap y linkea los nodos, de manera logica.
 ///```
@@ -311,6 +339,18 @@ where T : Integer + Clone + Copy + Display + Debug{
         }
     }
 }
+///### Drop
+/// La liberación por defecto de `Option<Box<NodeLink<T>>>` es recursiva, cada `Box` libera a su siguiente antes de terminar,
+/// por lo que una lista de cientos de miles de nodos desborda el stack. Aqui se desenlaza la lista nodo a nodo desde la cabeza
+/// de manera iterativa, de esta forma cada nodo se libera sin un siguiente enlazado.
+impl<T> Drop for SinglyLinkedList<T>{
+    fn drop(&mut self){
+        let mut current: Option<Box<NodeLink<T>>> = self.head.take();
+        while let Some(mut node) = current{
+            current = node.next.take();
+        }
+    }
+}
 #[cfg(test)]
 mod tests{
     use super::*;
@@ -369,4 +409,15 @@ mod tests{
         assert_eq!(list.remove_first_ocurrence(70).unwrap() , 70 , "No coincide el valor eliminado con el comparado");
         assert_eq!(list.len() , 4);
     }
-}
\ No newline at end of file
+    #[test]
+    fn drop_million_nodes(){
+        let mut list: SinglyLinkedList<i32> = SinglyLinkedList::new();
+        //Se enlaza por la cabeza para no recorrer la lista en cada inserción
+        for i in 0..1_000_000{
+            list.head = Some(Box::new(NodeLink{ value : i, next : list.head.take() }));
+            list.size += 1;
+        }
+        assert_eq!(list.len() , 1_000_000);
+        drop(list);
+    }
+}
@@ -0,0 +1,324 @@
+//! ### Sobre las Skip Lists
+//! Una skip list es una lista enlazada ordenada con carriles rápidos: cada nodo tiene una torre de punteros de altura
+//! aleatoria (nivel `k` con probabilidad `1/2^k`), los niveles altos saltan muchos nodos y las busquedas bajan de nivel
+//! cuando el siguiente nodo se pasa del valor buscado.
+//! ```text
+//!   nivel 2 : HEAD ---------------------------> 30 -------------------> None
+//!   nivel 1 : HEAD ----------> 10 ------------> 30 ---------> 50 -----> None
+//!   nivel 0 : HEAD --> 5 ----> 10 ----> 20 ---> 30 --> 40 --> 50 -----> None
+//! ```
+//! En promedio `insert`, `remove` y `contains` son O(log(n)) igual que un `AVLTree`, pero sin rotaciones: el balanceo
+//! depende solo del generador aleatorio, por ello `with_seed` permite reproducir exactamente la misma forma.
+//!
+//! En esta versión safe los nodos viven en una arena (`Vec<Node<T>>`) y las torres guardan indices, el nodo `0` es la
+//! cabecera. Los indices liberados por `remove` se reutilizan.
+use std::fmt::{self, Debug};
+use std::ops::{Bound, RangeBounds};
+use crate::lineal::safety::set::Set;
+use crate::random::XorShift64;
+
+///Altura máxima de una torre, suficiente para 2^32 elementos con p = 1/2.
+pub(crate) const MAX_LEVEL : usize = 32;
+const HEAD : usize = 0;
+
+struct Node<T>{
+    ///`None` en la cabecera y en los nodos libres.
+    value : Option<T>,
+    next : Vec<Option<usize>>
+}
+///### SkipList
+/// Conjunto ordenado sin repetidos.
+pub struct SkipList<T>{
+    nodes : Vec<Node<T>>,
+    free : Vec<usize>,
+    level : usize,
+    len : usize,
+    rng : XorShift64
+}
+impl<T : Ord> SkipList<T>{
+    pub fn new() -> Self{
+        Self::with_rng(XorShift64::from_entropy())
+    }
+    ///Misma semilla y mismas operaciones producen las mismas torres.
+    pub fn with_seed(seed : u64) -> Self{
+        Self::with_rng(XorShift64::new(seed))
+    }
+    fn with_rng(rng : XorShift64) -> Self{
+        Self{
+            nodes : vec![Node{ value : None, next : vec![None; MAX_LEVEL] }],
+            free : Vec::new(),
+            level : 1,
+            len : 0,
+            rng
+        }
+    }
+    pub fn len(&self) -> usize{
+        self.len
+    }
+    pub fn empty(&self) -> bool{
+        self.len == 0
+    }
+    ///Cantidad de niveles en uso.
+    pub fn height(&self) -> usize{
+        self.level
+    }
+    fn value(&self , index : usize) -> &T{
+        self.nodes[index].value.as_ref().expect("la torre apunta a un nodo libre")
+    }
+    ///Siguiente nodo de `index` en `level` si cumple `advance`.
+    fn step(&self , index : usize , level : usize , advance : impl Fn(&T) -> bool) -> Option<usize>{
+        self.nodes[index].next[level].filter(|&next| advance(self.value(next)))
+    }
+    ///Último nodo de cada nivel que cumple `advance`, es decir el predecesor donde se enlazaría.
+    fn predecessors(&self , advance : impl Fn(&T) -> bool) -> [usize; MAX_LEVEL]{
+        let mut update: [usize; MAX_LEVEL] = [HEAD; MAX_LEVEL];
+        let mut current: usize = HEAD;
+        for level in (0..self.level).rev(){
+            while let Some(next) = self.step(current, level, &advance){
+                current = next;
+            }
+            update[level] = current;
+        }
+        update
+    }
+    ///Primer nodo que no cumple `advance`.
+    fn first_not(&self , advance : impl Fn(&T) -> bool) -> Option<usize>{
+        let current: usize = self.predecessors(advance)[0];
+        self.nodes[current].next[0]
+    }
+    fn find(&self , value : &T) -> Option<usize>{
+        self.first_not(|v| v < value).filter(|&index| self.value(index) == value)
+    }
+    pub fn contains(&self , value : &T) -> bool{
+        self.find(value).is_some()
+    }
+    ///Inserta el valor, retorna `false` si ya existía.
+    pub fn insert(&mut self , value : T) -> bool{
+        let mut update: [usize; MAX_LEVEL] = self.predecessors(|v| *v < value);
+        if let Some(next) = self.nodes[update[0]].next[0]{
+            if *self.value(next) == value{
+                return false;
+            }
+        }
+        let height: usize = self.rng.level(MAX_LEVEL);
+        if height > self.level{
+            //Los niveles nuevos solo tienen a la cabecera como predecesor
+            update[self.level..height].fill(HEAD);
+            self.level = height;
+        }
+        let next: Vec<Option<usize>> = (0..height).map(|level| self.nodes[update[level]].next[level]).collect();
+        let node: Node<T> = Node{ value : Some(value), next };
+        let index: usize = match self.free.pop(){
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            },
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+        for (level, &predecessor) in update.iter().enumerate().take(height){
+            self.nodes[predecessor].next[level] = Some(index);
+        }
+        self.len += 1;
+        true
+    }
+    ///Elimina y retorna el valor si existía.
+    pub fn remove(&mut self , value : &T) -> Option<T>{
+        let update: [usize; MAX_LEVEL] = self.predecessors(|v| v < value);
+        let target: usize = self.nodes[update[0]].next[0].filter(|&index| self.value(index) == value)?;
+        let next: Vec<Option<usize>> = std::mem::take(&mut self.nodes[target].next);
+        for (level, successor) in next.into_iter().enumerate(){
+            self.nodes[update[level]].next[level] = successor;
+        }
+        while self.level > 1 && self.nodes[HEAD].next[self.level - 1].is_none(){
+            self.level -= 1;
+        }
+        self.free.push(target);
+        self.len -= 1;
+        self.nodes[target].value.take()
+    }
+    pub fn first(&self) -> Option<&T>{
+        self.nodes[HEAD].next[0].map(|index| self.value(index))
+    }
+    ///Valores en orden ascendente.
+    pub fn iter(&self) -> Iter<'_, T>{
+        Iter{ list : self, next : self.nodes[HEAD].next[0], stop : None }
+    }
+    ///Valores dentro del rango en orden ascendente, se ubica el inicio y el fin con dos busquedas O(log(n)).
+    pub fn range<R : RangeBounds<T>>(&self , range : R) -> Iter<'_, T>{
+        let next: Option<usize> = match range.start_bound(){
+            Bound::Included(start) => self.first_not(|v| v < start),
+            Bound::Excluded(start) => self.first_not(|v| v <= start),
+            Bound::Unbounded => self.nodes[HEAD].next[0]
+        };
+        let stop: Option<usize> = match range.end_bound(){
+            Bound::Included(end) => self.first_not(|v| v <= end),
+            Bound::Excluded(end) => self.first_not(|v| v < end),
+            Bound::Unbounded => None
+        };
+        //Si el primer candidato ya queda fuera (rango vacio o invertido) no se recorre nada
+        let next: Option<usize> = next.filter(|&index| range.contains(self.value(index)));
+        Iter{ list : self, next, stop }
+    }
+    pub fn clear(&mut self){
+        self.nodes.truncate(1);
+        self.nodes[HEAD].next.fill(None);
+        self.free.clear();
+        self.level = 1;
+        self.len = 0;
+    }
+    ///Alturas de las torres en orden, permite comparar la forma de dos listas.
+    #[cfg(test)]
+    pub(crate) fn tower_heights(&self) -> Vec<usize>{
+        let mut heights: Vec<usize> = Vec::with_capacity(self.len);
+        let mut current: Option<usize> = self.nodes[HEAD].next[0];
+        while let Some(index) = current{
+            heights.push(self.nodes[index].next.len());
+            current = self.nodes[index].next[0];
+        }
+        heights
+    }
+}
+impl<T : Ord> Default for SkipList<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T : Ord> Set<T> for SkipList<T>{
+    type Iter<'a> = Iter<'a, T> where Self : 'a, T : 'a;
+    fn new_set() -> Self{
+        Self::new()
+    }
+    fn insert(&mut self , value : T) -> bool{
+        SkipList::insert(self, value)
+    }
+    fn contains(&self , value : &T) -> bool{
+        SkipList::contains(self, value)
+    }
+    fn remove(&mut self , value : &T) -> bool{
+        SkipList::remove(self, value).is_some()
+    }
+    fn len(&self) -> usize{
+        self.len
+    }
+    fn iter(&self) -> Iter<'_, T>{
+        SkipList::iter(self)
+    }
+}
+impl<T : Ord + Debug> Debug for SkipList<T>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+impl<T : Ord> FromIterator<T> for SkipList<T>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        let mut list: SkipList<T> = SkipList::new();
+        list.extend(iter);
+        list
+    }
+}
+impl<T : Ord> Extend<T> for SkipList<T>{
+    fn extend<I : IntoIterator<Item = T>>(&mut self , iter : I){
+        for value in iter{
+            self.insert(value);
+        }
+    }
+}
+///Iterador ordenado por el nivel 0, termina al llegar a `stop`.
+pub struct Iter<'a, T>{
+    list : &'a SkipList<T>,
+    next : Option<usize>,
+    stop : Option<usize>
+}
+impl<'a, T : Ord> Iterator for Iter<'a, T>{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T>{
+        let index: usize = self.next.filter(|&index| Some(index) != self.stop)?;
+        self.next = self.list.nodes[index].next[0];
+        Some(self.list.value(index))
+    }
+}
+impl<'a, T : Ord> IntoIterator for &'a SkipList<T>{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Iter<'a, T>{
+        self.iter()
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn pseudo_random(count : usize , seed : u64) -> Vec<i32>{
+        let mut rng: XorShift64 = XorShift64::new(seed);
+        (0..count).map(|_| (rng.next_u64() % 500) as i32).collect()
+    }
+    #[test]
+    fn matches_btree_set_on_random_operations(){
+        let mut list: SkipList<i32> = SkipList::with_seed(42);
+        let mut expected: BTreeSet<i32> = BTreeSet::new();
+        for (step, value) in pseudo_random(5_000, 3).into_iter().enumerate(){
+            if step % 3 == 2{
+                assert_eq!(list.remove(&value).is_some() , expected.remove(&value));
+            }else {
+                assert_eq!(list.insert(value) , expected.insert(value));
+            }
+            assert_eq!(list.len() , expected.len());
+        }
+        assert!(list.iter().eq(expected.iter()));
+        assert_eq!(list.first() , expected.first());
+        for value in 0..500{
+            assert_eq!(list.contains(&value) , expected.contains(&value));
+        }
+    }
+    #[test]
+    fn range_matches_btree_set(){
+        let list: SkipList<i32> = (0..100).map(|v| v * 3).collect();
+        let expected: BTreeSet<i32> = (0..100).map(|v| v * 3).collect();
+        for (start, end) in [(10, 40), (0, 0), (-5, 7), (297, 400), (31, 33)]{
+            assert!(list.range(start..end).eq(expected.range(start..end)), "{start}..{end}");
+            assert!(list.range(start..=end).eq(expected.range(start..=end)), "{start}..={end}");
+            assert!(list.range((Bound::Excluded(start), Bound::Unbounded)).eq(expected.range((Bound::Excluded(start), Bound::Unbounded))));
+        }
+        //A diferencia de BTreeSet un rango invertido no entra en pánico, solo queda vacio
+        assert_eq!(list.range(50..20).count() , 0);
+        assert_eq!(list.range(..).count() , 100);
+        assert!(list.range(40..).take(2).eq([42, 45].iter()));
+    }
+    #[test]
+    fn same_seed_builds_the_same_towers(){
+        let values: Vec<i32> = pseudo_random(1_000, 9);
+        let first: SkipList<i32> = {
+            let mut list: SkipList<i32> = SkipList::with_seed(7);
+            list.extend(values.iter().copied());
+            list
+        };
+        let mut second: SkipList<i32> = SkipList::with_seed(7);
+        second.extend(values.iter().copied());
+        assert_eq!(first.tower_heights() , second.tower_heights());
+        assert_eq!(first.height() , second.height());
+        assert!(first.height() > 1 && first.height() < 20);
+    }
+    #[test]
+    fn removed_nodes_are_reused_and_height_shrinks(){
+        let mut list: SkipList<String> = SkipList::with_seed(1);
+        for value in 0..64{
+            list.insert(format!("{value:03}"));
+        }
+        let arena: usize = list.nodes.len();
+        for value in 0..64{
+            assert_eq!(list.remove(&format!("{value:03}")) , Some(format!("{value:03}")));
+        }
+        assert!(list.empty());
+        assert_eq!(list.height() , 1);
+        for value in 0..64{
+            list.insert(format!("{value:03}"));
+        }
+        assert_eq!(list.nodes.len() , arena);
+        list.clear();
+        assert_eq!(list.iter().count() , 0);
+    }
+}
@@ -0,0 +1,105 @@
+//! ### Sobre las pilas (Stack)
+//! Una pila es una estructura LIFO (Last In, First Out), el ultimo elemento insertado es el primero en salir.
+//! Se construye sobre `Deque` (que a su vez usa una `DoublyLinkedList`) restringiendo las operaciones al final de la cola.
+//! ```text
+//!              | 30 | <-- push / pop
+//!              | 20 |
+//!              | 10 |
+//!              ------
+//! ```
+use std::cell::{Ref, RefMut};
+use super::deque::Deque;
+
+///### Stack
+/// Pila LIFO safe sobre `Deque`, tanto `push` como `pop` son O(1).
+#[derive(Debug)]
+pub struct Stack<T>{
+    deque : Deque<T>
+}
+impl<T> Default for Stack<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T> Stack<T>{
+    pub fn new() -> Self{
+        Self{
+            deque : Deque::new()
+        }
+    }
+    pub fn with_capacity(capacity : usize) -> Self{
+        Self{
+            deque : Deque::with_capacity(capacity)
+        }
+    }
+    pub fn len(&self) -> usize{
+        self.deque.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.deque.empty()
+    }
+    ///Inserta un elemento en la cima de la pila.
+    pub fn push(&mut self , value : T){
+        self.deque.push_back(value);
+    }
+    ///Elimina y retorna el elemento de la cima de la pila.
+    pub fn pop(&mut self) -> Option<T>{
+        self.deque.pop_back()
+    }
+    ///El siguiente elemento en salir de la pila.
+    pub fn peek(&self) -> Option<Ref<'_, T>>{
+        self.deque.back()
+    }
+    pub fn peek_mut(&mut self) -> Option<RefMut<'_, T>>{
+        self.deque.back_mut()
+    }
+}
+///Iterador que consume la pila desde la cima.
+pub struct IntoIter<T>{
+    stack : Stack<T>
+}
+impl<T> Iterator for IntoIter<T>{
+    type Item = T;
+    fn next(&mut self) -> Option<T>{
+        self.stack.pop()
+    }
+}
+impl<T> IntoIterator for Stack<T>{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> IntoIter<T>{
+        IntoIter{ stack : self }
+    }
+}
+impl<T> FromIterator<T> for Stack<T>{
+    ///El ultimo elemento del iterador queda en la cima.
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        let mut stack: Stack<T> = Stack::new();
+        for value in iter{
+            stack.push(value);
+        }
+        stack
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn lifo_order(){
+        let mut stack: Stack<i32> = Stack::new();
+        assert!(stack.empty());
+        assert_eq!(stack.pop() , None);
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        assert_eq!(stack.len() , 3);
+        assert_eq!(*stack.peek().unwrap() , 3);
+        *stack.peek_mut().unwrap() = 30;
+        assert_eq!(stack.pop() , Some(30));
+        stack.push(4);
+        assert_eq!(stack.into_iter().collect::<Vec<i32>>() , vec![4, 2, 1]);
+        let stack: Stack<i32> = (1..=3).collect();
+        assert_eq!(stack.into_iter().collect::<Vec<i32>>() , vec![3, 2, 1]);
+    }
+}
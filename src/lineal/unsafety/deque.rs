@@ -0,0 +1,353 @@
+//! ### Sobre la cola doble con buffer circular
+//! La version unsafe de `Deque` almacena sus elementos en un unico bloque de memoria reservado con `std::alloc`, tratado
+//! como un anillo, `head` indica la posición fisica del frente y los elementos ocupan `len` posiciones consecutivas
+//! dando la vuelta al final del bloque cuando es necesario.
+//! ```text
+//!   capacity = 8 , head = 6 , len = 5
+//!     0     1     2     3     4     5     6     7
+//!  +-----+-----+-----+-----+-----+-----+-----+-----+
+//!  |  c  |  d  |  e  |     |     |     |  a  |  b  |
+//!  +-----+-----+-----+-----+-----+-----+-----+-----+
+//!                 ↑ back                  ↑ front
+//! ```
+//! Cuando el anillo se llena se reserva un bloque del doble de capacidad y se copian los dos tramos (`a b` y `c d e`)
+//! de manera contigua al inicio del nuevo bloque, por lo que las inserciones son O(1) amortizado.
+//! ### Invariantes
+//! - Las posiciones `head..head + len` (modulo `capacity`) siempre están inicializadas y el resto no.
+//! - Para tipos de tamaño cero no se reserva memoria y la capacidad es `usize::MAX`.
+use std::alloc::{self, Layout};
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::{self, NonNull};
+
+///### Deque
+/// Cola doble sobre un buffer circular de crecimiento dinamico.
+pub struct Deque<T>{
+    ptr : NonNull<T>,
+    capacity : usize,
+    head : usize,
+    len : usize,
+    _marker : PhantomData<T>
+}
+unsafe impl<T : Send> Send for Deque<T>{}
+unsafe impl<T : Sync> Sync for Deque<T>{}
+impl<T> Default for Deque<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T> Deque<T>{
+    const MIN_CAPACITY : usize = 4;
+    pub fn new() -> Self{
+        Self::with_capacity(0)
+    }
+    ///Reserva de antemano espacio para `capacity` elementos.
+    pub fn with_capacity(capacity : usize) -> Self{
+        if mem::size_of::<T>() == 0{
+            return Self{ ptr : NonNull::dangling(), capacity : usize::MAX, head : 0, len : 0, _marker : PhantomData };
+        }
+        Self{
+            ptr : Self::allocate(capacity),
+            capacity,
+            head : 0,
+            len : 0,
+            _marker : PhantomData
+        }
+    }
+    pub fn len(&self) -> usize{
+        self.len
+    }
+    pub fn empty(&self) -> bool{
+        self.len == 0
+    }
+    pub fn capacity(&self) -> usize{
+        self.capacity
+    }
+    fn allocate(capacity : usize) -> NonNull<T>{
+        if capacity == 0{
+            return NonNull::dangling();
+        }
+        let layout: Layout = Layout::array::<T>(capacity).expect("La capacidad excede el tamaño maximo de una reserva");
+        //SAFETY: el layout tiene tamaño distinto de cero porque capacity > 0 y T no es de tamaño cero
+        let raw: *mut T = unsafe { alloc::alloc(layout) as *mut T };
+        match NonNull::new(raw) {
+            Some(ptr) => ptr,
+            None => alloc::handle_alloc_error(layout)
+        }
+    }
+    ///Posición fisica del elemento que se encuentra `offset` lugares despues del frente.
+    fn physical(&self , offset : usize) -> usize{
+        let until_end: usize = self.capacity - self.head;
+        if offset < until_end{
+            self.head + offset
+        }else {
+            offset - until_end
+        }
+    }
+    ///### Grow
+    /// Duplica la capacidad copiando los dos tramos del anillo de manera contigua al inicio del nuevo bloque.
+    /// ```text
+    ///   antes:  | c | d | e | a | b |          head = 3
+    ///   despues:| a | b | c | d | e |   |   |   |   |   |   head = 0
+    /// ```
+    fn grow(&mut self){
+        let new_capacity: usize = (self.capacity * 2).max(Self::MIN_CAPACITY);
+        let new_ptr: NonNull<T> = Self::allocate(new_capacity);
+        let (first, second): (usize, usize) = self.segment_lengths();
+        //SAFETY: ambos tramos están inicializados y el nuevo bloque tiene espacio para len elementos, los bloques no se solapan
+        unsafe {
+            ptr::copy_nonoverlapping(self.ptr.as_ptr().add(self.head), new_ptr.as_ptr(), first);
+            ptr::copy_nonoverlapping(self.ptr.as_ptr(), new_ptr.as_ptr().add(first), second);
+        }
+        self.deallocate();
+        self.ptr = new_ptr;
+        self.capacity = new_capacity;
+        self.head = 0;
+    }
+    //Libera el bloque sin liberar los elementos, estos ya fueron movidos o liberados
+    fn deallocate(&mut self){
+        if self.capacity != 0 && mem::size_of::<T>() != 0{
+            //SAFETY: el bloque se reservo con este mismo layout
+            unsafe { alloc::dealloc(self.ptr.as_ptr() as *mut u8, Layout::array::<T>(self.capacity).unwrap()) }
+        }
+    }
+    ///Largos del tramo que va desde `head` hasta el final del bloque y del tramo que da la vuelta al inicio.
+    fn segment_lengths(&self) -> (usize, usize){
+        let until_end: usize = self.capacity - self.head;
+        if self.len <= until_end{
+            (self.len, 0)
+        }else {
+            (until_end, self.len - until_end)
+        }
+    }
+    pub fn push_back(&mut self , value : T){
+        if self.len == self.capacity{
+            self.grow();
+        }
+        //SAFETY: la posición siguiente al final está dentro del bloque y no inicializada
+        unsafe { ptr::write(self.ptr.as_ptr().add(self.physical(self.len)), value) }
+        self.len += 1;
+    }
+    pub fn push_front(&mut self , value : T){
+        if self.len == self.capacity{
+            self.grow();
+        }
+        self.head = if self.head == 0 { self.capacity - 1 } else { self.head - 1 };
+        //SAFETY: la posición anterior al frente está dentro del bloque y no inicializada
+        unsafe { ptr::write(self.ptr.as_ptr().add(self.head), value) }
+        self.len += 1;
+    }
+    pub fn pop_front(&mut self) -> Option<T>{
+        if self.len == 0{
+            return None;
+        }
+        //SAFETY: el frente está inicializado, al avanzar head la posición queda fuera de los elementos vivos
+        let value: T = unsafe { ptr::read(self.ptr.as_ptr().add(self.head)) };
+        self.head = self.physical(1);
+        self.len -= 1;
+        Some(value)
+    }
+    pub fn pop_back(&mut self) -> Option<T>{
+        if self.len == 0{
+            return None;
+        }
+        self.len -= 1;
+        //SAFETY: la posición del ultimo elemento está inicializada y al disminuir len queda fuera de los elementos vivos
+        Some(unsafe { ptr::read(self.ptr.as_ptr().add(self.physical(self.len))) })
+    }
+    ///Referencia al elemento que se encuentra `index` lugares despues del frente.
+    pub fn get(&self , index : usize) -> Option<&T>{
+        if index >= self.len{
+            return None;
+        }
+        //SAFETY: index < len, la posición está inicializada
+        Some(unsafe { &*self.ptr.as_ptr().add(self.physical(index)) })
+    }
+    pub fn get_mut(&mut self , index : usize) -> Option<&mut T>{
+        if index >= self.len{
+            return None;
+        }
+        //SAFETY: index < len, la posición está inicializada y &mut self garantiza exclusividad
+        Some(unsafe { &mut *self.ptr.as_ptr().add(self.physical(index)) })
+    }
+    pub fn front(&self) -> Option<&T>{
+        self.get(0)
+    }
+    pub fn back(&self) -> Option<&T>{
+        self.len.checked_sub(1).and_then(|index| self.get(index))
+    }
+    pub fn front_mut(&mut self) -> Option<&mut T>{
+        self.get_mut(0)
+    }
+    pub fn back_mut(&mut self) -> Option<&mut T>{
+        match self.len.checked_sub(1) {
+            None => None,
+            Some(index) => self.get_mut(index)
+        }
+    }
+    ///Los elementos en orden lógico divididos en los dos tramos contiguos del anillo.
+    pub fn as_slices(&self) -> (&[T], &[T]){
+        let (first, second): (usize, usize) = self.segment_lengths();
+        //SAFETY: ambos tramos están inicializados y dentro del bloque
+        unsafe {
+            (std::slice::from_raw_parts(self.ptr.as_ptr().add(self.head), first),
+             std::slice::from_raw_parts(self.ptr.as_ptr(), second))
+        }
+    }
+    pub fn iter(&self) -> Iter<'_, T>{
+        Iter{
+            deque : self,
+            front : 0,
+            back : self.len
+        }
+    }
+    ///Elimina todos los elementos conservando la capacidad.
+    pub fn clear(&mut self){
+        while self.pop_front().is_some(){}
+        self.head = 0;
+    }
+}
+impl<T> Drop for Deque<T>{
+    fn drop(&mut self){
+        let (first, second): (*mut [T], *mut [T]) = {
+            let (first, second): (&[T], &[T]) = self.as_slices();
+            (first as *const [T] as *mut [T], second as *const [T] as *mut [T])
+        };
+        //Si liberar un elemento entra en panico el guardia libera el bloque de todas maneras
+        struct Deallocate<'a, T>(&'a mut Deque<T>);
+        impl<T> Drop for Deallocate<'_, T>{
+            fn drop(&mut self){
+                self.0.deallocate();
+            }
+        }
+        let _guard: Deallocate<'_, T> = Deallocate(self);
+        //SAFETY: los tramos contienen los elementos vivos y no se vuelven a utilizar
+        unsafe {
+            ptr::drop_in_place(first);
+            ptr::drop_in_place(second);
+        }
+    }
+}
+impl<T : Debug> Debug for Deque<T>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+///Iterador por referencia de `Deque`, recorre las posiciones lógicas `front..back`.
+pub struct Iter<'a, T>{
+    deque : &'a Deque<T>,
+    front : usize,
+    back : usize
+}
+impl<'a, T> Iterator for Iter<'a, T>{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T>{
+        if self.front == self.back{
+            return None;
+        }
+        let value: Option<&'a T> = self.deque.get(self.front);
+        self.front += 1;
+        value
+    }
+    fn size_hint(&self) -> (usize, Option<usize>){
+        (self.back - self.front, Some(self.back - self.front))
+    }
+}
+impl<T> DoubleEndedIterator for Iter<'_, T>{
+    fn next_back(&mut self) -> Option<Self::Item>{
+        if self.front == self.back{
+            return None;
+        }
+        self.back -= 1;
+        self.deque.get(self.back)
+    }
+}
+impl<T> ExactSizeIterator for Iter<'_, T>{}
+impl<'a, T> IntoIterator for &'a Deque<T>{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Iter<'a, T>{
+        self.iter()
+    }
+}
+///Iterador que consume la cola desde el frente.
+pub struct IntoIter<T>{
+    deque : Deque<T>
+}
+impl<T> Iterator for IntoIter<T>{
+    type Item = T;
+    fn next(&mut self) -> Option<T>{
+        self.deque.pop_front()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>){
+        (self.deque.len(), Some(self.deque.len()))
+    }
+}
+impl<T> DoubleEndedIterator for IntoIter<T>{
+    fn next_back(&mut self) -> Option<T>{
+        self.deque.pop_back()
+    }
+}
+impl<T> ExactSizeIterator for IntoIter<T>{}
+impl<T> IntoIterator for Deque<T>{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> IntoIter<T>{
+        IntoIter{ deque : self }
+    }
+}
+impl<T> FromIterator<T> for Deque<T>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        let iter = iter.into_iter();
+        let mut deque: Deque<T> = Deque::with_capacity(iter.size_hint().0);
+        for value in iter{
+            deque.push_back(value);
+        }
+        deque
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    crate::lineal::queue_tests::deque_tests!(Deque);
+    #[test]
+    fn as_slices_after_wrapping(){
+        let mut deque : Deque<i32> = Deque::with_capacity(4);
+        deque.push_back(2);
+        deque.push_back(3);
+        deque.push_front(1);
+        deque.push_front(0);
+        assert_eq!(deque.capacity() , 4);
+        assert_eq!(deque.as_slices() , (&[0 , 1][..] , &[2 , 3][..]));
+        deque.push_back(4);
+        assert_eq!(deque.capacity() , 8);
+        assert_eq!(deque.as_slices() , (&[0 , 1 , 2 , 3 , 4][..] , &[][..]));
+    }
+    #[test]
+    fn zero_sized_types(){
+        let mut deque : Deque<()> = Deque::new();
+        for _ in 0..1_000{
+            deque.push_back(());
+        }
+        assert_eq!(deque.len() , 1_000);
+        assert_eq!(deque.iter().count() , 1_000);
+        assert_eq!(deque.pop_front() , Some(()));
+        assert_eq!(deque.pop_back() , Some(()));
+        assert_eq!(deque.len() , 998);
+    }
+    #[test]
+    fn drops_every_element_once(){
+        use std::rc::Rc;
+        let counter: Rc<()> = Rc::new(());
+        {
+            let mut deque : Deque<Rc<()>> = Deque::with_capacity(2);
+            for i in 0..10{
+                if i % 2 == 0 { deque.push_back(counter.clone()) } else { deque.push_front(counter.clone()) }
+            }
+            deque.pop_front();
+            assert_eq!(Rc::strong_count(&counter) , 10);
+        }
+        assert_eq!(Rc::strong_count(&counter) , 1);
+    }
+}
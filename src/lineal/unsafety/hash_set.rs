@@ -0,0 +1,164 @@
+//! ### HashSet sobre `OpenHashMap`
+//! Mismo conjunto que `lineal::safety::hash_set` pero guardado en la tabla de direccionamiento abierto, por defecto con
+//! sondeo `RobinHood`. Implementa `Set` para poder intercambiarse con los conjuntos ordenados como `AVLTree`.
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::fmt::{self, Debug};
+use std::hash::{BuildHasher, Hash};
+use crate::lineal::safety::set::Set;
+use super::open_hash_map::{self, OpenHashMap, Probing};
+
+///### HashSet
+/// Conjunto por hash con direccionamiento abierto.
+pub struct HashSet<T, S = RandomState>{
+    map : OpenHashMap<T, (), S>
+}
+impl<T> HashSet<T, RandomState>{
+    pub fn new() -> Self{
+        Self::with_probing(Probing::RobinHood)
+    }
+    pub fn with_probing(probing : Probing) -> Self{
+        Self{ map : OpenHashMap::new(probing) }
+    }
+    pub fn with_capacity(capacity : usize) -> Self{
+        Self{ map : OpenHashMap::with_capacity(Probing::RobinHood, capacity) }
+    }
+}
+impl<T, S : Default> Default for HashSet<T, S>{
+    fn default() -> Self{
+        Self::with_hasher(Probing::RobinHood, S::default())
+    }
+}
+impl<T, S> HashSet<T, S>{
+    pub fn with_hasher(probing : Probing , hash_builder : S) -> Self{
+        Self{ map : OpenHashMap::with_hasher(probing, hash_builder) }
+    }
+    pub fn len(&self) -> usize{
+        self.map.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.map.empty()
+    }
+    pub fn probing(&self) -> Probing{
+        self.map.probing()
+    }
+    pub fn iter(&self) -> Iter<'_, T, S>{
+        Iter{ inner : self.map.iter() }
+    }
+    pub fn clear(&mut self){
+        self.map.clear();
+    }
+}
+impl<T : Hash + Eq, S : BuildHasher> HashSet<T, S>{
+    ///Inserta el valor, retorna `false` si ya existía.
+    pub fn insert(&mut self , value : T) -> bool{
+        self.map.insert(value, ()).is_none()
+    }
+    pub fn contains<Q>(&self , value : &Q) -> bool
+    where T : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        self.map.contains_key(value)
+    }
+    ///Elimina el valor, retorna `false` si no existía.
+    pub fn remove<Q>(&mut self , value : &Q) -> bool
+    where T : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        self.map.remove(value).is_some()
+    }
+    ///Elimina y retorna el valor guardado en el conjunto.
+    pub fn take<Q>(&mut self , value : &Q) -> Option<T>
+    where T : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        self.map.remove_entry(value).map(|(value, _)| value)
+    }
+}
+impl<T : Hash + Eq , S : BuildHasher + Default> Set<T> for HashSet<T, S>{
+    type Iter<'a> = Iter<'a, T, S> where Self : 'a, T : 'a;
+    fn new_set() -> Self{
+        Self::default()
+    }
+    fn insert(&mut self , value : T) -> bool{
+        HashSet::insert(self, value)
+    }
+    fn contains(&self , value : &T) -> bool{
+        HashSet::contains(self, value)
+    }
+    fn remove(&mut self , value : &T) -> bool{
+        HashSet::remove(self, value)
+    }
+    fn len(&self) -> usize{
+        HashSet::len(self)
+    }
+    fn iter(&self) -> Iter<'_, T, S>{
+        HashSet::iter(self)
+    }
+}
+impl<T : Hash + Eq, S : BuildHasher> PartialEq for HashSet<T, S>{
+    fn eq(&self , other : &Self) -> bool{
+        self.len() == other.len() && self.iter().all(|value| other.contains(value))
+    }
+}
+impl<T : Debug, S> Debug for HashSet<T, S>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+impl<T : Hash + Eq> FromIterator<T> for HashSet<T, RandomState>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        let mut set: HashSet<T> = HashSet::new();
+        set.extend(iter);
+        set
+    }
+}
+impl<T : Hash + Eq, S : BuildHasher> Extend<T> for HashSet<T, S>{
+    fn extend<I : IntoIterator<Item = T>>(&mut self , iter : I){
+        self.map.extend(iter.into_iter().map(|value| (value, ())));
+    }
+}
+///Iterador por referencia de `HashSet` en el orden de los slots.
+pub struct Iter<'a, T, S>{
+    inner : open_hash_map::Iter<'a, T, (), S>
+}
+impl<'a, T, S> Iterator for Iter<'a, T, S>{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T>{
+        self.inner.next().map(|(value, _)| value)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>){
+        self.inner.size_hint()
+    }
+}
+impl<T, S> ExactSizeIterator for Iter<'_, T, S>{}
+impl<'a, T, S> IntoIterator for &'a HashSet<T, S>{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, S>;
+    fn into_iter(self) -> Iter<'a, T, S>{
+        self.iter()
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    #[test]
+    fn every_probing_behaves_as_a_set(){
+        for probing in [Probing::Linear, Probing::Quadratic, Probing::DoubleHashing, Probing::RobinHood]{
+            let mut set : HashSet<String> = HashSet::with_probing(probing);
+            for value in 0..200{
+                assert!(set.insert(value.to_string()));
+            }
+            assert!(!set.insert(String::from("7")));
+            for value in (0..200).step_by(2){
+                assert!(set.remove(value.to_string().as_str()));
+            }
+            assert_eq!(set.len() , 100);
+            assert!(set.contains("7") && !set.contains("8"), "{probing:?}");
+            assert_eq!(set.take("7") , Some(String::from("7")));
+            assert!(set.take("7").is_none() && set.len() == 99);
+        }
+    }
+    #[test]
+    fn collect_extend_and_compare(){
+        let mut set : HashSet<i32> = (0..10).chain(0..5).collect();
+        assert_eq!(set.len() , 10);
+        set.extend(5..15);
+        let expected : HashSet<i32> = (0..15).rev().collect();
+        assert_eq!(set , expected);
+    }
+}
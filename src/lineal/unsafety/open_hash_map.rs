@@ -0,0 +1,581 @@
+//! ### Sobre las tablas hash de direccionamiento abierto (Open Addressing)
+//! En lugar de encadenar las colisiones, todos los pares viven directamente en un único arreglo de slots. Si el slot de
+//! una clave (`hash & mask`) está ocupado se prueba con otros slots siguiendo una secuencia de sondeo (`Probing`).
+//! - `Linear`: `home, home + 1, home + 2, ...`, excelente localidad de cache pero forma grupos (clustering primario).
+//! - `Quadratic`: `home + 1, home + 3, home + 6, ...` (números triangulares), con capacidad potencia de dos recorre
+//!   todos los slots y evita el clustering primario.
+//! - `DoubleHashing`: el salto se obtiene de la otra mitad del hash, claves con igual `home` siguen caminos distintos.
+//! - `RobinHood`: sondeo lineal donde al insertar, si el residente está más cerca de su `home` que la clave que se
+//!   inserta, se le quita el lugar ("se le roba al rico"), así las distancias se emparejan y las busquedas fallidas
+//!   terminan antes.
+//! ```text
+//!   control : | Full(h) | Empty | Tombstone | Full(h) | Full(h) | Empty | ...
+//!   entries : | (k , v) |   ?   |     ?     | (k , v) | (k , v) |   ?   | ...
+//! ```
+//! Los pares se guardan en un `RawVec<(K, V)>` sin inicializar, el arreglo `control` indica qué slots están vivos.
+//!
+//! ### Eliminación
+//! - `Linear` y `RobinHood` usan *backward shift*: los pares siguientes del grupo retroceden para cerrar el hueco, la
+//!   tabla nunca acumula lapidas.
+//! - `Quadratic` y `DoubleHashing` no pueden retroceder pares porque sus secuencias no son contiguas, dejan una lapida
+//!   (`Tombstone`) que las busquedas saltan y las inserciones reutilizan, las lapidas se limpian al redimensionar.
+//!
+//! La tabla crece cuando los slots ocupados (pares y lapidas) superarían 3/4 de la capacidad. `stats` entrega contadores
+//! de sondeo para comparar las estrategias en los benches.
+use std::borrow::Borrow;
+use std::cell::Cell;
+use std::collections::hash_map::RandomState;
+use std::fmt::{self, Debug};
+use std::hash::{BuildHasher, Hash};
+use std::mem;
+use std::ptr;
+use super::vector::RawVec;
+
+///Estrategia de sondeo de `OpenHashMap`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Probing{
+    Linear,
+    Quadratic,
+    DoubleHashing,
+    RobinHood
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Control{
+    Empty,
+    Tombstone,
+    Full(u64)
+}
+///Contadores acumulados desde la creación de la tabla o desde `reset_stats`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProbeStats{
+    ///Operaciones (`insert`, `get`, `remove`, ...) que recorrieron la tabla.
+    pub operations : u64,
+    ///Slots inspeccionados entre todas las operaciones.
+    pub probes : u64,
+    ///Mayor cantidad de slots inspeccionados por una sola operación.
+    pub max_probe_length : u64,
+    ///Veces que la tabla se reconstruyo.
+    pub resizes : u64
+}
+impl ProbeStats{
+    pub fn average_probe_length(&self) -> f64{
+        if self.operations == 0{
+            return 0.0;
+        }
+        self.probes as f64 / self.operations as f64
+    }
+}
+///### OpenHashMap
+/// Tabla hash de direccionamiento abierto con reserva manual de memoria.
+pub struct OpenHashMap<K, V, S = RandomState>{
+    entries : RawVec<(K, V)>,
+    control : Vec<Control>,
+    len : usize,
+    tombstones : usize,
+    probing : Probing,
+    hash_builder : S,
+    //Las busquedas toman &self, por eso los contadores usan Cell
+    stats : Cell<ProbeStats>
+}
+impl<K, V> OpenHashMap<K, V, RandomState>{
+    pub fn new(probing : Probing) -> Self{
+        Self::with_hasher(probing, RandomState::new())
+    }
+    pub fn with_capacity(probing : Probing , capacity : usize) -> Self{
+        let mut map: OpenHashMap<K, V> = Self::new(probing);
+        if capacity > 0{
+            map.allocate(Self::slots_for(capacity));
+        }
+        map
+    }
+}
+impl<K, V, S> OpenHashMap<K, V, S>{
+    const MIN_SLOTS : usize = 8;
+    pub fn with_hasher(probing : Probing , hash_builder : S) -> Self{
+        Self{
+            entries : RawVec::new(),
+            control : Vec::new(),
+            len : 0,
+            tombstones : 0,
+            probing,
+            hash_builder,
+            stats : Cell::new(ProbeStats::default())
+        }
+    }
+    pub fn len(&self) -> usize{
+        self.len
+    }
+    pub fn empty(&self) -> bool{
+        self.len == 0
+    }
+    ///Cantidad de slots de la tabla.
+    pub fn capacity(&self) -> usize{
+        self.control.len()
+    }
+    pub fn tombstones(&self) -> usize{
+        self.tombstones
+    }
+    pub fn probing(&self) -> Probing{
+        self.probing
+    }
+    pub fn stats(&self) -> ProbeStats{
+        self.stats.get()
+    }
+    pub fn reset_stats(&self){
+        self.stats.set(ProbeStats::default());
+    }
+    pub fn iter(&self) -> Iter<'_, K, V, S>{
+        Iter{ map : self, slot : 0, remaining : self.len }
+    }
+    ///Menor potencia de dos de slots que guarda `len` pares bajo el factor de carga.
+    fn slots_for(len : usize) -> usize{
+        (len * 4).div_ceil(3).next_power_of_two().max(Self::MIN_SLOTS)
+    }
+    fn allocate(&mut self , slots : usize){
+        self.entries = RawVec::with_capacity(slots);
+        self.control = vec![Control::Empty; slots];
+    }
+    fn mask(&self) -> usize{
+        self.control.len() - 1
+    }
+    ///Distancia entre el slot y el `home` del hash que lo ocupa.
+    fn distance(&self , hash : u64 , slot : usize) -> usize{
+        slot.wrapping_sub(hash as usize) & self.mask()
+    }
+    fn record(&self , probes : u64){
+        let mut stats: ProbeStats = self.stats.get();
+        stats.operations += 1;
+        stats.probes += probes;
+        stats.max_probe_length = stats.max_probe_length.max(probes);
+        self.stats.set(stats);
+    }
+    ///### Entry
+    /// # Safety
+    /// El slot debe estar en `Control::Full`.
+    unsafe fn entry(&self , slot : usize) -> &(K, V){
+        &*self.entries.ptr().add(slot)
+    }
+    unsafe fn entry_mut(&mut self , slot : usize) -> &mut (K, V){
+        &mut *self.entries.ptr().add(slot)
+    }
+    ///Libera los pares vivos dejando todos los slots vacios.
+    pub fn clear(&mut self){
+        for slot in 0..self.control.len(){
+            if let Control::Full(_) = mem::replace(&mut self.control[slot], Control::Empty){
+                //SAFETY: el slot estaba lleno y ya se marco vacio, nadie más lo libera
+                unsafe { ptr::drop_in_place(self.entries.ptr().add(slot)) }
+            }
+        }
+        self.len = 0;
+        self.tombstones = 0;
+    }
+}
+///Posiciones de la secuencia de sondeo de un hash.
+struct ProbeSequence{
+    slot : usize,
+    step : usize,
+    stride : usize,
+    mask : usize,
+    probing : Probing
+}
+impl ProbeSequence{
+    fn new(hash : u64 , mask : usize , probing : Probing) -> Self{
+        //El salto de double hashing sale de la mitad alta del hash y es impar para recorrer todos los slots
+        let stride: usize = ((hash >> 32) as usize) | 1;
+        Self{ slot : hash as usize & mask, step : 0, stride, mask, probing }
+    }
+    fn advance(&mut self){
+        self.step += 1;
+        let jump: usize = match self.probing {
+            Probing::Linear | Probing::RobinHood => 1,
+            //home + 1 + 2 + ... + step = home + step(step+1)/2
+            Probing::Quadratic => self.step,
+            Probing::DoubleHashing => self.stride
+        };
+        self.slot = (self.slot + jump) & self.mask;
+    }
+}
+impl<K : Hash + Eq, V, S : BuildHasher> OpenHashMap<K, V, S>{
+    fn hash<Q>(&self , key : &Q) -> u64
+    where Q : Hash + ?Sized{
+        self.hash_builder.hash_one(key)
+    }
+    ///Slot donde vive la clave.
+    fn find<Q>(&self , key : &Q) -> Option<usize>
+    where K : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        if self.len == 0{
+            return None;
+        }
+        let hash: u64 = self.hash(key);
+        let mut sequence: ProbeSequence = ProbeSequence::new(hash, self.mask(), self.probing);
+        let mut probes: u64 = 0;
+        let found: Option<usize> = loop {
+            probes += 1;
+            let slot: usize = sequence.slot;
+            match self.control[slot] {
+                Control::Empty => break None,
+                //SAFETY: el slot está lleno
+                Control::Full(stored) if stored == hash && unsafe { self.entry(slot) }.0.borrow() == key => break Some(slot),
+                //En Robin Hood si el residente está más cerca de su home que la clave, esta no existe
+                Control::Full(stored) if self.probing == Probing::RobinHood && self.distance(stored, slot) < probes as usize - 1 => break None,
+                _ => {}
+            }
+            //Con lapidas la tabla puede quedar sin slots vacios, se corta tras una vuelta completa
+            if probes as usize == self.control.len(){
+                break None;
+            }
+            sequence.advance();
+        };
+        self.record(probes);
+        found
+    }
+    ///Reconstruye la tabla si insertar un par más superaría el factor de carga, si la mayoría de lo ocupado son lapidas
+    ///se reconstruye con la misma capacidad.
+    fn reserve_one(&mut self){
+        let slots: usize = self.control.len();
+        if (self.len + self.tombstones + 1) * 4 <= slots * 3{
+            return;
+        }
+        let new_slots: usize = if (self.len + 1) * 2 > slots { (slots * 2).max(Self::MIN_SLOTS) } else { slots };
+        let old_entries: RawVec<(K, V)> = mem::take(&mut self.entries);
+        let old_control: Vec<Control> = mem::take(&mut self.control);
+        self.allocate(new_slots);
+        self.len = 0;
+        self.tombstones = 0;
+        for (slot, control) in old_control.into_iter().enumerate(){
+            if let Control::Full(hash) = control{
+                //SAFETY: el slot estaba lleno, el par se mueve una única vez y old_entries solo libera el bloque
+                let (key, value): (K, V) = unsafe { ptr::read(old_entries.ptr().add(slot)) };
+                self.insert_new(hash, key, value);
+            }
+        }
+        let mut stats: ProbeStats = self.stats.get();
+        stats.resizes += 1;
+        self.stats.set(stats);
+    }
+    ///Inserta una clave que no existe en la tabla, debe haber espacio. No se cuenta en `stats`, el sondeo de una
+    ///inserción ya quedó registrado por la busqueda fallida previa.
+    fn insert_new(&mut self , hash : u64 , key : K , value : V){
+        let mut sequence: ProbeSequence = ProbeSequence::new(hash, self.mask(), self.probing);
+        let (mut hash, mut key, mut value): (u64, K, V) = (hash, key, value);
+        loop {
+            let slot: usize = sequence.slot;
+            match self.control[slot] {
+                Control::Empty | Control::Tombstone => {
+                    if self.control[slot] == Control::Tombstone{
+                        self.tombstones -= 1;
+                    }
+                    self.control[slot] = Control::Full(hash);
+                    //SAFETY: el slot no tenía par vivo
+                    unsafe { ptr::write(self.entries.ptr().add(slot), (key, value)) }
+                    self.len += 1;
+                    return;
+                },
+                Control::Full(stored) if self.probing == Probing::RobinHood && self.distance(stored, slot) < self.distance(hash, slot) => {
+                    //El residente está más cerca de su home, se intercambia y se continúa insertando al desplazado
+                    self.control[slot] = Control::Full(hash);
+                    //SAFETY: el slot está lleno, se intercambia su par con el que se está insertando
+                    let resident: &mut (K, V) = unsafe { self.entry_mut(slot) };
+                    mem::swap(&mut resident.0, &mut key);
+                    mem::swap(&mut resident.1, &mut value);
+                    hash = stored;
+                },
+                _ => {}
+            }
+            sequence.advance();
+        }
+    }
+    ///Inserta el par, si la clave ya existía reemplaza su valor y retorna el anterior.
+    pub fn insert(&mut self , key : K , value : V) -> Option<V>{
+        if let Some(slot) = self.find(&key){
+            //SAFETY: find solo retorna slots llenos
+            return Some(mem::replace(&mut unsafe { self.entry_mut(slot) }.1, value));
+        }
+        self.reserve_one();
+        let hash: u64 = self.hash(&key);
+        self.insert_new(hash, key, value);
+        None
+    }
+    pub fn get<Q>(&self , key : &Q) -> Option<&V>
+    where K : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        //SAFETY: find solo retorna slots llenos
+        self.find(key).map(|slot| &unsafe { self.entry(slot) }.1)
+    }
+    pub fn get_mut<Q>(&mut self , key : &Q) -> Option<&mut V>
+    where K : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        let slot: usize = self.find(key)?;
+        //SAFETY: find solo retorna slots llenos
+        Some(&mut unsafe { self.entry_mut(slot) }.1)
+    }
+    pub fn contains_key<Q>(&self , key : &Q) -> bool
+    where K : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        self.find(key).is_some()
+    }
+    pub fn remove<Q>(&mut self , key : &Q) -> Option<V>
+    where K : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        //La clave se libera con el slot ya marcado, si su Drop entra en panico clear no la vuelve a liberar
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+    ///Elimina el par y lo retorna junto a la clave guardada.
+    pub fn remove_entry<Q>(&mut self , key : &Q) -> Option<(K, V)>
+    where K : Borrow<Q>, Q : Hash + Eq + ?Sized{
+        let slot: usize = self.find(key)?;
+        //SAFETY: el slot está lleno, su par se mueve fuera y el slot se marca enseguida
+        let (key, value): (K, V) = unsafe { ptr::read(self.entries.ptr().add(slot)) };
+        self.len -= 1;
+        match self.probing {
+            Probing::Linear | Probing::RobinHood => self.backward_shift(slot),
+            Probing::Quadratic | Probing::DoubleHashing => {
+                self.control[slot] = Control::Tombstone;
+                self.tombstones += 1;
+            }
+        }
+        Some((key, value))
+    }
+    ///### Backward shift
+    /// Cierra el hueco en `gap` retrocediendo los pares siguientes del grupo que pueden quedar más cerca de su home.
+    /// ```text
+    ///   remove(b)   | a | b | c | d | Empty |      c y d estaban desplazados una posición
+    ///   despues     | a | c | d | Empty | Empty |
+    /// ```
+    fn backward_shift(&mut self , mut gap : usize){
+        let mask: usize = self.mask();
+        let mut slot: usize = (gap + 1) & mask;
+        while let Control::Full(hash) = self.control[slot]{
+            //El par puede ocupar el hueco si su home no está entre el hueco y su posición actual
+            if self.distance(hash, slot) >= (slot.wrapping_sub(gap) & mask){
+                //SAFETY: slot está lleno y gap libre, las posiciones son distintas
+                unsafe { ptr::copy_nonoverlapping(self.entries.ptr().add(slot), self.entries.ptr().add(gap), 1) }
+                self.control[gap] = Control::Full(hash);
+                gap = slot;
+            }else if self.probing == Probing::RobinHood{
+                //Adelantar a un par que no se movió rompería el orden por distancias de Robin Hood
+                break;
+            }
+            slot = (slot + 1) & mask;
+        }
+        self.control[gap] = Control::Empty;
+    }
+}
+impl<K, V, S> Drop for OpenHashMap<K, V, S>{
+    fn drop(&mut self){
+        //El RawVec libera el bloque despues, incluso si liberar un par entra en panico
+        self.clear();
+    }
+}
+impl<K : Debug, V : Debug, S> Debug for OpenHashMap<K, V, S>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+impl<K : Hash + Eq, V, S : BuildHasher> Extend<(K, V)> for OpenHashMap<K, V, S>{
+    fn extend<I : IntoIterator<Item = (K, V)>>(&mut self , iter : I){
+        for (key, value) in iter{
+            self.insert(key, value);
+        }
+    }
+}
+///Iterador por referencia en el orden de los slots.
+pub struct Iter<'a, K, V, S>{
+    map : &'a OpenHashMap<K, V, S>,
+    slot : usize,
+    remaining : usize
+}
+impl<'a, K, V, S> Iterator for Iter<'a, K, V, S>{
+    type Item = (&'a K, &'a V);
+    fn next(&mut self) -> Option<Self::Item>{
+        while self.slot < self.map.control.len(){
+            let slot: usize = self.slot;
+            self.slot += 1;
+            if let Control::Full(_) = self.map.control[slot]{
+                self.remaining -= 1;
+                //SAFETY: el slot está lleno y el préstamo del mapa dura 'a
+                let (key, value): &'a (K, V) = unsafe { self.map.entry(slot) };
+                return Some((key, value));
+            }
+        }
+        None
+    }
+    fn size_hint(&self) -> (usize, Option<usize>){
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<K, V, S> ExactSizeIterator for Iter<'_, K, V, S>{}
+impl<'a, K, V, S> IntoIterator for &'a OpenHashMap<K, V, S>{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V, S>;
+    fn into_iter(self) -> Iter<'a, K, V, S>{
+        self.iter()
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use std::collections::HashMap;
+    use std::hash::Hasher;
+    use std::rc::Rc;
+
+    const STRATEGIES : [Probing; 4] = [Probing::Linear , Probing::Quadratic , Probing::DoubleHashing , Probing::RobinHood];
+
+    fn pseudo_random_keys(count : usize , seed : u64 , modulo : u64) -> Vec<u64>{
+        let mut state: u64 = seed;
+        (0..count).map(|_| {
+            state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            (state >> 33) % modulo
+        }).collect()
+    }
+    #[test]
+    fn insert_get_replace_for_every_strategy(){
+        for probing in STRATEGIES{
+            let mut map : OpenHashMap<String, usize> = OpenHashMap::new(probing);
+            for i in 0..1_000{
+                assert_eq!(map.insert(i.to_string(), i) , None);
+            }
+            assert_eq!(map.insert(String::from("10"), 0) , Some(10));
+            assert_eq!(map.len() , 1_000);
+            assert!(map.len() * 4 <= map.capacity() * 3);
+            assert_eq!(map.get("10") , Some(&0));
+            assert_eq!(map.get("999") , Some(&999));
+            assert_eq!(map.get("1000") , None);
+            *map.get_mut("5").unwrap() += 1;
+            assert_eq!(map.get("5") , Some(&6) , "{:?}" , probing);
+        }
+    }
+    #[test]
+    fn matches_std_hash_map_for_every_strategy(){
+        for probing in STRATEGIES{
+            let mut map : OpenHashMap<u64, usize> = OpenHashMap::new(probing);
+            let mut model : HashMap<u64, usize> = HashMap::new();
+            for (step, key) in pseudo_random_keys(20_000, 3, 2_000).into_iter().enumerate(){
+                match step % 3 {
+                    0 | 1 => assert_eq!(map.insert(key, step) , model.insert(key, step)),
+                    _ => assert_eq!(map.remove(&key) , model.remove(&key))
+                }
+                assert_eq!(map.len() , model.len());
+            }
+            assert!(model.iter().all(|(key, value)| map.get(key) == Some(value)) , "{:?}" , probing);
+            assert_eq!(map.iter().count() , model.len());
+        }
+    }
+    #[test]
+    fn deletion_strategy_depends_on_probing(){
+        for probing in STRATEGIES{
+            let mut map : OpenHashMap<u32, u32> = OpenHashMap::with_capacity(probing, 64);
+            for i in 0..40{
+                map.insert(i, i);
+            }
+            for i in 0..20{
+                map.remove(&i);
+            }
+            let uses_tombstones : bool = matches!(probing , Probing::Quadratic | Probing::DoubleHashing);
+            assert_eq!(map.tombstones() > 0 , uses_tombstones , "{:?}" , probing);
+            assert!((20..40).all(|i| map.get(&i) == Some(&i)));
+        }
+    }
+    #[test]
+    fn tombstones_are_purged_without_growing(){
+        let mut map : OpenHashMap<u32, u32> = OpenHashMap::with_capacity(Probing::Quadratic, 8);
+        let capacity : usize = map.capacity();
+        //Insertar y eliminar una y otra vez llena la tabla de lapidas, la reconstrucción las limpia sin crecer
+        for i in 0..1_000{
+            map.insert(i, i);
+            map.remove(&i);
+        }
+        assert_eq!(map.capacity() , capacity);
+        assert!(map.stats().resizes > 0);
+        assert!(map.empty());
+    }
+    #[test]
+    fn robin_hood_keeps_probe_lengths_short(){
+        let keys : Vec<u64> = pseudo_random_keys(6_000, 17, u64::MAX);
+        let mut linear : OpenHashMap<u64, ()> = OpenHashMap::with_capacity(Probing::Linear, 6_000);
+        let mut robin_hood : OpenHashMap<u64, ()> = OpenHashMap::with_capacity(Probing::RobinHood, 6_000);
+        for &key in keys.iter(){
+            linear.insert(key, ());
+            robin_hood.insert(key, ());
+        }
+        linear.reset_stats();
+        robin_hood.reset_stats();
+        //Busquedas fallidas
+        for key in pseudo_random_keys(6_000, 99, u64::MAX){
+            linear.get(&key);
+            robin_hood.get(&key);
+        }
+        assert_eq!(linear.stats().operations , 6_000);
+        assert!(robin_hood.stats().average_probe_length() <= linear.stats().average_probe_length());
+        assert!(robin_hood.stats().max_probe_length <= linear.stats().max_probe_length);
+    }
+    #[test]
+    fn drops_every_value_once(){
+        let tracker : Rc<()> = Rc::new(());
+        for probing in STRATEGIES{
+            let mut map : OpenHashMap<u32, Rc<()>> = OpenHashMap::new(probing);
+            for i in 0..100{
+                map.insert(i, Rc::clone(&tracker));
+            }
+            map.insert(3, Rc::clone(&tracker));
+            for i in 0..50{
+                map.remove(&i);
+            }
+            assert_eq!(Rc::strong_count(&tracker) , 51);
+            drop(map);
+            assert_eq!(Rc::strong_count(&tracker) , 1);
+        }
+    }
+    ///Clave que cuenta sus liberaciones y puede entrar en panico al liberarse.
+    struct Fragile{
+        id : u32,
+        panics : bool,
+        drops : Rc<Cell<u32>>
+    }
+    impl Drop for Fragile{
+        fn drop(&mut self){
+            self.drops.set(self.drops.get() + 1);
+            if self.panics{
+                panic!("drop de la clave {}", self.id);
+            }
+        }
+    }
+    impl Hash for Fragile{
+        fn hash<H : Hasher>(&self , state : &mut H){
+            self.id.hash(state);
+        }
+    }
+    impl PartialEq for Fragile{
+        fn eq(&self , other : &Self) -> bool{
+            self.id == other.id
+        }
+    }
+    impl Eq for Fragile{}
+    impl Borrow<u32> for Fragile{
+        fn borrow(&self) -> &u32{
+            &self.id
+        }
+    }
+    #[test]
+    fn panicking_key_drop_is_not_repeated(){
+        for probing in STRATEGIES{
+            let drops : Rc<Cell<u32>> = Rc::new(Cell::new(0));
+            let mut map : OpenHashMap<Fragile, u32> = OpenHashMap::new(probing);
+            for id in 0..10{
+                map.insert(Fragile{ id, panics : id == 5, drops : Rc::clone(&drops) }, id);
+            }
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| map.remove(&5)));
+            assert!(result.is_err());
+            assert_eq!((map.len(), map.contains_key(&5)) , (9, false));
+            assert_eq!(map.get(&6) , Some(&6));
+            drop(map);
+            assert_eq!(drops.get() , 10);
+        }
+    }
+    #[test]
+    fn zero_sized_values(){
+        let mut map : OpenHashMap<u8, ()> = OpenHashMap::new(Probing::RobinHood);
+        for i in 0..=255{
+            map.insert(i, ());
+        }
+        assert_eq!(map.len() , 256);
+        assert_eq!(map.remove(&7) , Some(()));
+        assert!(!map.contains_key(&7));
+    }
+}
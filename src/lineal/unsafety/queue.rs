@@ -0,0 +1,76 @@
+//! ### Sobre las colas (Queue)
+//! Una cola es una estructura FIFO (First In, First Out), el primer elemento insertado es el primero en salir.
+//! La version unsafe se construye sobre el `Deque` de buffer circular restringiendo las operaciones a insertar por el final
+//! y eliminar por el frente.
+//! ```text
+//!    pop_front <-- | 10 | 20 | 30 | <-- push_back
+//! ```
+use super::deque::{Deque, IntoIter, Iter};
+
+///### Queue
+/// Cola FIFO sobre un buffer circular, `push_back` es O(1) amortizado y `pop_front` es O(1).
+#[derive(Debug)]
+pub struct Queue<T>{
+    deque : Deque<T>
+}
+impl<T> Default for Queue<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T> Queue<T>{
+    pub fn new() -> Self{
+        Self{
+            deque : Deque::new()
+        }
+    }
+    pub fn with_capacity(capacity : usize) -> Self{
+        Self{
+            deque : Deque::with_capacity(capacity)
+        }
+    }
+    pub fn len(&self) -> usize{
+        self.deque.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.deque.empty()
+    }
+    ///Inserta un elemento al final de la cola.
+    pub fn push_back(&mut self , value : T){
+        self.deque.push_back(value);
+    }
+    ///Elimina y retorna el elemento del frente de la cola.
+    pub fn pop_front(&mut self) -> Option<T>{
+        self.deque.pop_front()
+    }
+    ///El siguiente elemento en salir de la cola.
+    pub fn front(&self) -> Option<&T>{
+        self.deque.front()
+    }
+    ///El ultimo elemento insertado en la cola.
+    pub fn back(&self) -> Option<&T>{
+        self.deque.back()
+    }
+    pub fn iter(&self) -> Iter<'_, T>{
+        self.deque.iter()
+    }
+}
+impl<T> IntoIterator for Queue<T>{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> IntoIter<T>{
+        self.deque.into_iter()
+    }
+}
+impl<T> FromIterator<T> for Queue<T>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        Self{
+            deque : iter.into_iter().collect()
+        }
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    crate::lineal::queue_tests::queue_tests!(Queue);
+}
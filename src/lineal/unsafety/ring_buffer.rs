@@ -0,0 +1,140 @@
+//! ### Buffer circular en el heap
+//! Misma semantica que `safety::ring_buffer::RingBuffer` pero la capacidad se elige en tiempo de ejecución, el
+//! almacenamiento es el `Deque` de esta misma carpeta reservado una única vez con la capacidad pedida.
+//! Como nunca se inserta con el deque lleno este jamás vuelve a reservar memoria.
+use std::fmt::{self, Debug};
+use super::deque::{Deque, IntoIter, Iter};
+pub use crate::lineal::safety::ring_buffer::Overflow;
+
+///### RingBuffer
+/// Buffer circular de capacidad fija reservado en el heap.
+pub struct RingBuffer<T>{
+    deque : Deque<T>,
+    capacity : usize,
+    mode : Overflow
+}
+impl<T> RingBuffer<T>{
+    ///Crea un buffer vacio que rechaza las inserciones cuando está lleno.
+    pub fn with_capacity(capacity : usize) -> Self{
+        Self::with_capacity_and_mode(capacity, Overflow::Reject)
+    }
+    pub fn with_capacity_and_mode(capacity : usize , mode : Overflow) -> Self{
+        assert!(capacity > 0, "La capacidad del buffer debe ser mayor a cero");
+        Self{
+            deque : Deque::with_capacity(capacity),
+            capacity,
+            mode
+        }
+    }
+    pub fn len(&self) -> usize{
+        self.deque.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.deque.empty()
+    }
+    pub fn is_full(&self) -> bool{
+        self.deque.len() == self.capacity
+    }
+    pub fn capacity(&self) -> usize{
+        self.capacity
+    }
+    pub fn mode(&self) -> Overflow{
+        self.mode
+    }
+    ///### Push
+    /// Inserta al final del buffer.
+    /// - `Ok(None)`: habia espacio para el valor.
+    /// - `Ok(Some(oldest))`: el buffer estaba lleno en modo `Overwrite`, se retorna el elemento descartado.
+    /// - `Err(value)`: el buffer estaba lleno en modo `Reject`, se devuelve el valor.
+    pub fn push(&mut self , value : T) -> Result<Option<T>, T>{
+        if !self.is_full(){
+            self.deque.push_back(value);
+            return Ok(None);
+        }
+        match self.mode {
+            Overflow::Reject => Err(value),
+            Overflow::Overwrite => {
+                //Primero se libera el frente para que el deque nunca necesite crecer
+                let oldest: Option<T> = self.deque.pop_front();
+                self.deque.push_back(value);
+                Ok(oldest)
+            }
+        }
+    }
+    ///Elimina y retorna el elemento más antiguo.
+    pub fn pop(&mut self) -> Option<T>{
+        self.deque.pop_front()
+    }
+    pub fn front(&self) -> Option<&T>{
+        self.deque.front()
+    }
+    pub fn back(&self) -> Option<&T>{
+        self.deque.back()
+    }
+    ///Los elementos en orden lógico divididos en el tramo que va desde el frente hasta el final de la reserva y el
+    ///tramo que dio la vuelta al inicio.
+    pub fn as_slices(&self) -> (&[T], &[T]){
+        self.deque.as_slices()
+    }
+    ///Recorre los elementos desde el más antiguo al más reciente.
+    pub fn iter(&self) -> Iter<'_, T>{
+        self.deque.iter()
+    }
+    pub fn clear(&mut self){
+        self.deque.clear();
+    }
+}
+impl<T : Debug> Debug for RingBuffer<T>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl<'a, T> IntoIterator for &'a RingBuffer<T>{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Iter<'a, T>{
+        self.iter()
+    }
+}
+impl<T> IntoIterator for RingBuffer<T>{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> IntoIter<T>{
+        self.deque.into_iter()
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    crate::lineal::queue_tests::fifo_tests!(new: || RingBuffer::with_capacity(64), push: push, pop: pop);
+    #[test]
+    fn reject_when_full(){
+        let mut buffer : RingBuffer<i32> = RingBuffer::with_capacity(3);
+        for i in 1..=3{
+            assert_eq!(buffer.push(i) , Ok(None));
+        }
+        assert!(buffer.is_full());
+        assert_eq!(buffer.push(4) , Err(4));
+        assert_eq!(buffer.iter().copied().collect::<Vec<i32>>() , vec![1 , 2 , 3]);
+    }
+    #[test]
+    fn overwrite_never_grows(){
+        let mut buffer : RingBuffer<i32> = RingBuffer::with_capacity_and_mode(3, Overflow::Overwrite);
+        for i in 1..=3{
+            assert_eq!(buffer.push(i) , Ok(None));
+        }
+        assert_eq!(buffer.push(4) , Ok(Some(1)));
+        assert_eq!(buffer.push(5) , Ok(Some(2)));
+        assert_eq!(buffer.as_slices() , (&[3][..] , &[4 , 5][..]));
+        assert_eq!(buffer.deque.capacity() , 3);
+        assert_eq!(format!("{:?}" , buffer) , "[3, 4, 5]");
+    }
+    #[test]
+    fn zero_sized_types(){
+        let mut buffer : RingBuffer<()> = RingBuffer::with_capacity_and_mode(2, Overflow::Overwrite);
+        assert_eq!(buffer.push(()) , Ok(None));
+        assert_eq!(buffer.push(()) , Ok(None));
+        assert_eq!(buffer.push(()) , Ok(Some(())));
+        assert_eq!(buffer.len() , 2);
+    }
+}
@@ -0,0 +1,364 @@
+//! ### Skip List con punteros crudos
+//! Misma estructura que `lineal::safety::skip_list` (ver su documentación), pero cada nodo es una reserva propia con una
+//! torre de `*mut Node<T>` y la cabecera es solo una torre, sin valor.
+//! ```text
+//!   head[1] ----------------> +-------+ ----------------> null
+//!   head[0] --> +-------+ --> | 10    | --> +-------+ --> null
+//!               | 5     |     | tower |     | 20    |
+//!               | tower |     +-------+     | tower |
+//!               +-------+                   +-------+
+//! ```
+//! Las busquedas guardan, por nivel, un puntero al *enlace* (`*mut *mut Node<T>`) que habría que reescribir, así
+//! insertar y eliminar no distinguen entre la cabecera y la torre de un nodo.
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+use std::ptr;
+use crate::lineal::safety::set::Set;
+use crate::lineal::safety::skip_list::MAX_LEVEL;
+use crate::random::XorShift64;
+
+type Link<T> = *mut Node<T>;
+
+struct Node<T>{
+    value : T,
+    tower : Box<[Link<T>]>
+}
+///### SkipList
+/// Conjunto ordenado sin repetidos.
+pub struct SkipList<T>{
+    head : Box<[Link<T>]>,
+    level : usize,
+    len : usize,
+    rng : XorShift64,
+    _marker : PhantomData<Box<Node<T>>>
+}
+impl<T : Ord> SkipList<T>{
+    pub fn new() -> Self{
+        Self::with_rng(XorShift64::from_entropy())
+    }
+    ///Misma semilla y mismas operaciones producen las mismas torres.
+    pub fn with_seed(seed : u64) -> Self{
+        Self::with_rng(XorShift64::new(seed))
+    }
+    fn with_rng(rng : XorShift64) -> Self{
+        Self{
+            head : vec![ptr::null_mut(); MAX_LEVEL].into_boxed_slice(),
+            level : 1,
+            len : 0,
+            rng,
+            _marker : PhantomData
+        }
+    }
+    pub fn len(&self) -> usize{
+        self.len
+    }
+    pub fn empty(&self) -> bool{
+        self.len == 0
+    }
+    ///Cantidad de niveles en uso.
+    pub fn height(&self) -> usize{
+        self.level
+    }
+    ///### Links
+    /// Por nivel, el enlace que apunta al primer nodo que no cumple `advance`.
+    fn links(&mut self , advance : impl Fn(&T) -> bool) -> [*mut Link<T>; MAX_LEVEL]{
+        let mut update: [*mut Link<T>; MAX_LEVEL] = [ptr::null_mut(); MAX_LEVEL];
+        let mut tower: *mut Link<T> = self.head.as_mut_ptr();
+        for level in (0..MAX_LEVEL).rev(){
+            if level < self.level{
+                //SAFETY: `tower` es la cabecera o la torre de un nodo vivo con altura mayor a `level`,
+                //los nodos enlazados en `level` tienen torres de al menos `level + 1` enlaces
+                unsafe{
+                    while !(*tower.add(level)).is_null() && advance(&(**tower.add(level)).value){
+                        tower = (**tower.add(level)).tower.as_mut_ptr();
+                    }
+                    update[level] = tower.add(level);
+                }
+            }else {
+                //SAFETY: la cabecera tiene MAX_LEVEL enlaces
+                update[level] = unsafe { self.head.as_mut_ptr().add(level) };
+            }
+        }
+        update
+    }
+    ///Primer nodo que no cumple `advance`, sin tocar la lista.
+    fn first_not(&self , advance : impl Fn(&T) -> bool) -> *const Node<T>{
+        let mut tower: *const Link<T> = self.head.as_ptr();
+        for level in (0..self.level).rev(){
+            //SAFETY: igual que en `links`, solo lectura
+            unsafe{
+                while !(*tower.add(level)).is_null() && advance(&(**tower.add(level)).value){
+                    tower = (**tower.add(level)).tower.as_ptr();
+                }
+            }
+        }
+        //SAFETY: toda torre tiene el nivel 0
+        unsafe { *tower }
+    }
+    pub fn contains(&self , value : &T) -> bool{
+        let node: *const Node<T> = self.first_not(|v| v < value);
+        //SAFETY: el nodo no nulo está vivo mientras dure el préstamo
+        !node.is_null() && unsafe { (*node).value == *value }
+    }
+    ///Inserta el valor, retorna `false` si ya existía.
+    pub fn insert(&mut self , value : T) -> bool{
+        let update: [*mut Link<T>; MAX_LEVEL] = self.links(|v| *v < value);
+        //SAFETY: los enlaces de `update` apuntan a la cabecera o a torres vivas
+        unsafe{
+            let next: Link<T> = *update[0];
+            if !next.is_null() && (*next).value == value{
+                return false;
+            }
+            let height: usize = self.rng.level(MAX_LEVEL);
+            self.level = self.level.max(height);
+            let tower: Box<[Link<T>]> = (0..height).map(|level| *update[level]).collect();
+            let node: Link<T> = Box::into_raw(Box::new(Node{ value, tower }));
+            for link in &update[..height]{
+                **link = node;
+            }
+        }
+        self.len += 1;
+        true
+    }
+    ///Elimina y retorna el valor si existía.
+    pub fn remove(&mut self , value : &T) -> Option<T>{
+        let update: [*mut Link<T>; MAX_LEVEL] = self.links(|v| v < value);
+        //SAFETY: `target` es el nodo enlazado desde `update[0]`, en cada nivel de su torre el enlace previo es `update[level]`,
+        //se desenlaza de todos los niveles antes de recuperar el Box, así nadie más lo apunta
+        let node: Node<T> = unsafe{
+            let target: Link<T> = *update[0];
+            if target.is_null() || (*target).value != *value{
+                return None;
+            }
+            for (level, &successor) in (*target).tower.iter().enumerate(){
+                *update[level] = successor;
+            }
+            *Box::from_raw(target)
+        };
+        while self.level > 1 && self.head[self.level - 1].is_null(){
+            self.level -= 1;
+        }
+        self.len -= 1;
+        Some(node.value)
+    }
+    pub fn first(&self) -> Option<&T>{
+        //SAFETY: el nodo vive mientras dure el préstamo de la lista
+        unsafe { self.head[0].as_ref().map(|node| &node.value) }
+    }
+    ///Valores en orden ascendente.
+    pub fn iter(&self) -> Iter<'_, T>{
+        Iter{ next : self.head[0], stop : ptr::null(), _marker : PhantomData }
+    }
+    ///Valores dentro del rango en orden ascendente.
+    pub fn range<R : RangeBounds<T>>(&self , range : R) -> Iter<'_, T>{
+        let next: *const Node<T> = match range.start_bound(){
+            Bound::Included(start) => self.first_not(|v| v < start),
+            Bound::Excluded(start) => self.first_not(|v| v <= start),
+            Bound::Unbounded => self.head[0]
+        };
+        let stop: *const Node<T> = match range.end_bound(){
+            Bound::Included(end) => self.first_not(|v| v <= end),
+            Bound::Excluded(end) => self.first_not(|v| v < end),
+            Bound::Unbounded => ptr::null()
+        };
+        //SAFETY: `next` es nulo o un nodo vivo
+        let inside: bool = unsafe { next.as_ref().is_some_and(|node| range.contains(&node.value)) };
+        Iter{ next : if inside { next } else { ptr::null() }, stop, _marker : PhantomData }
+    }
+    pub fn clear(&mut self){
+        self.free_nodes();
+    }
+}
+impl<T> SkipList<T>{
+    ///Desengancha la cadena antes de liberarla, si el Drop de un valor entra en panico la lista ya quedó vacía y nadie
+    ///vuelve a recorrer nodos liberados (los que faltaban se pierden).
+    fn free_nodes(&mut self){
+        let mut current: Link<T> = self.head[0];
+        self.head.fill(ptr::null_mut());
+        self.level = 1;
+        self.len = 0;
+        while !current.is_null(){
+            //SAFETY: la cadena ya no es alcanzable desde la lista, cada nodo se libera una sola vez por el nivel 0
+            let node: Box<Node<T>> = unsafe { Box::from_raw(current) };
+            current = node.tower[0];
+        }
+    }
+}
+impl<T> Drop for SkipList<T>{
+    fn drop(&mut self){
+        self.free_nodes();
+    }
+}
+impl<T : Ord> Default for SkipList<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T : Ord> Set<T> for SkipList<T>{
+    type Iter<'a> = Iter<'a, T> where Self : 'a, T : 'a;
+    fn new_set() -> Self{
+        Self::new()
+    }
+    fn insert(&mut self , value : T) -> bool{
+        SkipList::insert(self, value)
+    }
+    fn contains(&self , value : &T) -> bool{
+        SkipList::contains(self, value)
+    }
+    fn remove(&mut self , value : &T) -> bool{
+        SkipList::remove(self, value).is_some()
+    }
+    fn len(&self) -> usize{
+        self.len
+    }
+    fn iter(&self) -> Iter<'_, T>{
+        SkipList::iter(self)
+    }
+}
+impl<T : Ord + Debug> Debug for SkipList<T>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+impl<T : Ord> FromIterator<T> for SkipList<T>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        let mut list: SkipList<T> = SkipList::new();
+        list.extend(iter);
+        list
+    }
+}
+impl<T : Ord> Extend<T> for SkipList<T>{
+    fn extend<I : IntoIterator<Item = T>>(&mut self , iter : I){
+        for value in iter{
+            self.insert(value);
+        }
+    }
+}
+///Iterador ordenado por el nivel 0, termina al llegar a `stop`.
+pub struct Iter<'a, T>{
+    next : *const Node<T>,
+    stop : *const Node<T>,
+    _marker : PhantomData<&'a T>
+}
+impl<'a, T> Iterator for Iter<'a, T>{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T>{
+        if self.next.is_null() || self.next == self.stop{
+            return None;
+        }
+        //SAFETY: el iterador toma prestada la lista por 'a, el nodo sigue vivo
+        let node: &'a Node<T> = unsafe { &*self.next };
+        self.next = node.tower[0];
+        Some(&node.value)
+    }
+}
+impl<'a, T : Ord> IntoIterator for &'a SkipList<T>{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Iter<'a, T>{
+        self.iter()
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use std::cell::Cell;
+    use std::collections::BTreeSet;
+    use std::rc::Rc;
+
+    #[test]
+    fn matches_btree_set_on_random_operations(){
+        let mut rng: XorShift64 = XorShift64::new(5);
+        let mut list: SkipList<i32> = SkipList::with_seed(42);
+        let mut expected: BTreeSet<i32> = BTreeSet::new();
+        for step in 0..5_000{
+            let value: i32 = (rng.next_u64() % 500) as i32;
+            if step % 3 == 2{
+                assert_eq!(list.remove(&value).is_some() , expected.remove(&value));
+            }else {
+                assert_eq!(list.insert(value) , expected.insert(value));
+            }
+        }
+        assert_eq!(list.len() , expected.len());
+        assert!(list.iter().eq(expected.iter()));
+        for (start, end) in [(10, 40), (-5, 7), (450, 600)]{
+            assert!(list.range(start..end).eq(expected.range(start..end)));
+            assert!(list.range((Bound::Excluded(start), Bound::Unbounded)).eq(expected.range((Bound::Excluded(start), Bound::Unbounded))));
+        }
+        assert_eq!(list.range(60..20).count() , 0);
+    }
+    #[test]
+    fn same_seed_matches_the_safe_version(){
+        let mut unsafe_list: SkipList<u32> = SkipList::with_seed(11);
+        let mut safe_list = crate::lineal::safety::skip_list::SkipList::with_seed(11);
+        for value in (0..300).map(|v| (v * 7_919) % 1_000){
+            unsafe_list.insert(value);
+            safe_list.insert(value);
+        }
+        assert_eq!(unsafe_list.height() , safe_list.height());
+        assert!(unsafe_list.iter().eq(safe_list.iter()));
+    }
+    #[test]
+    fn values_are_dropped_on_remove_clear_and_drop(){
+        let tracker: Rc<()> = Rc::new(());
+        let mut list: SkipList<(u32, Rc<()>)> = SkipList::with_seed(3);
+        for value in 0..100{
+            list.insert((value, Rc::clone(&tracker)));
+        }
+        assert!(list.remove(&(10, Rc::clone(&tracker))).is_some());
+        assert_eq!(Rc::strong_count(&tracker) , 100);
+        list.clear();
+        assert_eq!(Rc::strong_count(&tracker) , 1);
+        for value in 0..50{
+            list.insert((value, Rc::clone(&tracker)));
+        }
+        drop(list);
+        assert_eq!(Rc::strong_count(&tracker) , 1);
+    }
+    ///Valor que cuenta sus liberaciones y puede entrar en panico al liberarse.
+    struct Fragile{
+        id : u32,
+        panics : bool,
+        drops : Rc<Cell<u32>>
+    }
+    impl Drop for Fragile{
+        fn drop(&mut self){
+            self.drops.set(self.drops.get() + 1);
+            if self.panics{
+                panic!("drop del valor {}", self.id);
+            }
+        }
+    }
+    impl PartialEq for Fragile{
+        fn eq(&self , other : &Self) -> bool{
+            self.id == other.id
+        }
+    }
+    impl Eq for Fragile{}
+    impl PartialOrd for Fragile{
+        fn partial_cmp(&self , other : &Self) -> Option<std::cmp::Ordering>{
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Fragile{
+        fn cmp(&self , other : &Self) -> std::cmp::Ordering{
+            self.id.cmp(&other.id)
+        }
+    }
+    #[test]
+    fn panicking_drop_during_clear_leaves_an_empty_list(){
+        let drops: Rc<Cell<u32>> = Rc::new(Cell::new(0));
+        let mut list: SkipList<Fragile> = SkipList::with_seed(7);
+        for id in 0..10{
+            list.insert(Fragile{ id, panics : id == 4, drops : Rc::clone(&drops) });
+        }
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| list.clear()));
+        assert!(result.is_err());
+        //Se liberaron 0..=4 y el resto se perdió, la lista quedó vacía y usable
+        assert_eq!((list.len(), list.iter().count(), drops.get()) , (0, 0, 5));
+        list.insert(Fragile{ id : 20, panics : false, drops : Rc::clone(&drops) });
+        drop(list);
+        assert_eq!(drops.get() , 6);
+    }
+}
@@ -0,0 +1,579 @@
+//! ### Sobre los vectores
+//! Un vector es un arreglo que crece de manera dinamica, los elementos viven contiguos en un único bloque del heap y
+//! cuando el bloque se llena se reserva uno del doble de tamaño, de esta manera `push` cuesta O(1) amortizado.
+//! ```text
+//!     MyVec { buf : RawVec { ptr , capacity : 8 } , len : 5 }
+//!                  |
+//!                  v
+//!               +-----+-----+-----+-----+-----+-----+-----+-----+
+//!               |  a  |  b  |  c  |  d  |  e  |  ?  |  ?  |  ?  |
+//!               +-----+-----+-----+-----+-----+-----+-----+-----+
+//!                <-------- inicializados ------> <- sin inicializar ->
+//! ```
+//! La responsabilidad se divide en dos tipos:
+//! - `RawVec<T>`: solo administra el bloque de memoria (reservar, crecer y liberar), no sabe cuantos elementos están vivos.
+//! - `MyVec<T>`: lleva la cuenta de los elementos inicializados y es quien los libera.
+//!
+//! Los tipos de tamaño cero (`()`, structs vacios) no necesitan memoria, para ellos la capacidad es `usize::MAX` y el
+//! puntero es `NonNull::dangling()`.
+//!
+//! Las pruebas están pensadas para ejecutarse además bajo Miri (`cargo +nightly miri test vector`), con Miri activo se
+//! reducen los tamaños para que terminen en un tiempo razonable.
+use std::alloc::{self, Layout};
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Bound, Deref, DerefMut, RangeBounds};
+use std::ptr::{self, NonNull};
+use std::slice;
+
+///### RawVec
+/// Bloque de memoria sin inicializar con espacio para `capacity` elementos de tipo `T`.
+pub struct RawVec<T>{
+    ptr : NonNull<T>,
+    capacity : usize,
+    _marker : PhantomData<T>
+}
+//SAFETY: RawVec es dueño exclusivo de su bloque, igual que un Box<[T]>
+unsafe impl<T : Send> Send for RawVec<T>{}
+unsafe impl<T : Sync> Sync for RawVec<T>{}
+impl<T> Default for RawVec<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T> RawVec<T>{
+    const MIN_CAPACITY : usize = 4;
+    pub fn new() -> Self{
+        let capacity: usize = if mem::size_of::<T>() == 0 { usize::MAX } else { 0 };
+        Self{ ptr : NonNull::dangling(), capacity, _marker : PhantomData }
+    }
+    pub fn with_capacity(capacity : usize) -> Self{
+        let mut raw: RawVec<T> = Self::new();
+        if capacity > raw.capacity{
+            raw.reallocate(capacity);
+        }
+        raw
+    }
+    pub fn ptr(&self) -> *mut T{
+        self.ptr.as_ptr()
+    }
+    pub fn capacity(&self) -> usize{
+        self.capacity
+    }
+    ///Asegura espacio para `len + additional` elementos, duplicando la capacidad para que el costo sea amortizado.
+    pub fn reserve(&mut self , len : usize , additional : usize){
+        let required: usize = len.checked_add(additional).expect("La capacidad excede usize::MAX");
+        if required <= self.capacity{
+            return;
+        }
+        let new_capacity: usize = required.max(self.capacity * 2).max(Self::MIN_CAPACITY);
+        self.reallocate(new_capacity);
+    }
+    fn reallocate(&mut self , new_capacity : usize){
+        //Con tipos de tamaño cero la capacidad ya es usize::MAX, reserve nunca llega aquí
+        debug_assert!(mem::size_of::<T>() != 0);
+        let new_layout: Layout = Layout::array::<T>(new_capacity).expect("La capacidad excede el tamaño maximo de una reserva");
+        let raw: *mut u8 = if self.capacity == 0{
+            //SAFETY: new_capacity > 0 y T no es de tamaño cero, el layout no es vacio
+            unsafe { alloc::alloc(new_layout) }
+        }else {
+            let old_layout: Layout = Layout::array::<T>(self.capacity).unwrap();
+            //SAFETY: el bloque se reservo con old_layout y el nuevo tamaño no es cero
+            unsafe { alloc::realloc(self.ptr.as_ptr() as *mut u8, old_layout, new_layout.size()) }
+        };
+        self.ptr = match NonNull::new(raw as *mut T) {
+            Some(ptr) => ptr,
+            None => alloc::handle_alloc_error(new_layout)
+        };
+        self.capacity = new_capacity;
+    }
+}
+impl<T> Drop for RawVec<T>{
+    fn drop(&mut self){
+        if self.capacity != 0 && mem::size_of::<T>() != 0{
+            //SAFETY: el bloque se reservo con este mismo layout, los elementos ya fueron liberados por MyVec
+            unsafe { alloc::dealloc(self.ptr.as_ptr() as *mut u8, Layout::array::<T>(self.capacity).unwrap()) }
+        }
+    }
+}
+///### MyVec
+/// Vector construido desde cero, se comporta como un `[T]` gracias a `Deref`.
+pub struct MyVec<T>{
+    buf : RawVec<T>,
+    len : usize
+}
+impl<T> Default for MyVec<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T> MyVec<T>{
+    pub fn new() -> Self{
+        Self{ buf : RawVec::new(), len : 0 }
+    }
+    pub fn with_capacity(capacity : usize) -> Self{
+        Self{ buf : RawVec::with_capacity(capacity), len : 0 }
+    }
+    pub fn len(&self) -> usize{
+        self.len
+    }
+    pub fn empty(&self) -> bool{
+        self.len == 0
+    }
+    pub fn capacity(&self) -> usize{
+        self.buf.capacity()
+    }
+    pub fn reserve(&mut self , additional : usize){
+        self.buf.reserve(self.len, additional);
+    }
+    ///Inserta al final en O(1) amortizado.
+    pub fn push(&mut self , value : T){
+        if self.len == self.buf.capacity(){
+            self.buf.reserve(self.len, 1);
+        }
+        //SAFETY: len < capacity, la posición está reservada y sin inicializar
+        unsafe { ptr::write(self.buf.ptr().add(self.len), value) }
+        self.len += 1;
+    }
+    pub fn pop(&mut self) -> Option<T>{
+        if self.len == 0{
+            return None;
+        }
+        self.len -= 1;
+        //SAFETY: la posición len estaba inicializada y al reducir len deja de pertenecer al vector
+        Some(unsafe { ptr::read(self.buf.ptr().add(self.len)) })
+    }
+    ///### Insert
+    /// Inserta en `index` desplazando a la derecha los elementos siguientes, O(n).
+    /// # Panics
+    /// Si `index > len`.
+    pub fn insert(&mut self , index : usize , value : T){
+        assert!(index <= self.len, "Indice {} fuera de rango para un vector de largo {}", index, self.len);
+        if self.len == self.buf.capacity(){
+            self.buf.reserve(self.len, 1);
+        }
+        //SAFETY: hay espacio para len + 1 elementos, ptr::copy admite regiones solapadas
+        unsafe {
+            let position: *mut T = self.buf.ptr().add(index);
+            ptr::copy(position, position.add(1), self.len - index);
+            ptr::write(position, value);
+        }
+        self.len += 1;
+    }
+    ///### Remove
+    /// Elimina y retorna el elemento en `index` desplazando a la izquierda los siguientes, O(n).
+    /// # Panics
+    /// Si `index >= len`.
+    pub fn remove(&mut self , index : usize) -> T{
+        assert!(index < self.len, "Indice {} fuera de rango para un vector de largo {}", index, self.len);
+        //SAFETY: index < len, se lee el valor y se cierra el hueco con los elementos siguientes
+        unsafe {
+            let position: *mut T = self.buf.ptr().add(index);
+            let value: T = ptr::read(position);
+            ptr::copy(position.add(1), position, self.len - index - 1);
+            self.len -= 1;
+            value
+        }
+    }
+    ///Elimina todos los elementos conservando la capacidad.
+    pub fn clear(&mut self){
+        let elements: *mut [T] = self.as_mut_slice() as *mut [T];
+        //Se reduce len antes de liberar, si un drop entra en panico el vector no vuelve a liberar esos elementos
+        self.len = 0;
+        //SAFETY: los elementos estaban inicializados y ya no pertenecen al vector
+        unsafe { ptr::drop_in_place(elements) }
+    }
+    pub fn as_slice(&self) -> &[T]{
+        //SAFETY: las primeras len posiciones están inicializadas, el puntero nunca es nulo
+        unsafe { slice::from_raw_parts(self.buf.ptr(), self.len) }
+    }
+    pub fn as_mut_slice(&mut self) -> &mut [T]{
+        //SAFETY: igual que as_slice, el préstamo mutable de self garantiza exclusividad
+        unsafe { slice::from_raw_parts_mut(self.buf.ptr(), self.len) }
+    }
+    ///### Drain
+    /// Retira los elementos del rango entregandolos por valor, al terminar los elementos posteriores al rango se
+    /// desplazan para cerrar el hueco. Los elementos que el iterador no alcanzó a entregar se liberan al soltarlo.
+    /// ```text
+    ///   drain(1..3) sobre | a | b | c | d | e |
+    ///   durante:          | a | b | c | d | e |    len = 1 , tail = 3..5
+    ///   despues:          | a | d | e |            len = 3
+    /// ```
+    /// # Panics
+    /// Si el rango no está contenido en `0..len`.
+    pub fn drain<R : RangeBounds<usize>>(&mut self , range : R) -> Drain<'_, T>{
+        let start: usize = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("attempted to index slice from after maximum usize"),
+            Bound::Unbounded => 0
+        };
+        let end: usize = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("attempted to index slice up to maximum usize"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len
+        };
+        assert!(start <= end && end <= self.len, "Rango {}..{} fuera de un vector de largo {}", start, end, self.len);
+        let tail_len: usize = self.len - end;
+        //Mientras exista el Drain el vector solo conserva el prefijo, si se olvida el Drain se filtran elementos pero nunca se liberan dos veces
+        self.len = start;
+        Drain{
+            vec : self,
+            front : start,
+            back : end,
+            tail_start : end,
+            tail_len
+        }
+    }
+}
+impl<T> Drop for MyVec<T>{
+    fn drop(&mut self){
+        //Si un elemento entra en panico drop_in_place continua con el resto y luego se libera el RawVec
+        //SAFETY: los elementos están inicializados y el vector no se vuelve a usar
+        unsafe { ptr::drop_in_place(self.as_mut_slice() as *mut [T]) }
+    }
+}
+impl<T> Deref for MyVec<T>{
+    type Target = [T];
+    fn deref(&self) -> &[T]{
+        self.as_slice()
+    }
+}
+impl<T> DerefMut for MyVec<T>{
+    fn deref_mut(&mut self) -> &mut [T]{
+        self.as_mut_slice()
+    }
+}
+impl<T : Debug> Debug for MyVec<T>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl<T : Clone> Clone for MyVec<T>{
+    fn clone(&self) -> Self{
+        let mut cloned: MyVec<T> = MyVec::with_capacity(self.len);
+        for value in self.iter(){
+            cloned.push(value.clone());
+        }
+        cloned
+    }
+}
+impl<T : PartialEq> PartialEq for MyVec<T>{
+    fn eq(&self , other : &Self) -> bool{
+        self.as_slice() == other.as_slice()
+    }
+}
+impl<T> FromIterator<T> for MyVec<T>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        let iter = iter.into_iter();
+        let mut vec: MyVec<T> = MyVec::with_capacity(iter.size_hint().0);
+        for value in iter{
+            vec.push(value);
+        }
+        vec
+    }
+}
+impl<T> Extend<T> for MyVec<T>{
+    fn extend<I : IntoIterator<Item = T>>(&mut self , iter : I){
+        for value in iter{
+            self.push(value);
+        }
+    }
+}
+impl<'a, T> IntoIterator for &'a MyVec<T>{
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    fn into_iter(self) -> slice::Iter<'a, T>{
+        self.iter()
+    }
+}
+///Iterador que consume el vector, es dueño del `RawVec` y de los elementos en `front..back`.
+pub struct IntoIter<T>{
+    buf : RawVec<T>,
+    front : usize,
+    back : usize
+}
+impl<T> IntoIterator for MyVec<T>{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> IntoIter<T>{
+        let vec: mem::ManuallyDrop<MyVec<T>> = mem::ManuallyDrop::new(self);
+        //SAFETY: el vector no se libera, el RawVec se mueve al iterador una única vez
+        let buf: RawVec<T> = unsafe { ptr::read(&vec.buf) };
+        IntoIter{ buf, front : 0, back : vec.len }
+    }
+}
+impl<T> Iterator for IntoIter<T>{
+    type Item = T;
+    fn next(&mut self) -> Option<T>{
+        if self.front == self.back{
+            return None;
+        }
+        self.front += 1;
+        //SAFETY: la posición front - 1 estaba viva y al avanzar front deja de pertenecer al iterador
+        Some(unsafe { ptr::read(self.buf.ptr().add(self.front - 1)) })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>){
+        (self.back - self.front, Some(self.back - self.front))
+    }
+}
+impl<T> DoubleEndedIterator for IntoIter<T>{
+    fn next_back(&mut self) -> Option<T>{
+        if self.front == self.back{
+            return None;
+        }
+        self.back -= 1;
+        //SAFETY: igual que next pero desde el final
+        Some(unsafe { ptr::read(self.buf.ptr().add(self.back)) })
+    }
+}
+impl<T> ExactSizeIterator for IntoIter<T>{}
+impl<T> Drop for IntoIter<T>{
+    fn drop(&mut self){
+        //SAFETY: solo los elementos que no se entregaron siguen vivos, luego el RawVec libera el bloque
+        unsafe {
+            let remaining: *mut [T] = ptr::slice_from_raw_parts_mut(self.buf.ptr().add(self.front), self.back - self.front);
+            ptr::drop_in_place(remaining);
+        }
+    }
+}
+///Iterador creado por `MyVec::drain`.
+pub struct Drain<'a, T>{
+    vec : &'a mut MyVec<T>,
+    front : usize,
+    back : usize,
+    tail_start : usize,
+    tail_len : usize
+}
+impl<T> Iterator for Drain<'_, T>{
+    type Item = T;
+    fn next(&mut self) -> Option<T>{
+        if self.front == self.back{
+            return None;
+        }
+        self.front += 1;
+        //SAFETY: las posiciones front..back siguen vivas y pertenecen al Drain
+        Some(unsafe { ptr::read(self.vec.buf.ptr().add(self.front - 1)) })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>){
+        (self.back - self.front, Some(self.back - self.front))
+    }
+}
+impl<T> DoubleEndedIterator for Drain<'_, T>{
+    fn next_back(&mut self) -> Option<T>{
+        if self.front == self.back{
+            return None;
+        }
+        self.back -= 1;
+        //SAFETY: igual que next pero desde el final del rango
+        Some(unsafe { ptr::read(self.vec.buf.ptr().add(self.back)) })
+    }
+}
+impl<T> ExactSizeIterator for Drain<'_, T>{}
+impl<T> Drop for Drain<'_, T>{
+    fn drop(&mut self){
+        //El guardia cierra el hueco aunque liberar un elemento del rango entre en panico
+        struct MoveTail<'r, 'a, T>(&'r mut Drain<'a, T>);
+        impl<T> Drop for MoveTail<'_, '_, T>{
+            fn drop(&mut self){
+                let drain: &mut Drain<'_, T> = &mut *self.0;
+                let start: usize = drain.vec.len;
+                //SAFETY: la cola está viva en tail_start.. y el destino start.. está libre, ptr::copy admite solapamiento
+                unsafe {
+                    let base: *mut T = drain.vec.buf.ptr();
+                    ptr::copy(base.add(drain.tail_start), base.add(start), drain.tail_len);
+                }
+                drain.vec.len = start + drain.tail_len;
+            }
+        }
+        let remaining: *mut [T] = {
+            //SAFETY: front..back son los elementos que no se entregaron
+            unsafe { ptr::slice_from_raw_parts_mut(self.vec.buf.ptr().add(self.front), self.back - self.front) }
+        };
+        self.front = self.back;
+        let _guard: MoveTail<'_, '_, T> = MoveTail(self);
+        //SAFETY: esos elementos siguen inicializados y nadie más los libera
+        unsafe { ptr::drop_in_place(remaining) }
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use std::cell::Cell;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    //Miri es varios ordenes de magnitud más lento, con él se usan tamaños pequeños
+    const N : usize = if cfg!(miri) { 100 } else { 10_000 };
+
+    ///Cuenta cuantas veces se libera un valor, opcionalmente entra en panico al liberarse.
+    struct Counted{
+        drops : Rc<Cell<usize>>,
+        panic_on_drop : bool
+    }
+    impl Drop for Counted{
+        fn drop(&mut self){
+            self.drops.set(self.drops.get() + 1);
+            if self.panic_on_drop{
+                panic!("panico al liberar");
+            }
+        }
+    }
+    fn counted(drops : &Rc<Cell<usize>> , panic_on_drop : bool) -> Counted{
+        Counted{ drops : Rc::clone(drops), panic_on_drop }
+    }
+    #[test]
+    fn push_pop_matches_std_vec(){
+        let mut vec : MyVec<usize> = MyVec::new();
+        let mut model : Vec<usize> = Vec::new();
+        for i in 0..N{
+            vec.push(i);
+            model.push(i);
+            if i % 3 == 0{
+                assert_eq!(vec.pop() , model.pop());
+            }
+        }
+        assert_eq!(vec.as_slice() , model.as_slice());
+        assert!(vec.capacity() >= vec.len());
+        while let Some(value) = model.pop(){
+            assert_eq!(vec.pop() , Some(value));
+        }
+        assert_eq!(vec.pop() , None);
+        assert!(vec.empty());
+    }
+    #[test]
+    fn amortized_growth_doubles_capacity(){
+        let mut vec : MyVec<i32> = MyVec::new();
+        assert_eq!(vec.capacity() , 0);
+        let mut reallocations : usize = 0;
+        let mut last_capacity : usize = 0;
+        for i in 0..1_000{
+            vec.push(i);
+            if vec.capacity() != last_capacity{
+                reallocations += 1;
+                last_capacity = vec.capacity();
+            }
+        }
+        //4 , 8 , 16 , ... , 1024
+        assert_eq!(reallocations , 9);
+    }
+    #[test]
+    fn insert_and_remove_shift_elements(){
+        let mut vec : MyVec<i32> = MyVec::new();
+        vec.insert(0, 2);
+        vec.insert(0, 0);
+        vec.insert(1, 1);
+        vec.insert(3, 3);
+        assert_eq!(&*vec , &[0 , 1 , 2 , 3]);
+        assert_eq!(vec.remove(1) , 1);
+        assert_eq!(vec.remove(2) , 3);
+        assert_eq!(&*vec , &[0 , 2]);
+    }
+    #[test]
+    #[should_panic]
+    fn insert_out_of_bounds_panics(){
+        let mut vec : MyVec<i32> = MyVec::new();
+        vec.insert(1, 10);
+    }
+    #[test]
+    #[should_panic(expected = "attempted to index slice up to maximum usize")]
+    fn drain_up_to_usize_max_panics_without_overflow(){
+        let mut vec : MyVec<i32> = (0..4).collect();
+        vec.drain(2..=usize::MAX);
+    }
+    #[test]
+    #[should_panic(expected = "attempted to index slice from after maximum usize")]
+    fn drain_from_after_usize_max_panics_without_overflow(){
+        let mut vec : MyVec<i32> = (0..4).collect();
+        vec.drain((std::ops::Bound::Excluded(usize::MAX), std::ops::Bound::Unbounded));
+    }
+    #[test]
+    fn deref_exposes_slice_methods(){
+        let mut vec : MyVec<i32> = [5 , 3 , 1 , 4].into_iter().collect();
+        vec.sort();
+        assert_eq!(vec.first() , Some(&1));
+        assert_eq!(vec[3] , 5);
+        assert!(vec.contains(&4));
+        vec[0] = 10;
+        assert_eq!(format!("{:?}" , vec) , "[10, 3, 4, 5]");
+    }
+    #[test]
+    fn drain_middle_range(){
+        let mut vec : MyVec<String> = (0..6).map(|i| i.to_string()).collect();
+        let drained : Vec<String> = vec.drain(1..4).collect();
+        assert_eq!(drained , vec!["1" , "2" , "3"]);
+        assert_eq!(vec.iter().map(String::as_str).collect::<Vec<&str>>() , vec!["0" , "4" , "5"]);
+        //Un drain que no se consume completo igual retira todo el rango
+        let mut partial = vec.drain(..2);
+        assert_eq!(partial.next_back().as_deref() , Some("4"));
+        drop(partial);
+        assert_eq!(vec.iter().map(String::as_str).collect::<Vec<&str>>() , vec!["5"]);
+        assert_eq!(vec.drain(..).count() , 1);
+        assert!(vec.empty());
+    }
+    #[test]
+    fn into_iter_from_both_ends_drops_the_rest(){
+        let drops : Rc<Cell<usize>> = Rc::new(Cell::new(0));
+        let vec : MyVec<Counted> = (0..10).map(|_| counted(&drops, false)).collect();
+        let mut iter : IntoIter<Counted> = vec.into_iter();
+        drop(iter.next());
+        drop(iter.next_back());
+        assert_eq!(iter.len() , 8);
+        assert_eq!(drops.get() , 2);
+        drop(iter);
+        assert_eq!(drops.get() , 10);
+    }
+    #[test]
+    fn zero_sized_types(){
+        let mut vec : MyVec<()> = MyVec::new();
+        assert_eq!(vec.capacity() , usize::MAX);
+        for _ in 0..N{
+            vec.push(());
+        }
+        vec.insert(5, ());
+        vec.remove(0);
+        assert_eq!(vec.len() , N);
+        assert_eq!(vec.drain(..10).count() , 10);
+        assert_eq!(vec.into_iter().rev().count() , N - 10);
+    }
+    #[test]
+    fn drops_every_element_once(){
+        let drops : Rc<Cell<usize>> = Rc::new(Cell::new(0));
+        {
+            let mut vec : MyVec<Counted> = MyVec::new();
+            for _ in 0..20{
+                vec.push(counted(&drops, false));
+            }
+            drop(vec.remove(3));
+            drop(vec.pop());
+            vec.drain(0..5);
+            assert_eq!(drops.get() , 7);
+        }
+        assert_eq!(drops.get() , 20);
+    }
+    #[test]
+    fn panic_while_dropping_releases_the_rest(){
+        let drops : Rc<Cell<usize>> = Rc::new(Cell::new(0));
+        let mut vec : MyVec<Counted> = MyVec::new();
+        for i in 0..5{
+            vec.push(counted(&drops, i == 1));
+        }
+        let result = panic::catch_unwind(AssertUnwindSafe(move || drop(vec)));
+        assert!(result.is_err());
+        assert_eq!(drops.get() , 5);
+    }
+    #[test]
+    fn panic_inside_drain_keeps_the_tail(){
+        let drops : Rc<Cell<usize>> = Rc::new(Cell::new(0));
+        let mut vec : MyVec<Counted> = MyVec::new();
+        for i in 0..6{
+            vec.push(counted(&drops, i == 2));
+        }
+        let result = panic::catch_unwind(AssertUnwindSafe(|| { vec.drain(1..4); }));
+        assert!(result.is_err());
+        assert_eq!(drops.get() , 3);
+        assert_eq!(vec.len() , 3);
+        drop(vec);
+        assert_eq!(drops.get() , 6);
+    }
+}
@@ -0,0 +1,191 @@
+//! Pruebas compartidas por los montículos que implementan `PriorityQueue`, cada implementación invoca la macro dentro
+//! de su propio modulo de pruebas.
+
+///Secuencia pseudoaleatoria reproducible en `0..modulo`.
+pub(crate) fn pseudo_random(count : usize , seed : u64 , modulo : u64) -> Vec<i64>{
+    let mut state: u64 = seed;
+    (0..count).map(|_| {
+        state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        ((state >> 33) % modulo) as i64
+    }).collect()
+}
+
+///El tipo debe exponer `new` e implementar `PriorityQueue`. Con `arena` se indica cuántas posiciones ocupa el arena del
+///montículo para comprobar que las posiciones liberadas se reutilizan.
+macro_rules! priority_queue_tests {
+    ($heap:ident , arena : $arena:expr) => {
+        $crate::no_lineal::priority_queue_tests::priority_queue_tests!($heap);
+        #[test]
+        fn steady_size_reuses_freed_positions(){
+            let arena : fn(&$heap<i64>) -> usize = $arena;
+            let mut heap : $heap<i64> = $heap::new();
+            let values : Vec<i64> = pseudo_random(5_000, 17, 1_000);
+            for &value in values.iter().take(32){
+                heap.push(value);
+            }
+            for &value in values.iter().skip(32){
+                //`-1` es el menor, su manejador queda viejo apenas sale y el siguiente push reutiliza su posición
+                let stale = heap.push(-1);
+                assert_eq!(heap.pop_min() , Some(-1));
+                heap.push(value);
+                assert!(heap.decrease_key(stale, -2).is_err());
+                assert!(heap.pop_min().is_some_and(|popped| popped >= 0));
+                assert_eq!(heap.len() , 32);
+            }
+            //El arena depende del mayor tamaño alcanzado y no de las miles de inserciones
+            assert!(arena(&heap) <= 33, "arena de {} posiciones", arena(&heap));
+        }
+    };
+    ($heap:ident) => {
+        use $crate::no_lineal::priority_queue_tests::pseudo_random;
+        #[test]
+        fn pops_in_ascending_order(){
+            let mut heap : $heap<i64> = $heap::new();
+            assert!(heap.empty());
+            assert_eq!(heap.pop_min() , None);
+            let values : Vec<i64> = pseudo_random(500, 5, 1_000);
+            for &value in values.iter(){
+                heap.push(value);
+            }
+            let mut sorted : Vec<i64> = values.clone();
+            sorted.sort();
+            assert_eq!(heap.len() , sorted.len());
+            assert_eq!(heap.peek_min() , sorted.first());
+            let popped : Vec<i64> = std::iter::from_fn(|| heap.pop_min()).collect();
+            assert_eq!(popped , sorted);
+            assert!(heap.empty());
+        }
+        #[test]
+        fn interleaved_push_and_pop(){
+            let mut heap : $heap<i64> = $heap::new();
+            let mut model : std::collections::BinaryHeap<std::cmp::Reverse<i64>> = std::collections::BinaryHeap::new();
+            for (step, value) in pseudo_random(2_000, 9, 500).into_iter().enumerate(){
+                if step % 3 == 2{
+                    assert_eq!(heap.pop_min() , model.pop().map(|std::cmp::Reverse(value)| value));
+                }else {
+                    heap.push(value);
+                    model.push(std::cmp::Reverse(value));
+                }
+                assert_eq!(heap.peek_min() , model.peek().map(|std::cmp::Reverse(value)| value));
+                assert_eq!(heap.len() , model.len());
+            }
+        }
+        #[test]
+        fn meld_keeps_handles_of_both_heaps(){
+            let mut first : $heap<i64> = $heap::new();
+            let mut second : $heap<i64> = $heap::new();
+            let a = first.push(50);
+            first.push(70);
+            let b = second.push(60);
+            second.push(80);
+            let mut third : $heap<i64> = $heap::new();
+            let c = third.push(90);
+            second.meld(third);
+            first.meld(second);
+            assert_eq!(first.len() , 5);
+            first.decrease_key(b, 10).unwrap();
+            first.decrease_key(c, 20).unwrap();
+            assert!(first.decrease_key(a, 55).is_err());
+            let popped : Vec<i64> = std::iter::from_fn(|| first.pop_min()).collect();
+            assert_eq!(popped , vec![10 , 20 , 50 , 70 , 80]);
+            //Fusionar con un montículo vacio en ambos sentidos
+            let mut empty : $heap<i64> = $heap::new();
+            empty.meld($heap::new());
+            let d = empty.push(1);
+            let mut other : $heap<i64> = $heap::new();
+            other.meld(empty);
+            other.decrease_key(d, 0).unwrap();
+            assert_eq!(other.pop_min() , Some(0));
+        }
+        #[test]
+        fn meld_in_both_directions_keeps_handles(){
+            //Alterna fusionar un montículo chico en uno grande y uno grande en uno chico
+            let mut heap : $heap<i64> = $heap::new();
+            let mut handles = Vec::new();
+            for round in 0..20{
+                let mut other : $heap<i64> = $heap::new();
+                let size : i64 = if round % 2 == 0 { 3 } else { 4 * (round + 1) };
+                for value in 0..size{
+                    handles.push((other.push(1_000 + round * 100 + value), round * 100 + value));
+                }
+                if round % 3 == 0{
+                    other.meld(heap);
+                    heap = other;
+                }else {
+                    heap.meld(other);
+                }
+            }
+            //Cada manejador sigue llegando a su elemento sin importar a qué arena se copió
+            for &(handle, lowered) in handles.iter(){
+                heap.decrease_key(handle, lowered).unwrap();
+            }
+            let mut expected : Vec<i64> = handles.iter().map(|&(_, lowered)| lowered).collect();
+            expected.sort();
+            let popped : Vec<i64> = std::iter::from_fn(|| heap.pop_min()).collect();
+            assert_eq!(popped , expected);
+        }
+        #[test]
+        fn decrease_key_errors(){
+            let mut heap : $heap<i64> = $heap::new();
+            let a = heap.push(10);
+            let b = heap.push(20);
+            assert!(heap.decrease_key(b, 30).is_err());
+            heap.decrease_key(b, 20).unwrap();
+            assert_eq!(heap.pop_min() , Some(10));
+            //El elemento de `a` ya salió del montículo
+            assert!(heap.decrease_key(a, 0).is_err());
+            let mut other : $heap<i64> = $heap::new();
+            other.push(5);
+            assert!(other.decrease_key(b, 0).is_err());
+        }
+        #[test]
+        fn random_decrease_key_matches_brute_force(){
+            //Cada valor es prioridad * 10_000 + id, así el id sobrevive a las disminuciones y se sabe qué elemento salió
+            let mut heap : $heap<i64> = $heap::new();
+            let mut handles = Vec::new();
+            let mut model : Vec<Option<i64>> = Vec::new();
+            for (step, value) in pseudo_random(3_000, 31, 1_000).into_iter().enumerate(){
+                match step % 5 {
+                    0 | 1 => {
+                        let id : i64 = model.len() as i64;
+                        let priority : i64 = (value + 1_000) * 10_000 + id;
+                        handles.push(heap.push(priority));
+                        model.push(Some(priority));
+                    },
+                    2 | 3 => {
+                        if model.is_empty(){
+                            continue;
+                        }
+                        let target : usize = value as usize % model.len();
+                        if let Some(current) = model[target]{
+                            let lowered : i64 = current - (value % 7) * 10_000;
+                            heap.decrease_key(handles[target], lowered).unwrap();
+                            model[target] = Some(lowered);
+                        }else {
+                            assert!(heap.decrease_key(handles[target], 0).is_err());
+                        }
+                    },
+                    _ => {
+                        let expected : Option<i64> = model.iter().flatten().min().copied();
+                        let popped : Option<i64> = heap.pop_min();
+                        assert_eq!(popped , expected);
+                        if let Some(value) = popped{
+                            model[(value.rem_euclid(10_000)) as usize] = None;
+                        }
+                    }
+                }
+                assert_eq!(heap.len() , model.iter().flatten().count());
+            }
+        }
+        #[test]
+        fn owned_values_are_dropped(){
+            let mut heap : $heap<String> = $heap::new();
+            for i in 0..100{
+                heap.push(format!("{:03}", i));
+            }
+            assert_eq!(heap.pop_min().as_deref() , Some("000"));
+            assert_eq!(heap.peek_min().map(String::as_str) , Some("001"));
+        }
+    };
+}
+pub(crate) use priority_queue_tests;
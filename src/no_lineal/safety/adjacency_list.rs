@@ -0,0 +1,39 @@
+//! ### Lista de adyacencia
+//! Cada nodo guarda un vector con sus pares `(vecino, arista)` en el orden en que se agregaron.
+//! ```text
+//!   0 : [ (1 , e0) , (2 , e3) ]
+//!   1 : [ (2 , e1) ]
+//!   2 : [ (0 , e2) ]
+//! ```
+//! Admite aristas paralelas y lazos, buscar una arista concreta es O(grado).
+use std::iter::Copied;
+use std::slice;
+use super::graph::{EdgeId, GraphStorage, NodeId};
+
+#[derive(Debug, Clone, Default)]
+pub struct AdjacencyList{
+    adjacency : Vec<Vec<(NodeId, EdgeId)>>
+}
+impl GraphStorage for AdjacencyList{
+    type Neighbors<'a> = Copied<slice::Iter<'a, (NodeId, EdgeId)>>;
+    fn add_node(&mut self){
+        self.adjacency.push(Vec::new());
+    }
+    fn add_edge(&mut self , from : NodeId , to : NodeId , edge : EdgeId) -> Result<(), String>{
+        self.adjacency[from.index()].push((to, edge));
+        Ok(())
+    }
+    fn remove_edge(&mut self , from : NodeId , _to : NodeId , edge : EdgeId){
+        let list: &mut Vec<(NodeId, EdgeId)> = &mut self.adjacency[from.index()];
+        //`remove` y no `swap_remove` para conservar el orden de inserción de los vecinos
+        if let Some(position) = list.iter().position(|&(_, current)| current == edge){
+            list.remove(position);
+        }
+    }
+    fn neighbors(&self , node : NodeId) -> Self::Neighbors<'_>{
+        self.adjacency.get(node.index()).map_or(&[][..], Vec::as_slice).iter().copied()
+    }
+    fn find_edge(&self , from : NodeId , to : NodeId) -> Option<EdgeId>{
+        self.neighbors(from).find(|&(neighbor, _)| neighbor == to).map(|(_, edge)| edge)
+    }
+}
@@ -0,0 +1,55 @@
+//! ### Matriz de adyacencia
+//! La celda `(i , j)` guarda la arista `i -> j` si existe.
+//! ```text
+//!          0      1      2
+//!   0 | None | e0   | e3   |
+//!   1 | None | None | e1   |
+//!   2 | e2   | None | None |
+//! ```
+//! Consultar una arista es O(1) pero los vecinos se recorren en O(V) y la memoria es O(V²), conviene en grafos densos.
+//! Como cada par tiene una sola celda no admite aristas paralelas.
+use std::iter::{Enumerate, FilterMap};
+use std::slice;
+use super::graph::{EdgeId, GraphStorage, NodeId};
+
+type Present = fn((usize, &Option<EdgeId>)) -> Option<(NodeId, EdgeId)>;
+
+#[derive(Debug, Clone, Default)]
+pub struct AdjacencyMatrix{
+    matrix : Vec<Vec<Option<EdgeId>>>
+}
+impl AdjacencyMatrix{
+    fn present((index, cell) : (usize, &Option<EdgeId>)) -> Option<(NodeId, EdgeId)>{
+        cell.map(|edge| (NodeId(index), edge))
+    }
+}
+impl GraphStorage for AdjacencyMatrix{
+    type Neighbors<'a> = FilterMap<Enumerate<slice::Iter<'a, Option<EdgeId>>>, Present>;
+    fn add_node(&mut self){
+        for row in self.matrix.iter_mut(){
+            row.push(None);
+        }
+        self.matrix.push(vec![None; self.matrix.len() + 1]);
+    }
+    fn add_edge(&mut self , from : NodeId , to : NodeId , edge : EdgeId) -> Result<(), String>{
+        let cell: &mut Option<EdgeId> = &mut self.matrix[from.index()][to.index()];
+        if cell.is_some(){
+            return Err(format!("la matriz de adyacencia no admite aristas paralelas entre {:?} y {:?}", from, to));
+        }
+        *cell = Some(edge);
+        Ok(())
+    }
+    fn remove_edge(&mut self , from : NodeId , to : NodeId , edge : EdgeId){
+        let cell: &mut Option<EdgeId> = &mut self.matrix[from.index()][to.index()];
+        if *cell == Some(edge){
+            *cell = None;
+        }
+    }
+    fn neighbors(&self , node : NodeId) -> Self::Neighbors<'_>{
+        let present: Present = Self::present;
+        self.matrix.get(node.index()).map_or(&[][..], Vec::as_slice).iter().enumerate().filter_map(present)
+    }
+    fn find_edge(&self , from : NodeId , to : NodeId) -> Option<EdgeId>{
+        self.matrix.get(from.index())?.get(to.index()).copied().flatten()
+    }
+}
@@ -158,15 +158,21 @@
 //! - `Rotaciones Dobles`:
 use num::Integer;
 use std::{cmp::Ordering, fmt::{Debug, Display}, isize, ops::Deref};
+use crate::lineal::safety::set::Set;
+///Valores que cargan un dato derivado de su subárbol (por ejemplo el mayor extremo de un arbol de intervalos).
+///`update_height_node` lo recalcula junto a la altura, así las rotaciones lo mantienen al día sin código extra.
+pub trait Augmented{
+    fn refresh(&mut self , _left : Option<&Self> , _right : Option<&Self>){}
+}
+impl<T : Integer> Augmented for T{}
 #[derive(Debug)]
 pub struct AVLNode<T>{
-    left : Option<Box<AVLNode<T>>>,
-    value : T,
-    right : Option<Box<AVLNode<T>>>,
-    height : isize
+    pub(crate) left : Option<Box<AVLNode<T>>>,
+    pub(crate) value : T,
+    pub(crate) right : Option<Box<AVLNode<T>>>,
+    pub(crate) height : isize
 }
-impl <T> AVLNode<T>
-where T : Integer + Clone + Copy + Display + Debug + Ord {
+impl <T> AVLNode<T>{
     ///En teoria todos los nodos insertados, se insertan como Hojas, es por ello que no necesitamos especificar realmente un
     ///calculo complejo de la altura. quedese con esta idea, cada nodo creado es una Hoja.
     pub fn new(value : T) -> Self{
@@ -180,11 +186,10 @@ where T : Integer + Clone + Copy + Display + Debug + Ord {
 }
 #[derive(Debug)]
 pub struct AVLTree<T>{
-    root : Option<Box<AVLNode<T>>>,
-    size : usize 
+    pub(crate) root : Option<Box<AVLNode<T>>>,
+    pub(crate) size : usize 
 }
-impl <T> AVLTree<T> 
-where T : Integer + Clone + Copy + Display + Debug + Ord{
+impl <T : Augmented> AVLTree<T>{
     pub fn new() -> Self{
         Self{
             root : None,
@@ -213,6 +218,8 @@ where T : Integer + Clone + Copy + Display + Debug + Ord{
                 let height_left = Self::height(&mut n.left);
                 let height_right = Self::height(&mut n.right);
                 n.height = *[height_left, height_right].iter().max().unwrap() + 1;
+                let node: &mut AVLNode<T> = n;
+                node.value.refresh(node.left.as_deref().map(|left| &left.value), node.right.as_deref().map(|right| &right.value));
             }
         }
     }
@@ -685,16 +692,20 @@ where T : Integer + Clone + Copy + Display + Debug + Ord{
         }else if balance_factor == -2 {
             let right_child_balance_factor : Option<isize> = node.as_mut().and_then(|n| {Some(Self::balance_factor(&mut n.right))});
             if let Some(balance_child) = right_child_balance_factor{
-                if balance_child >= 0{ //right_left_rotation
+                //Un hijo balanceado (solo ocurre al eliminar) se corrige con la rotación simple, igual que a la izquierda
+                if balance_child == 1{ //right_left_rotation
                     node = Self::right_left_rotation(node.take());
 
-                }else if balance_child == -1 {//simple rotation left
+                }else if balance_child <= 0 {//simple rotation left
                     node = Self::simple_rotation_left(node.take());
                 }
             }
         }
         node
     }
+}
+impl <T> AVLTree<T>
+where T : Integer + Clone + Copy + Display + Debug + Ord{
     ///Un metodo de la implementación que permite hacer una inserción recursiva, dicho metodo es el encargado de implementar
     ///toda la logica dentro del arbol para el balance de mismo, esta función garantiza una capa de abstracción que mantiene 
     /// el arbol totalmente balanceado.
@@ -747,6 +758,8 @@ where T : Integer + Clone + Copy + Display + Debug + Ord{
                                     n.left = Self::remove_recursibly(n.left.take(), max_left_subtree);
                                     n.value = max_left_subtree;
                                 }
+                                //El subárbol izquierdo pudo bajar de altura, rebalance necesita la altura al día
+                                Self::update_height_node(&mut node);
                             }
                         }
                     },
@@ -830,6 +843,23 @@ where T : Integer + Clone + Copy + Display + Debug + Ord{
             Self::inorder_recursive(&node_unw.right);
         }
     }
+    ///Valores del arbol en recorrido inorder, es decir ordenados de menor a mayor.
+    pub fn inorder_values(&self) -> Vec<T>{
+        let mut values: Vec<T> = Vec::with_capacity(self.size);
+        let mut stack: Vec<&AVLNode<T>> = Vec::new();
+        let mut current: &Option<Box<AVLNode<T>>> = &self.root;
+        while current.is_some() || !stack.is_empty(){
+            while let Some(node) = current{
+                stack.push(node);
+                current = &node.left;
+            }
+            if let Some(node) = stack.pop(){
+                values.push(node.value);
+                current = &node.right;
+            }
+        }
+        values
+    }
     /// ### Recorrido PostOrder
     /// En el recorrido inorder se recorre primero recursivamente el subarbol derecho de la raiz, luego el nodo raiz
     /// y por ultimo recursivamente el subarbol izquierdo del nodo raiz
@@ -888,15 +918,96 @@ where T : Integer + Clone + Copy + Display + Debug + Ord{
             Self::verify_property_avl(&mut n.left);
             Self::verify_property_avl(&mut n.right);
         }
-        if balance_factor_current_node >= 2{
+        if balance_factor_current_node.abs() >= 2{
             panic!("No cumple la propiedad AVL");
         }
     }
 
 }
+///### Drop
+/// Aunque un AVL balanceado tiene altura O(log(n)), los nodos pueden enlazarse a mano (como en las pruebas de rotación) y la
+/// liberación recursiva por defecto dependeria de esa altura, por ello se libera el arbol con una pila propia.
+impl<T> Drop for AVLTree<T>{
+    fn drop(&mut self){
+        let mut stack: Vec<Box<AVLNode<T>>> = Vec::new();
+        if let Some(root) = self.root.take(){
+            stack.push(root);
+        }
+        while let Some(mut node) = stack.pop(){
+            if let Some(left) = node.left.take(){
+                stack.push(left);
+            }
+            if let Some(right) = node.right.take(){
+                stack.push(right);
+            }
+        }
+    }
+}
+impl<T> AVLTree<T>{
+    ///Recorre los valores en orden ascendente sin copiarlos.
+    pub fn iter(&self) -> Iter<'_, T>{
+        let mut iter: Iter<'_, T> = Iter{ stack : Vec::new() };
+        iter.push_left(self.root.as_deref());
+        iter
+    }
+}
+///Iterador inorder por referencia de `AVLTree`, la pila guarda la rama izquierda pendiente.
+pub struct Iter<'a, T>{
+    stack : Vec<&'a AVLNode<T>>
+}
+impl<'a, T> Iter<'a, T>{
+    fn push_left(&mut self , mut node : Option<&'a AVLNode<T>>){
+        while let Some(current) = node{
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+impl<'a, T> Iterator for Iter<'a, T>{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T>{
+        let node: &'a AVLNode<T> = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+        Some(&node.value)
+    }
+}
+///### Set
+/// `insert_node` acepta repetidos y `remove_node` no admite valores ausentes, por ello la implementación del conjunto
+/// consulta primero con `search`.
+impl<T> Set<T> for AVLTree<T>
+where T : Integer + Clone + Copy + Display + Debug + Ord{
+    type Iter<'a> = Iter<'a, T> where Self : 'a, T : 'a;
+    fn new_set() -> Self{
+        Self::new()
+    }
+    fn insert(&mut self , value : T) -> bool{
+        if self.search(value).is_some(){
+            return false;
+        }
+        self.insert_node(value);
+        true
+    }
+    fn contains(&self , value : &T) -> bool{
+        self.search(*value).is_some()
+    }
+    fn remove(&mut self , value : &T) -> bool{
+        if self.search(*value).is_none(){
+            return false;
+        }
+        self.remove_node(*value);
+        true
+    }
+    fn len(&self) -> usize{
+        self.size
+    }
+    fn iter(&self) -> Iter<'_, T>{
+        AVLTree::iter(self)
+    }
+}
 #[cfg(test)]
 mod tests{
     use super::*;
+    use crate::random::XorShift64;
     #[test]
     fn test_insertion(){
         let mut tree: AVLTree<i32> = AVLTree::new();
@@ -1018,6 +1129,56 @@ mod tests{
         println!("{:?}" , tree);
         tree.is_avl();
     }
+    ///Recalcula la altura real de cada nodo, verifica la guardada y el factor de balance.
+    fn checked_height(node : &Option<Box<AVLNode<i32>>>) -> isize{
+        match node{
+            None => -1,
+            Some(n) => {
+                let (left, right) = (checked_height(&n.left), checked_height(&n.right));
+                assert!((left - right).abs() <= 1, "No cumple la propiedad AVL en {}", n.value);
+                assert_eq!(n.height , left.max(right) + 1);
+                n.height
+            }
+        }
+    }
+    #[test]
+    fn remove_with_balanced_right_child(){
+        //       10           Al eliminar 3 la raíz queda con factor -2 y su hijo derecho 30 con factor 0, la rotación
+        //     /    \         doble dejaría a 30 con factor -2, la simple a la izquierda deja el arbol balanceado.
+        //    5      30
+        //   /      /  \
+        //  3      20   40
+        //        /       \
+        //       15        45
+        let mut tree : AVLTree<i32> = AVLTree::new();
+        for value in [10 , 5 , 30 , 3 , 20 , 40 , 15 , 45]{
+            tree.insert_node(value);
+        }
+        tree.remove_node(3);
+        checked_height(&tree.root);
+        tree.is_avl();
+        assert_eq!(tree.root.as_ref().map(|root| root.value) , Some(30));
+        assert_eq!(tree.inorder_values() , vec![5 , 10 , 15 , 20 , 30 , 40 , 45]);
+    }
+    #[test]
+    fn random_removals_keep_balance(){
+        let mut rng : XorShift64 = XorShift64::new(17);
+        let mut tree : AVLTree<i32> = AVLTree::new();
+        let mut values : Vec<i32> = Vec::new();
+        for _ in 0..3_000{
+            if rng.next_u64() % 3 == 0 && !values.is_empty(){
+                let value : i32 = values.swap_remove(rng.next_u64() as usize % values.len());
+                tree.remove_node(value);
+            }else {
+                let value : i32 = (rng.next_u64() % 200) as i32;
+                tree.insert_node(value);
+                values.push(value);
+            }
+            checked_height(&tree.root);
+        }
+        values.sort();
+        assert_eq!(tree.inorder_values() , values);
+    }
     #[test]
     fn find_min_on_tree(){
         let mut tree : AVLTree<i32> = AVLTree::new();
@@ -1044,4 +1205,17 @@ mod tests{
 
 
     }
-}
\ No newline at end of file
+    #[test]
+    fn drop_degenerate_million_nodes(){
+        let mut avl_tree : AVLTree<i32> = AVLTree::new();
+        let mut root: Option<Box<AVLNode<i32>>> = None;
+        for i in (1..=1_000_000).rev(){
+            let mut node: Box<AVLNode<i32>> = Box::new(AVLNode::new(i));
+            node.right = root.take();
+            root = Some(node);
+        }
+        avl_tree.root = root;
+        avl_tree.size = 1_000_000;
+        drop(avl_tree);
+    }
+}
@@ -0,0 +1,521 @@
+//! ### Sobre los arboles B+
+//! Variante del arbol B pensada para recorridos: todos los pares clave-valor viven en las hojas, los nodos internos
+//! solo guardan copias de claves como separadores, y las hojas forman una lista enlazada de izquierda a derecha.
+//! ```text
+//!                              [ 30 | 60 ]
+//!                            /     |      \
+//!              [ 10 | 20 ] --> [ 30 | 40 ] --> [ 60 | 70 | 80 ] --> None
+//!                 v    v         v    v          v    v    v
+//! ```
+//! - El hijo `i` de un nodo interno tiene las claves en `[keys[i - 1], keys[i])`, por ello un separador es la menor clave
+//!   de su subarbol derecho en el momento en que se creó.
+//! - Un `range` baja una sola vez hasta la hoja del inicio y después solo sigue los enlaces `next` de las hojas.
+//!
+//! Las hojas se enlazan entre sí, así que los nodos viven en una arena (`Vec`) y se referencian por indice, igual que en
+//! la `SkipList` safe. Los indices liberados por un `merge` se reutilizan.
+//!
+//! Las reglas de `insert` y `remove` son las del `BTree` (partir al llenarse, `borrow` o `merge` al quedar bajo el
+//! minimo), con dos diferencias: al partir una hoja la clave separadora se copia hacia arriba en vez de moverse, y los
+//! `borrow`/`merge` entre hojas actualizan el separador del padre y la lista de hojas.
+use std::borrow::Borrow;
+use std::fmt::{self, Debug};
+use std::mem;
+use std::ops::{Bound, RangeBounds};
+use super::b_tree::even_sizes;
+
+struct BPlusNode<K, V>{
+    keys : Vec<K>,
+    ///Vacio en las hojas.
+    children : Vec<usize>,
+    ///Vacio en los nodos internos, paralelo a `keys` en las hojas.
+    values : Vec<V>,
+    next : Option<usize>,
+    leaf : bool
+}
+impl<K, V> BPlusNode<K, V>{
+    fn leaf() -> Self{
+        Self{ keys : Vec::new(), children : Vec::new(), values : Vec::new(), next : None, leaf : true }
+    }
+    fn internal(keys : Vec<K> , children : Vec<usize>) -> Self{
+        Self{ keys, children, values : Vec::new(), next : None, leaf : false }
+    }
+}
+impl<K, V> Default for BPlusNode<K, V>{
+    fn default() -> Self{
+        Self::leaf()
+    }
+}
+///### BPlusTree
+/// Mapa ordenado sobre un arbol B+ de orden `B` (maximo de hijos por nodo), `B` debe ser al menos 3.
+pub struct BPlusTree<K, V, const B : usize>{
+    nodes : Vec<BPlusNode<K, V>>,
+    free : Vec<usize>,
+    root : usize,
+    len : usize
+}
+impl<K : Ord, V, const B : usize> BPlusTree<K, V, B>{
+    const VALID_ORDER : () = assert!(B >= 3, "el orden de un arbol B+ debe ser al menos 3");
+    ///Minimo de claves en un nodo que no es la raíz.
+    const MIN_KEYS : usize = (B - 1) / 2;
+
+    pub fn new() -> Self{
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_ORDER;
+        Self{ nodes : vec![BPlusNode::leaf()], free : Vec::new(), root : 0, len : 0 }
+    }
+    pub fn len(&self) -> usize{
+        self.len
+    }
+    pub fn empty(&self) -> bool{
+        self.len == 0
+    }
+    ///Cantidad de niveles, un arbol de una sola hoja tiene altura 1.
+    pub fn height(&self) -> usize{
+        let mut height: usize = 1;
+        let mut node: usize = self.root;
+        while !self.nodes[node].leaf{
+            node = self.nodes[node].children[0];
+            height += 1;
+        }
+        height
+    }
+    ///Cantidad de nodos vivos.
+    pub fn node_count(&self) -> usize{
+        self.nodes.len() - self.free.len()
+    }
+    fn allocate(&mut self , node : BPlusNode<K, V>) -> usize{
+        match self.free.pop(){
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            },
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+    ///Indice del hijo que cubre a `key`: la cantidad de separadores menores o iguales.
+    fn child_index<Q>(node : &BPlusNode<K, V> , key : &Q) -> usize
+    where K : Borrow<Q>, Q : Ord + ?Sized{
+        node.keys.partition_point(|separator| separator.borrow() <= key)
+    }
+    fn find_leaf<Q>(&self , key : &Q) -> usize
+    where K : Borrow<Q>, Q : Ord + ?Sized{
+        let mut node: usize = self.root;
+        while !self.nodes[node].leaf{
+            node = self.nodes[node].children[Self::child_index(&self.nodes[node], key)];
+        }
+        node
+    }
+    pub fn get<Q>(&self , key : &Q) -> Option<&V>
+    where K : Borrow<Q>, Q : Ord + ?Sized{
+        let leaf: &BPlusNode<K, V> = &self.nodes[self.find_leaf(key)];
+        let index: usize = leaf.keys.binary_search_by(|probe| probe.borrow().cmp(key)).ok()?;
+        Some(&leaf.values[index])
+    }
+    pub fn get_mut<Q>(&mut self , key : &Q) -> Option<&mut V>
+    where K : Borrow<Q>, Q : Ord + ?Sized{
+        let leaf: usize = self.find_leaf(key);
+        let leaf: &mut BPlusNode<K, V> = &mut self.nodes[leaf];
+        let index: usize = leaf.keys.binary_search_by(|probe| probe.borrow().cmp(key)).ok()?;
+        Some(&mut leaf.values[index])
+    }
+    pub fn contains_key<Q>(&self , key : &Q) -> bool
+    where K : Borrow<Q>, Q : Ord + ?Sized{
+        self.get(key).is_some()
+    }
+    ///Inserta el par, si la clave existía reemplaza y retorna el valor anterior.
+    pub fn insert(&mut self , key : K , value : V) -> Option<V>
+    where K : Clone{
+        let root: usize = self.root;
+        match self.insert_recursive(root, key, value){
+            Insertion::Replaced(old) => return Some(old),
+            Insertion::Inserted => {},
+            Insertion::Split(separator, right) => {
+                let left: usize = self.root;
+                self.root = self.allocate(BPlusNode::internal(vec![separator], vec![left, right]));
+            }
+        }
+        self.len += 1;
+        None
+    }
+    fn insert_recursive(&mut self , node : usize , key : K , value : V) -> Insertion<K, V>
+    where K : Clone{
+        if self.nodes[node].leaf{
+            let leaf: &mut BPlusNode<K, V> = &mut self.nodes[node];
+            match leaf.keys.binary_search(&key){
+                Ok(index) => return Insertion::Replaced(mem::replace(&mut leaf.values[index], value)),
+                Err(index) => {
+                    leaf.keys.insert(index, key);
+                    leaf.values.insert(index, value);
+                }
+            }
+            if leaf.keys.len() < B{
+                return Insertion::Inserted;
+            }
+            //Las claves [B/2 ..] pasan a una hoja nueva y la primera se copia como separador
+            let keys: Vec<K> = leaf.keys.split_off(B / 2);
+            let values: Vec<V> = leaf.values.split_off(B / 2);
+            let separator: K = keys[0].clone();
+            let next: Option<usize> = leaf.next;
+            let right: usize = self.allocate(BPlusNode{ keys, children : Vec::new(), values, next, leaf : true });
+            self.nodes[node].next = Some(right);
+            return Insertion::Split(separator, right);
+        }
+        let index: usize = Self::child_index(&self.nodes[node], &key);
+        let child: usize = self.nodes[node].children[index];
+        let (separator, right): (K, usize) = match self.insert_recursive(child, key, value){
+            Insertion::Split(separator, right) => (separator, right),
+            other => return other
+        };
+        let internal: &mut BPlusNode<K, V> = &mut self.nodes[node];
+        internal.keys.insert(index, separator);
+        internal.children.insert(index + 1, right);
+        if internal.keys.len() < B{
+            return Insertion::Inserted;
+        }
+        //Igual que en el arbol B la clave del medio se mueve hacia arriba
+        let keys: Vec<K> = internal.keys.split_off(B / 2 + 1);
+        let children: Vec<usize> = internal.children.split_off(B / 2 + 1);
+        let median: K = internal.keys.pop().expect("el nodo lleno tiene clave del medio");
+        let right: usize = self.allocate(BPlusNode::internal(keys, children));
+        Insertion::Split(median, right)
+    }
+    ///Elimina la clave y retorna su valor.
+    pub fn remove<Q>(&mut self , key : &Q) -> Option<V>
+    where K : Borrow<Q> + Clone, Q : Ord + ?Sized{
+        let removed: V = self.remove_recursive(self.root, key)?;
+        let root: &BPlusNode<K, V> = &self.nodes[self.root];
+        if !root.leaf && root.keys.is_empty(){
+            let old_root: usize = self.root;
+            self.root = root.children[0];
+            self.release(old_root);
+        }
+        self.len -= 1;
+        Some(removed)
+    }
+    fn release(&mut self , node : usize){
+        self.nodes[node] = BPlusNode::leaf();
+        self.free.push(node);
+    }
+    fn remove_recursive<Q>(&mut self , node : usize , key : &Q) -> Option<V>
+    where K : Borrow<Q> + Clone, Q : Ord + ?Sized{
+        if self.nodes[node].leaf{
+            let leaf: &mut BPlusNode<K, V> = &mut self.nodes[node];
+            let index: usize = leaf.keys.binary_search_by(|probe| probe.borrow().cmp(key)).ok()?;
+            leaf.keys.remove(index);
+            return Some(leaf.values.remove(index));
+        }
+        let index: usize = Self::child_index(&self.nodes[node], key);
+        let removed: V = self.remove_recursive(self.nodes[node].children[index], key)?;
+        self.fix_child(node, index);
+        Some(removed)
+    }
+    ///Devuelve al hijo `index` de `parent` al minimo de claves con un `borrow` de un hermano o un `merge`.
+    fn fix_child(&mut self , parent : usize , index : usize)
+    where K : Clone{
+        let child: usize = self.nodes[parent].children[index];
+        if self.nodes[child].keys.len() >= Self::MIN_KEYS{
+            return;
+        }
+        let siblings: usize = self.nodes[parent].children.len();
+        let left: Option<usize> = index.checked_sub(1).map(|left| self.nodes[parent].children[left]);
+        let right: Option<usize> = (index + 1 < siblings).then(|| self.nodes[parent].children[index + 1]);
+        if let Some(left) = left.filter(|&left| self.nodes[left].keys.len() > Self::MIN_KEYS){
+            self.borrow_from_left(parent, index, left, child);
+        }else if let Some(right) = right.filter(|&right| self.nodes[right].keys.len() > Self::MIN_KEYS){
+            self.borrow_from_right(parent, index, child, right);
+        }else if let Some(left) = left{
+            self.merge(parent, index - 1, left, child);
+        }else if let Some(right) = right{
+            self.merge(parent, index, child, right);
+        }
+    }
+    fn borrow_from_left(&mut self , parent : usize , index : usize , left : usize , child : usize)
+    where K : Clone{
+        let mut sibling: BPlusNode<K, V> = mem::take(&mut self.nodes[left]);
+        let mut node: BPlusNode<K, V> = mem::take(&mut self.nodes[child]);
+        let separator: &mut K = &mut self.nodes[parent].keys[index - 1];
+        let key: K = sibling.keys.pop().expect("el hermano tiene de sobra");
+        if node.leaf{
+            //La clave prestada pasa a ser la menor de la hoja, el separador se copia de ella
+            *separator = key.clone();
+            node.keys.insert(0, key);
+            node.values.insert(0, sibling.values.pop().expect("la hoja tiene un valor por clave"));
+        }else {
+            node.keys.insert(0, mem::replace(separator, key));
+            node.children.insert(0, sibling.children.pop().expect("el hermano interno tiene hijos"));
+        }
+        self.nodes[left] = sibling;
+        self.nodes[child] = node;
+    }
+    fn borrow_from_right(&mut self , parent : usize , index : usize , child : usize , right : usize)
+    where K : Clone{
+        let mut sibling: BPlusNode<K, V> = mem::take(&mut self.nodes[right]);
+        let mut node: BPlusNode<K, V> = mem::take(&mut self.nodes[child]);
+        let separator: &mut K = &mut self.nodes[parent].keys[index];
+        let key: K = sibling.keys.remove(0);
+        if node.leaf{
+            node.keys.push(key);
+            node.values.push(sibling.values.remove(0));
+            //El hermano derecho cambió su menor clave
+            *separator = sibling.keys[0].clone();
+        }else {
+            node.keys.push(mem::replace(separator, key));
+            node.children.push(sibling.children.remove(0));
+        }
+        self.nodes[right] = sibling;
+        self.nodes[child] = node;
+    }
+    ///Une el hijo `separator + 1` dentro del hijo `separator`, el padre pierde el separador entre ambos.
+    fn merge(&mut self , parent : usize , separator : usize , left : usize , right : usize){
+        let node: BPlusNode<K, V> = mem::take(&mut self.nodes[right]);
+        let key: K = self.nodes[parent].keys.remove(separator);
+        self.nodes[parent].children.remove(separator + 1);
+        let merged: &mut BPlusNode<K, V> = &mut self.nodes[left];
+        if merged.leaf{
+            //En las hojas el separador era solo una copia, y la lista de hojas salta a la hoja eliminada
+            merged.keys.extend(node.keys);
+            merged.values.extend(node.values);
+            merged.next = node.next;
+        }else {
+            merged.keys.push(key);
+            merged.keys.extend(node.keys);
+            merged.children.extend(node.children);
+        }
+        self.release(right);
+    }
+    fn first_leaf(&self) -> usize{
+        let mut node: usize = self.root;
+        while !self.nodes[node].leaf{
+            node = self.nodes[node].children[0];
+        }
+        node
+    }
+    ///Pares en orden de clave siguiendo la lista de hojas.
+    pub fn iter(&self) -> Iter<'_, K, V>{
+        Iter{ nodes : &self.nodes, leaf : Some(self.first_leaf()), index : 0 }
+    }
+    ///### Range
+    /// Baja una vez hasta la hoja del inicio y luego recorre la lista de hojas hasta pasar el fin.
+    pub fn range<'a, Q, R>(&'a self , range : R) -> impl Iterator<Item = (&'a K, &'a V)> + 'a
+    where K : Borrow<Q>, Q : Ord + ?Sized + 'a, R : RangeBounds<Q> + 'a{
+        let (leaf, index): (usize, usize) = match range.start_bound(){
+            Bound::Included(start) => {
+                let leaf: usize = self.find_leaf(start);
+                (leaf, self.nodes[leaf].keys.partition_point(|key| key.borrow() < start))
+            },
+            Bound::Excluded(start) => {
+                let leaf: usize = self.find_leaf(start);
+                (leaf, self.nodes[leaf].keys.partition_point(|key| key.borrow() <= start))
+            },
+            Bound::Unbounded => (self.first_leaf(), 0)
+        };
+        Iter{ nodes : &self.nodes, leaf : Some(leaf), index }.take_while(move |(key, _)| match range.end_bound(){
+            Bound::Included(end) => (*key).borrow() <= end,
+            Bound::Excluded(end) => (*key).borrow() < end,
+            Bound::Unbounded => true
+        })
+    }
+    ///### Carga masiva
+    /// Construye el arbol por niveles desde pares con claves estrictamente crecientes en O(n): las hojas se llenan de
+    /// forma pareja y se enlazan, la menor clave de cada hoja (salvo la primera) es el separador del nivel de arriba.
+    pub fn bulk_load<I : IntoIterator<Item = (K, V)>>(pairs : I) -> Result<Self, String>
+    where K : Clone{
+        let (keys, values): (Vec<K>, Vec<V>) = pairs.into_iter().unzip();
+        if keys.windows(2).any(|pair| pair[0] >= pair[1]){
+            return Err(String::from("la carga masiva requiere claves estrictamente crecientes"));
+        }
+        let mut tree: BPlusTree<K, V, B> = BPlusTree::new();
+        if keys.is_empty(){
+            return Ok(tree);
+        }
+        tree.nodes.clear();
+        tree.len = keys.len();
+        let leaves: usize = keys.len().div_ceil(B - 1);
+        let mut level: Vec<usize> = Vec::with_capacity(leaves);
+        let mut separators: Vec<K> = Vec::with_capacity(leaves - 1);
+        let (mut keys, mut values) = (keys.into_iter(), values.into_iter());
+        for size in even_sizes(keys.len(), leaves){
+            let leaf_keys: Vec<K> = keys.by_ref().take(size).collect();
+            if !level.is_empty(){
+                separators.push(leaf_keys[0].clone());
+            }
+            let leaf: BPlusNode<K, V> = BPlusNode{ keys : leaf_keys, children : Vec::new(), values : values.by_ref().take(size).collect(), next : None, leaf : true };
+            let index: usize = tree.allocate(leaf);
+            if let Some(&previous) = level.last(){
+                tree.nodes[previous].next = Some(index);
+            }
+            level.push(index);
+        }
+        while level.len() > 1{
+            let parents: usize = level.len().div_ceil(B);
+            let mut next_level: Vec<usize> = Vec::with_capacity(parents);
+            let mut next_separators: Vec<K> = Vec::with_capacity(parents - 1);
+            let mut children = level.into_iter();
+            let mut separators_iter = separators.into_iter();
+            for (position, size) in even_sizes(children.len(), parents).enumerate(){
+                if position > 0{
+                    next_separators.push(separators_iter.next().expect("hay un separador entre cada par de nodos"));
+                }
+                let children: Vec<usize> = children.by_ref().take(size).collect();
+                let keys: Vec<K> = separators_iter.by_ref().take(size - 1).collect();
+                next_level.push(tree.allocate(BPlusNode::internal(keys, children)));
+            }
+            level = next_level;
+            separators = next_separators;
+        }
+        tree.root = level[0];
+        Ok(tree)
+    }
+    ///Verifica los invariantes: tamaños de los nodos, hojas a la misma profundidad, separadores que acotan a sus
+    ///subarboles y una lista de hojas que recorre todas las claves en orden.
+    #[cfg(test)]
+    pub(crate) fn is_valid(&self) -> bool{
+        fn check<K : Ord, V, const B : usize>(tree : &BPlusTree<K, V, B> , node : usize , bounds : (Option<&K>, Option<&K>) , depth : usize , leaf_depth : &mut Option<usize>) -> bool{
+            let current: &BPlusNode<K, V> = &tree.nodes[node];
+            let sizes_ok: bool = current.keys.len() < B && (node == tree.root || current.keys.len() >= BPlusTree::<K, V, B>::MIN_KEYS);
+            let sorted: bool = current.keys.windows(2).all(|pair| pair[0] < pair[1]);
+            let bounded: bool = current.keys.iter().all(|key| bounds.0.is_none_or(|low| key >= low) && bounds.1.is_none_or(|high| key < high));
+            if current.leaf{
+                return sizes_ok && sorted && bounded && current.values.len() == current.keys.len() && *leaf_depth.get_or_insert(depth) == depth;
+            }
+            sizes_ok && sorted && bounded && current.children.len() == current.keys.len() + 1 && current.children.iter().enumerate().all(|(index, &child)| {
+                let low: Option<&K> = if index == 0 { bounds.0 } else { Some(&current.keys[index - 1]) };
+                let high: Option<&K> = current.keys.get(index).or(bounds.1);
+                check(tree, child, (low, high), depth + 1, leaf_depth)
+            })
+        }
+        let chain: Vec<&K> = self.iter().map(|(key, _)| key).collect();
+        check(self, self.root, (None, None), 0, &mut None) && chain.len() == self.len && chain.windows(2).all(|pair| pair[0] < pair[1])
+    }
+}
+enum Insertion<K, V>{
+    Replaced(V),
+    Inserted,
+    Split(K, usize)
+}
+impl<K : Ord, V, const B : usize> Default for BPlusTree<K, V, B>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<K : Ord + Debug, V : Debug, const B : usize> Debug for BPlusTree<K, V, B>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+impl<K : Ord + Clone, V, const B : usize> FromIterator<(K, V)> for BPlusTree<K, V, B>{
+    fn from_iter<I : IntoIterator<Item = (K, V)>>(iter : I) -> Self{
+        let mut tree: BPlusTree<K, V, B> = BPlusTree::new();
+        for (key, value) in iter{
+            tree.insert(key, value);
+        }
+        tree
+    }
+}
+///Iterador sobre la lista de hojas.
+pub struct Iter<'a, K, V>{
+    nodes : &'a [BPlusNode<K, V>],
+    leaf : Option<usize>,
+    index : usize
+}
+impl<'a, K, V> Iterator for Iter<'a, K, V>{
+    type Item = (&'a K, &'a V);
+    fn next(&mut self) -> Option<Self::Item>{
+        loop{
+            let leaf: &'a BPlusNode<K, V> = &self.nodes[self.leaf?];
+            if self.index < leaf.keys.len(){
+                self.index += 1;
+                return Some((&leaf.keys[self.index - 1], &leaf.values[self.index - 1]));
+            }
+            self.leaf = leaf.next;
+            self.index = 0;
+        }
+    }
+}
+impl<'a, K : Ord, V, const B : usize> IntoIterator for &'a BPlusTree<K, V, B>{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+    fn into_iter(self) -> Iter<'a, K, V>{
+        self.iter()
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use std::collections::BTreeMap;
+    use crate::random::XorShift64;
+
+    fn random_operations<const B : usize>(){
+        let mut tree: BPlusTree<u32, u64, B> = BPlusTree::new();
+        let mut expected: BTreeMap<u32, u64> = BTreeMap::new();
+        let mut rng: XorShift64 = XorShift64::new(B as u64 + 100);
+        for step in 0..4_000u64{
+            let key: u32 = (rng.next_u64() % 600) as u32;
+            if step % 3 == 2{
+                assert_eq!(tree.remove(&key) , expected.remove(&key));
+            }else {
+                assert_eq!(tree.insert(key, step) , expected.insert(key, step));
+            }
+            if step % 250 == 0{
+                assert!(tree.is_valid(), "orden {B}, paso {step}");
+            }
+        }
+        assert!(tree.is_valid());
+        assert!(tree.iter().eq(expected.iter()));
+        for key in 0..600{
+            assert_eq!(tree.get(&key) , expected.get(&key));
+        }
+        for key in expected.keys().copied().collect::<Vec<u32>>(){
+            assert!(tree.remove(&key).is_some());
+        }
+        assert!(tree.empty() && tree.is_valid() && tree.height() == 1 && tree.node_count() == 1);
+    }
+    #[test]
+    fn matches_btree_map_for_several_orders(){
+        random_operations::<3>();
+        random_operations::<4>();
+        random_operations::<7>();
+        random_operations::<32>();
+    }
+    #[test]
+    fn range_scans_follow_the_leaf_chain(){
+        let tree: BPlusTree<i32, i32, 4> = (0..300).map(|key| (key * 3, key)).collect();
+        let expected: BTreeMap<i32, i32> = (0..300).map(|key| (key * 3, key)).collect();
+        for (start, end) in [(0, 30), (14, 200), (-9, 4), (880, 1_000), (50, 50)]{
+            assert!(tree.range(start..end).eq(expected.range(start..end)));
+            assert!(tree.range(start..=end).eq(expected.range(start..=end)));
+            assert!(tree.range((Bound::Excluded(start), Bound::Unbounded)).eq(expected.range((Bound::Excluded(start), Bound::Unbounded))));
+        }
+        assert_eq!(tree.range(..).count() , 300);
+    }
+    #[test]
+    fn string_keys_with_borrowed_lookups(){
+        let mut tree: BPlusTree<String, usize, 5> = BPlusTree::new();
+        for (index, word) in ["pera", "manzana", "uva", "kiwi", "mango", "higo", "lima"].into_iter().enumerate(){
+            tree.insert(word.to_string(), index);
+        }
+        assert_eq!(tree.get("uva") , Some(&2));
+        *tree.get_mut("kiwi").unwrap() += 10;
+        assert_eq!(tree.remove("kiwi") , Some(13));
+        let words: Vec<&str> = tree.range::<str, _>((Bound::Included("l"), Bound::Excluded("p"))).map(|(key, _)| key.as_str()).collect();
+        assert_eq!(words , vec!["lima", "mango", "manzana"]);
+    }
+    #[test]
+    fn bulk_loading_builds_a_valid_tree(){
+        for count in [0, 1, 2, 3, 4, 9, 100, 2_000]{
+            let tree: BPlusTree<u32, u32, 4> = BPlusTree::bulk_load((0..count).map(|key| (key, key * 2))).unwrap();
+            assert!(tree.is_valid(), "{count} pares");
+            assert!(tree.iter().map(|(key, value)| (*key, *value)).eq((0..count).map(|key| (key, key * 2))));
+        }
+        let mut tree: BPlusTree<u32, u32, 3> = BPlusTree::bulk_load((0..1_000).map(|key| (key, key))).unwrap();
+        for key in (0..1_000).step_by(2){
+            assert_eq!(tree.remove(&key) , Some(key));
+        }
+        tree.insert(10, 10);
+        assert!(tree.is_valid());
+        assert!(BPlusTree::<u32, u32, 4>::bulk_load([(2, 0), (1, 0)]).is_err());
+    }
+}
@@ -0,0 +1,435 @@
+//! ### Sobre los arboles B
+//! Un arbol B generaliza al BST: cada nodo guarda varios valores ordenados y tiene un hijo más que valores, así el arbol
+//! es ancho y bajo. El orden `B` es la cantidad maxima de hijos de un nodo.
+//! ```text
+//!                           [ 20 | 40 ]
+//!                         /      |      \
+//!              [ 5 | 10 ]   [ 25 | 30 ]   [ 45 | 50 | 60 ]
+//! ```
+//! - Todo nodo tiene a lo más `B - 1` valores, y todo nodo que no es la raíz al menos `(B - 1) / 2`.
+//! - Todas las hojas están a la misma profundidad, la altura es O(log_B(n)).
+//!
+//! ###### Inserción
+//! Se inserta siempre en una hoja, si queda con `B` valores se parte en dos y el valor del medio sube al padre, lo que
+//! puede volver a partir al padre hasta crear una nueva raíz.
+//!
+//! ###### Eliminación
+//! Si el valor está en un nodo interno se reemplaza por su predecesor (el maximo del hijo izquierdo). Al volver de la
+//! recursión, si un hijo quedó bajo el minimo:
+//! - `borrow`: si un hermano vecino tiene valores de sobra, el separador del padre baja al hijo y el valor extremo del
+//!   hermano sube a ocupar su lugar.
+//! - `merge`: si no, el hijo, el separador y el hermano se unen en un solo nodo, el padre pierde un valor.
+//!
+//! Con `B` grande cada nodo ocupa memoria contigua y la busqueda dentro de él es binaria, la comparación contra
+//! `AVLTree` (un valor por nodo) está en los benches.
+use std::fmt::{self, Debug};
+use std::mem;
+use std::ops::{Bound, RangeBounds};
+
+#[derive(Debug)]
+pub struct BTreeNode<T>{
+    keys : Vec<T>,
+    ///Vacio en las hojas, en los nodos internos tiene `keys.len() + 1` hijos.
+    children : Vec<BTreeNode<T>>
+}
+impl<T> BTreeNode<T>{
+    fn leaf() -> Self{
+        Self{ keys : Vec::new(), children : Vec::new() }
+    }
+    fn is_leaf(&self) -> bool{
+        self.children.is_empty()
+    }
+}
+///### BTree
+/// Conjunto ordenado sobre un arbol B de orden `B` (maximo de hijos por nodo), `B` debe ser al menos 3.
+pub struct BTree<T, const B : usize>{
+    root : BTreeNode<T>,
+    size : usize
+}
+impl<T : Ord, const B : usize> BTree<T, B>{
+    const VALID_ORDER : () = assert!(B >= 3, "el orden de un arbol B debe ser al menos 3");
+    ///Minimo de valores en un nodo que no es la raíz.
+    const MIN_KEYS : usize = (B - 1) / 2;
+
+    pub fn new() -> Self{
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_ORDER;
+        Self{ root : BTreeNode::leaf(), size : 0 }
+    }
+    pub fn len(&self) -> usize{
+        self.size
+    }
+    pub fn empty(&self) -> bool{
+        self.size == 0
+    }
+    ///Cantidad de niveles, un arbol de una sola hoja tiene altura 1.
+    pub fn height(&self) -> usize{
+        let mut height: usize = 1;
+        let mut node: &BTreeNode<T> = &self.root;
+        while let Some(child) = node.children.first(){
+            height += 1;
+            node = child;
+        }
+        height
+    }
+    ///Cantidad de nodos, junto a `height` permite estudiar el efecto del orden.
+    pub fn node_count(&self) -> usize{
+        let mut count: usize = 0;
+        let mut stack: Vec<&BTreeNode<T>> = vec![&self.root];
+        while let Some(node) = stack.pop(){
+            count += 1;
+            stack.extend(node.children.iter());
+        }
+        count
+    }
+    pub fn search(&self , value : &T) -> Option<&T>{
+        let mut node: &BTreeNode<T> = &self.root;
+        loop{
+            match node.keys.binary_search(value){
+                Ok(index) => return Some(&node.keys[index]),
+                Err(index) => node = node.children.get(index)?
+            }
+        }
+    }
+    pub fn contains(&self , value : &T) -> bool{
+        self.search(value).is_some()
+    }
+    pub fn min(&self) -> Option<&T>{
+        let mut node: &BTreeNode<T> = &self.root;
+        while let Some(child) = node.children.first(){
+            node = child;
+        }
+        node.keys.first()
+    }
+    pub fn max(&self) -> Option<&T>{
+        let mut node: &BTreeNode<T> = &self.root;
+        while let Some(child) = node.children.last(){
+            node = child;
+        }
+        node.keys.last()
+    }
+    ///Inserta el valor, retorna `false` si ya existía.
+    pub fn insert(&mut self , value : T) -> bool{
+        if self.contains(&value){
+            return false;
+        }
+        if let Some((median, right)) = Self::insert_recursive(&mut self.root, value){
+            //La raíz se partió, el arbol crece un nivel hacia arriba
+            let left: BTreeNode<T> = mem::replace(&mut self.root, BTreeNode::leaf());
+            self.root.keys.push(median);
+            self.root.children = vec![left, right];
+        }
+        self.size += 1;
+        true
+    }
+    ///Retorna el valor del medio y el nodo derecho si `node` se partió.
+    fn insert_recursive(node : &mut BTreeNode<T> , value : T) -> Option<(T, BTreeNode<T>)>{
+        let index: usize = node.keys.partition_point(|key| *key < value);
+        if node.is_leaf(){
+            node.keys.insert(index, value);
+        }else if let Some((median, right)) = Self::insert_recursive(&mut node.children[index], value){
+            node.keys.insert(index, median);
+            node.children.insert(index + 1, right);
+        }
+        if node.keys.len() < B{
+            return None;
+        }
+        // [ k0 .. k(B/2 - 1) ] k(B/2) [ k(B/2 + 1) .. ]
+        let right_keys: Vec<T> = node.keys.split_off(B / 2 + 1);
+        let median: T = node.keys.pop().expect("el nodo lleno tiene valor del medio");
+        let right_children: Vec<BTreeNode<T>> = if node.is_leaf() { Vec::new() } else { node.children.split_off(B / 2 + 1) };
+        Some((median, BTreeNode{ keys : right_keys, children : right_children }))
+    }
+    ///Elimina el valor y lo retorna.
+    pub fn remove(&mut self , value : &T) -> Option<T>{
+        let removed: T = Self::remove_recursive(&mut self.root, value)?;
+        if self.root.keys.is_empty() && !self.root.is_leaf(){
+            //La raíz quedó sin valores tras un merge, el arbol baja un nivel
+            self.root = self.root.children.pop().expect("la raíz interna tiene un hijo");
+        }
+        self.size -= 1;
+        Some(removed)
+    }
+    fn remove_recursive(node : &mut BTreeNode<T> , value : &T) -> Option<T>{
+        match node.keys.binary_search(value){
+            Ok(index) if node.is_leaf() => Some(node.keys.remove(index)),
+            Ok(index) => {
+                //Se reemplaza por el predecesor, que siempre está en una hoja
+                let predecessor: T = Self::remove_max(&mut node.children[index]);
+                let removed: T = mem::replace(&mut node.keys[index], predecessor);
+                Self::fix_child(node, index);
+                Some(removed)
+            },
+            Err(_) if node.is_leaf() => None,
+            Err(index) => {
+                let removed: Option<T> = Self::remove_recursive(&mut node.children[index], value);
+                if removed.is_some(){
+                    Self::fix_child(node, index);
+                }
+                removed
+            }
+        }
+    }
+    fn remove_max(node : &mut BTreeNode<T>) -> T{
+        if node.is_leaf(){
+            return node.keys.pop().expect("un nodo que no es la raíz nunca está vacio");
+        }
+        let last: usize = node.children.len() - 1;
+        let max: T = Self::remove_max(&mut node.children[last]);
+        Self::fix_child(node, last);
+        max
+    }
+    ///Devuelve al hijo `index` al minimo de valores con un `borrow` de un hermano o un `merge`.
+    fn fix_child(node : &mut BTreeNode<T> , index : usize){
+        if node.children[index].keys.len() >= Self::MIN_KEYS{
+            return;
+        }
+        if index > 0 && node.children[index - 1].keys.len() > Self::MIN_KEYS{
+            //borrow del hermano izquierdo
+            let (left, right) = node.children.split_at_mut(index);
+            let (sibling, child) = (&mut left[index - 1], &mut right[0]);
+            let separator: T = mem::replace(&mut node.keys[index - 1], sibling.keys.pop().expect("el hermano tiene de sobra"));
+            child.keys.insert(0, separator);
+            if let Some(grandchild) = sibling.children.pop(){
+                child.children.insert(0, grandchild);
+            }
+        }else if index + 1 < node.children.len() && node.children[index + 1].keys.len() > Self::MIN_KEYS{
+            //borrow del hermano derecho
+            let (left, right) = node.children.split_at_mut(index + 1);
+            let (child, sibling) = (&mut left[index], &mut right[0]);
+            let separator: T = mem::replace(&mut node.keys[index], sibling.keys.remove(0));
+            child.keys.push(separator);
+            if !sibling.is_leaf(){
+                child.children.push(sibling.children.remove(0));
+            }
+        }else {
+            //merge del hijo con un hermano, siempre el derecho dentro del izquierdo
+            let left: usize = if index > 0 { index - 1 } else { index };
+            let right: BTreeNode<T> = node.children.remove(left + 1);
+            let separator: T = node.keys.remove(left);
+            let merged: &mut BTreeNode<T> = &mut node.children[left];
+            merged.keys.push(separator);
+            merged.keys.extend(right.keys);
+            merged.children.extend(right.children);
+        }
+    }
+    ///Valores en orden ascendente.
+    pub fn iter(&self) -> Iter<'_, T>{
+        let mut iter: Iter<'_, T> = Iter{ stack : Vec::new() };
+        iter.descend(&self.root, |_| 0);
+        iter
+    }
+    ///Valores dentro del rango en orden ascendente, el inicio se ubica bajando una vez por el arbol.
+    pub fn range<'a, R>(&'a self , range : R) -> impl Iterator<Item = &'a T> + 'a
+    where R : RangeBounds<T> + 'a{
+        let mut iter: Iter<'a, T> = Iter{ stack : Vec::new() };
+        match range.start_bound(){
+            Bound::Included(start) => iter.descend(&self.root, |node| node.keys.partition_point(|key| key < start)),
+            Bound::Excluded(start) => iter.descend(&self.root, |node| node.keys.partition_point(|key| key <= start)),
+            Bound::Unbounded => iter.descend(&self.root, |_| 0)
+        }
+        iter.take_while(move |value| match range.end_bound(){
+            Bound::Included(end) => *value <= end,
+            Bound::Excluded(end) => *value < end,
+            Bound::Unbounded => true
+        })
+    }
+    ///### Carga masiva
+    /// Construye el arbol por niveles desde valores estrictamente crecientes en O(n): las hojas se llenan de forma pareja
+    /// dejando un valor separador entre cada una, y los separadores forman el nivel de arriba. Retorna error si la
+    /// entrada no está ordenada o tiene repetidos.
+    pub fn from_sorted<I : IntoIterator<Item = T>>(values : I) -> Result<Self, String>{
+        let values: Vec<T> = values.into_iter().collect();
+        if values.windows(2).any(|pair| pair[0] >= pair[1]){
+            return Err(String::from("la carga masiva requiere valores estrictamente crecientes"));
+        }
+        let mut tree: BTree<T, B> = BTree::new();
+        tree.size = values.len();
+        if values.is_empty(){
+            return Ok(tree);
+        }
+        //Con `m` hojas se usan `m - 1` valores como separadores y cada hoja guarda a lo más `B - 1`
+        let leaves: usize = (values.len() + 1).div_ceil(B);
+        let mut level: Vec<BTreeNode<T>> = Vec::with_capacity(leaves);
+        let mut separators: Vec<T> = Vec::with_capacity(leaves - 1);
+        let mut values = values.into_iter();
+        for (position, size) in even_sizes(values.len() - (leaves - 1), leaves).enumerate(){
+            if position > 0{
+                separators.push(values.next().expect("se reservó un separador por hoja"));
+            }
+            level.push(BTreeNode{ keys : values.by_ref().take(size).collect(), children : Vec::new() });
+        }
+        while level.len() > 1{
+            let parents: usize = level.len().div_ceil(B);
+            let mut next_level: Vec<BTreeNode<T>> = Vec::with_capacity(parents);
+            let mut next_separators: Vec<T> = Vec::with_capacity(parents - 1);
+            let mut children = level.into_iter();
+            let mut separators_iter = separators.into_iter();
+            for (position, size) in even_sizes(children.len(), parents).enumerate(){
+                if position > 0{
+                    next_separators.push(separators_iter.next().expect("hay un separador entre cada par de nodos"));
+                }
+                let children: Vec<BTreeNode<T>> = children.by_ref().take(size).collect();
+                let keys: Vec<T> = separators_iter.by_ref().take(size - 1).collect();
+                next_level.push(BTreeNode{ keys, children });
+            }
+            level = next_level;
+            separators = next_separators;
+        }
+        tree.root = level.pop().expect("queda exactamente una raíz");
+        Ok(tree)
+    }
+    ///Verifica los invariantes: valores ordenados, cantidad de valores por nodo y hojas a la misma profundidad.
+    #[cfg(test)]
+    pub(crate) fn is_valid(&self) -> bool{
+        fn check<T : Ord>(node : &BTreeNode<T> , is_root : bool , min : usize , max : usize , depth : usize , leaf_depth : &mut Option<usize>) -> bool{
+            let sizes_ok: bool = node.keys.len() <= max && (is_root || node.keys.len() >= min);
+            let sorted: bool = node.keys.windows(2).all(|pair| pair[0] < pair[1]);
+            if node.is_leaf(){
+                return sizes_ok && sorted && *leaf_depth.get_or_insert(depth) == depth;
+            }
+            let bounded: bool = node.children.len() == node.keys.len() + 1 && node.keys.iter().enumerate().all(|(index, key)| {
+                node.children[index].keys.last().is_some_and(|last| last < key) && node.children[index + 1].keys.first().is_some_and(|first| first > key)
+            });
+            sizes_ok && sorted && bounded && node.children.iter().all(|child| check(child, false, min, max, depth + 1, leaf_depth))
+        }
+        check(&self.root, true, Self::MIN_KEYS, B - 1, 0, &mut None) && self.iter().count() == self.size
+    }
+}
+///Reparte `total` elementos en `parts` grupos cuyos tamaños difieren a lo más en uno.
+pub(crate) fn even_sizes(total : usize , parts : usize) -> impl Iterator<Item = usize>{
+    let (base, extra): (usize, usize) = (total / parts, total % parts);
+    (0..parts).map(move |part| base + usize::from(part < extra))
+}
+impl<T : Ord, const B : usize> Default for BTree<T, B>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T : Ord + Debug, const B : usize> Debug for BTree<T, B>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+impl<T : Ord, const B : usize> FromIterator<T> for BTree<T, B>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        let mut tree: BTree<T, B> = BTree::new();
+        for value in iter{
+            tree.insert(value);
+        }
+        tree
+    }
+}
+///Iterador inorder con una pila de `(nodo, siguiente valor)`, el tope es el nodo que se está recorriendo.
+pub struct Iter<'a, T>{
+    stack : Vec<(&'a BTreeNode<T>, usize)>
+}
+impl<'a, T> Iter<'a, T>{
+    ///Baja desde `node` eligiendo en cada nivel la posición `position(node)`.
+    fn descend(&mut self , mut node : &'a BTreeNode<T> , position : impl Fn(&BTreeNode<T>) -> usize){
+        loop{
+            let index: usize = position(node);
+            self.stack.push((node, index));
+            match node.children.get(index){
+                Some(child) => node = child,
+                None => break
+            }
+        }
+    }
+}
+impl<'a, T> Iterator for Iter<'a, T>{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T>{
+        loop{
+            let (node, index): (&'a BTreeNode<T>, usize) = *self.stack.last()?;
+            if index < node.keys.len(){
+                self.stack.last_mut().expect("se leyó el tope").1 += 1;
+                if let Some(child) = node.children.get(index + 1){
+                    self.descend(child, |_| 0);
+                }
+                return Some(&node.keys[index]);
+            }
+            self.stack.pop();
+        }
+    }
+}
+impl<'a, T : Ord, const B : usize> IntoIterator for &'a BTree<T, B>{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Iter<'a, T>{
+        self.iter()
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use std::collections::BTreeSet;
+    use crate::random::XorShift64;
+
+    fn random_operations<const B : usize>(){
+        let mut tree: BTree<i32, B> = BTree::new();
+        let mut expected: BTreeSet<i32> = BTreeSet::new();
+        let mut rng: XorShift64 = XorShift64::new(B as u64);
+        for step in 0..4_000{
+            let value: i32 = (rng.next_u64() % 600) as i32;
+            if step % 3 == 2{
+                assert_eq!(tree.remove(&value) , expected.take(&value));
+            }else {
+                assert_eq!(tree.insert(value) , expected.insert(value));
+            }
+            if step % 250 == 0{
+                assert!(tree.is_valid(), "orden {B}, paso {step}");
+            }
+        }
+        assert!(tree.is_valid());
+        assert!(tree.iter().eq(expected.iter()));
+        assert_eq!((tree.min(), tree.max()) , (expected.first(), expected.last()));
+        for value in expected.clone(){
+            assert_eq!(tree.remove(&value) , Some(value));
+        }
+        assert!(tree.empty() && tree.is_valid() && tree.height() == 1);
+    }
+    #[test]
+    fn matches_btree_set_for_several_orders(){
+        random_operations::<3>();
+        random_operations::<4>();
+        random_operations::<5>();
+        random_operations::<16>();
+    }
+    #[test]
+    fn range_matches_btree_set(){
+        let tree: BTree<i32, 4> = (0..200).map(|v| v * 2).collect();
+        let expected: BTreeSet<i32> = (0..200).map(|v| v * 2).collect();
+        for (start, end) in [(0, 10), (7, 91), (-4, 3), (390, 500), (13, 13)]{
+            assert!(tree.range(start..end).eq(expected.range(start..end)));
+            assert!(tree.range(start..=end).eq(expected.range(start..=end)));
+            assert!(tree.range((Bound::Excluded(start), Bound::Included(end))).eq(expected.range((Bound::Excluded(start), Bound::Included(end)))));
+        }
+        assert_eq!(tree.range(..).count() , 200);
+    }
+    #[test]
+    fn bulk_loading_builds_a_valid_tree(){
+        for count in [0, 1, 2, 3, 7, 8, 50, 1_000]{
+            let tree: BTree<u32, 5> = BTree::from_sorted(0..count).unwrap();
+            assert!(tree.is_valid(), "{count} valores");
+            assert!(tree.iter().copied().eq(0..count));
+        }
+        let mut tree: BTree<u32, 3> = BTree::from_sorted(0..1_000).unwrap();
+        assert!(tree.is_valid());
+        for value in (0..1_000).step_by(3){
+            assert_eq!(tree.remove(&value) , Some(value));
+        }
+        tree.insert(3);
+        assert!(tree.is_valid());
+        assert!(BTree::<u32, 4>::from_sorted([1, 3, 2]).is_err());
+        assert!(BTree::<u32, 4>::from_sorted([1, 1]).is_err());
+    }
+    #[test]
+    fn larger_order_gives_a_shallower_tree(){
+        let narrow: BTree<u32, 3> = (0..10_000).collect();
+        let wide: BTree<u32, 64> = (0..10_000).collect();
+        assert!(wide.height() < narrow.height());
+        assert!(wide.node_count() < narrow.node_count());
+        assert!(narrow.height() <= 14 && wide.height() <= 4);
+    }
+}
@@ -0,0 +1,457 @@
+//! ### Sobre los montículos binarios (Binary Heap)
+//! Un montículo binario es un árbol binario completo guardado por niveles dentro de un arreglo, el nodo en la posición `i`
+//! tiene a sus hijos en `2i + 1` y `2i + 2` y a su padre en `(i - 1) / 2`. Cada nodo tiene mayor prioridad que sus hijos,
+//! en un montículo de maximos la raíz es el mayor elemento y en uno de minimos la raíz es el menor.
+//! ```text
+//!                 50                         +----+----+----+----+----+----+
+//!              /      \                      | 50 | 30 | 40 | 10 | 20 | 35 |
+//!            30        40          ====>     +----+----+----+----+----+----+
+//!           /  \      /                        0    1    2    3    4    5
+//!         10    20  35
+//! ```
+//! - `push` inserta al final del arreglo y sube el elemento (sift up), O(log n).
+//! - `pop` intercambia la raíz con el ultimo elemento y baja la nueva raíz (sift down), O(log n).
+//! - `heapify` construye el montículo desde un vector en O(n) bajando los nodos internos desde el ultimo hacia la raíz.
+//!
+//! `IndexedHeap` agrega manejadores (`Handle`) a cada elemento insertado, gracias a ellos se puede modificar la prioridad
+//! o eliminar un elemento cualquiera en O(log n), operaciones necesarias en algoritmos como Dijkstra o Prim. Los slots
+//! de los manejadores retirados se reutilizan con una `generation` nueva, así la memoria depende de los elementos vivos
+//! y no de todos los `push` realizados, y un manejador viejo nunca confunde al elemento que ocupa su slot.
+
+///Define qué elemento queda en la raíz del montículo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeapKind{
+    ///La raíz es el menor elemento.
+    Min,
+    ///La raíz es el mayor elemento.
+    Max
+}
+impl HeapKind{
+    ///Indica si `a` debe quedar sobre `b` en el montículo.
+    pub(crate) fn precedes<T : Ord>(&self , a : &T , b : &T) -> bool{
+        match self {
+            HeapKind::Min => a < b,
+            HeapKind::Max => a > b
+        }
+    }
+}
+fn parent(index : usize) -> usize{
+    (index - 1) / 2
+}
+fn left_child(index : usize) -> usize{
+    2 * index + 1
+}
+///### BinaryHeap
+/// Montículo binario de minimos o de maximos según su `HeapKind`.
+#[derive(Debug, Clone)]
+pub struct BinaryHeap<T>{
+    data : Vec<T>,
+    kind : HeapKind
+}
+impl<T : Ord> Default for BinaryHeap<T>{
+    fn default() -> Self{
+        Self::new_max()
+    }
+}
+impl<T : Ord> BinaryHeap<T>{
+    pub fn new(kind : HeapKind) -> Self{
+        Self{ data : Vec::new(), kind }
+    }
+    pub fn new_min() -> Self{
+        Self::new(HeapKind::Min)
+    }
+    pub fn new_max() -> Self{
+        Self::new(HeapKind::Max)
+    }
+    ///### Heapify
+    /// Construye el montículo reutilizando el vector en O(n), solo los nodos internos `0..len/2` necesitan bajar.
+    pub fn heapify(data : Vec<T> , kind : HeapKind) -> Self{
+        let mut heap: BinaryHeap<T> = Self{ data, kind };
+        for index in (0..heap.data.len() / 2).rev(){
+            heap.sift_down(index, heap.data.len());
+        }
+        heap
+    }
+    pub fn len(&self) -> usize{
+        self.data.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.data.is_empty()
+    }
+    pub fn kind(&self) -> HeapKind{
+        self.kind
+    }
+    pub fn push(&mut self , value : T){
+        self.data.push(value);
+        self.sift_up(self.data.len() - 1);
+    }
+    ///Elimina y retorna el elemento de la raíz.
+    pub fn pop(&mut self) -> Option<T>{
+        if self.data.is_empty(){
+            return None;
+        }
+        let last: usize = self.data.len() - 1;
+        self.data.swap(0, last);
+        let value: Option<T> = self.data.pop();
+        self.sift_down(0, self.data.len());
+        value
+    }
+    ///El elemento de la raíz sin retirarlo.
+    pub fn peek(&self) -> Option<&T>{
+        self.data.first()
+    }
+    ///### Into sorted vec
+    /// Ordena de menor a mayor sin importar el `HeapKind` mediante heap sort, O(n log n) sin memoria adicional.
+    pub fn into_sorted_vec(mut self) -> Vec<T>{
+        //Cada iteración lleva la raíz al final de la zona que todavia es montículo
+        for end in (1..self.data.len()).rev(){
+            self.data.swap(0, end);
+            self.sift_down(0, end);
+        }
+        //Un montículo de minimos deja los elementos de mayor a menor
+        if self.kind == HeapKind::Min{
+            self.data.reverse();
+        }
+        self.data
+    }
+    ///Los elementos en el orden interno del arreglo.
+    pub fn as_slice(&self) -> &[T]{
+        &self.data
+    }
+    fn sift_up(&mut self , mut index : usize){
+        while index > 0 && self.kind.precedes(&self.data[index], &self.data[parent(index)]){
+            self.data.swap(index, parent(index));
+            index = parent(index);
+        }
+    }
+    ///Baja el elemento en `index` considerando solo las posiciones `0..end`.
+    fn sift_down(&mut self , mut index : usize , end : usize){
+        loop {
+            let left: usize = left_child(index);
+            if left >= end{
+                return;
+            }
+            let mut child: usize = left;
+            if left + 1 < end && self.kind.precedes(&self.data[left + 1], &self.data[left]){
+                child = left + 1;
+            }
+            if !self.kind.precedes(&self.data[child], &self.data[index]){
+                return;
+            }
+            self.data.swap(index, child);
+            index = child;
+        }
+    }
+}
+impl<T : Ord> FromIterator<T> for BinaryHeap<T>{
+    ///Construye un montículo de maximos usando `heapify`.
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        Self::heapify(iter.into_iter().collect(), HeapKind::Max)
+    }
+}
+///Manejador de un elemento de `IndexedHeap`, sigue siendo valido mientras el elemento no sea retirado.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Handle{
+    slot : usize,
+    generation : usize
+}
+///Posición en el arreglo del elemento dueño del slot, `generation` aumenta cada vez que el slot se libera.
+#[derive(Debug, Clone)]
+struct Slot{
+    position : Option<usize>,
+    generation : usize
+}
+///### IndexedHeap
+/// Montículo binario que recuerda la posición de cada elemento dentro del arreglo.
+/// ```text
+///   heap  : [(5 , h2) , (8 , h0) , (9 , h1)]
+///   slots : [Some(1) , Some(2) , Some(0)]      slots[h].position = posición del elemento con manejador h
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndexedHeap<T>{
+    heap : Vec<(T, Handle)>,
+    slots : Vec<Slot>,
+    ///Slots sin elemento listos para el siguiente `push`.
+    free : Vec<usize>,
+    kind : HeapKind
+}
+impl<T : Ord> Default for IndexedHeap<T>{
+    fn default() -> Self{
+        Self::new_min()
+    }
+}
+impl<T : Ord> IndexedHeap<T>{
+    pub fn new(kind : HeapKind) -> Self{
+        Self{ heap : Vec::new(), slots : Vec::new(), free : Vec::new(), kind }
+    }
+    pub fn new_min() -> Self{
+        Self::new(HeapKind::Min)
+    }
+    pub fn new_max() -> Self{
+        Self::new(HeapKind::Max)
+    }
+    pub fn len(&self) -> usize{
+        self.heap.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.heap.is_empty()
+    }
+    pub fn kind(&self) -> HeapKind{
+        self.kind
+    }
+    ///Inserta un elemento y retorna su manejador.
+    pub fn push(&mut self , value : T) -> Handle{
+        let slot: usize = self.free.pop().unwrap_or_else(|| {
+            self.slots.push(Slot{ position : None, generation : 0 });
+            self.slots.len() - 1
+        });
+        self.slots[slot].position = Some(self.heap.len());
+        let handle: Handle = Handle{ slot, generation : self.slots[slot].generation };
+        self.heap.push((value, handle));
+        self.sift_up(self.heap.len() - 1);
+        handle
+    }
+    ///Elimina y retorna la raíz junto con su manejador, el cual deja de ser valido.
+    pub fn pop(&mut self) -> Option<(Handle, T)>{
+        if self.heap.is_empty(){
+            return None;
+        }
+        self.remove_at(0)
+    }
+    pub fn peek(&self) -> Option<(Handle, &T)>{
+        self.heap.first().map(|(value, handle)| (*handle, value))
+    }
+    pub fn contains(&self , handle : Handle) -> bool{
+        self.position(handle).is_some()
+    }
+    pub fn get(&self , handle : Handle) -> Option<&T>{
+        self.position(handle).map(|position| &self.heap[position].0)
+    }
+    ///### Decrease key
+    /// Reemplaza el valor de `handle` por uno menor o igual.
+    /// # Errores
+    /// Si el manejador no pertenece al montículo o el nuevo valor es mayor al actual.
+    pub fn decrease_key(&mut self , handle : Handle , value : T) -> Result<(), String>{
+        let position: usize = self.position(handle).ok_or("El manejador no pertenece al montículo")?;
+        if value > self.heap[position].0{
+            return Err(String::from("El nuevo valor es mayor al actual"));
+        }
+        self.replace_at(position, value);
+        Ok(())
+    }
+    ///### Increase key
+    /// Reemplaza el valor de `handle` por uno mayor o igual.
+    /// # Errores
+    /// Si el manejador no pertenece al montículo o el nuevo valor es menor al actual.
+    pub fn increase_key(&mut self , handle : Handle , value : T) -> Result<(), String>{
+        let position: usize = self.position(handle).ok_or("El manejador no pertenece al montículo")?;
+        if value < self.heap[position].0{
+            return Err(String::from("El nuevo valor es menor al actual"));
+        }
+        self.replace_at(position, value);
+        Ok(())
+    }
+    ///Elimina el elemento de `handle` sin importar su posición en el montículo.
+    pub fn remove(&mut self , handle : Handle) -> Option<T>{
+        let position: usize = self.position(handle)?;
+        self.remove_at(position).map(|(_, value)| value)
+    }
+    fn position(&self , handle : Handle) -> Option<usize>{
+        self.slots.get(handle.slot).filter(|slot| slot.generation == handle.generation).and_then(|slot| slot.position)
+    }
+    fn replace_at(&mut self , position : usize , value : T){
+        self.heap[position].0 = value;
+        //Solo una de las dos llamadas mueve el elemento
+        let position: usize = self.sift_up(position);
+        self.sift_down(position);
+    }
+    fn remove_at(&mut self , position : usize) -> Option<(Handle, T)>{
+        let last: usize = self.heap.len() - 1;
+        self.swap(position, last);
+        let (value, handle): (T, Handle) = self.heap.pop()?;
+        let slot: &mut Slot = &mut self.slots[handle.slot];
+        slot.position = None;
+        slot.generation += 1;
+        self.free.push(handle.slot);
+        if position < self.heap.len(){
+            let position: usize = self.sift_up(position);
+            self.sift_down(position);
+        }
+        Some((handle, value))
+    }
+    fn swap(&mut self , a : usize , b : usize){
+        self.heap.swap(a, b);
+        self.slots[self.heap[a].1.slot].position = Some(a);
+        self.slots[self.heap[b].1.slot].position = Some(b);
+    }
+    ///Sube el elemento y retorna su posición final.
+    fn sift_up(&mut self , mut index : usize) -> usize{
+        while index > 0 && self.kind.precedes(&self.heap[index].0, &self.heap[parent(index)].0){
+            self.swap(index, parent(index));
+            index = parent(index);
+        }
+        index
+    }
+    fn sift_down(&mut self , mut index : usize){
+        let end: usize = self.heap.len();
+        loop {
+            let left: usize = left_child(index);
+            if left >= end{
+                return;
+            }
+            let mut child: usize = left;
+            if left + 1 < end && self.kind.precedes(&self.heap[left + 1].0, &self.heap[left].0){
+                child = left + 1;
+            }
+            if !self.kind.precedes(&self.heap[child].0, &self.heap[index].0){
+                return;
+            }
+            self.swap(index, child);
+            index = child;
+        }
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    fn pseudo_random(count : usize , seed : u64) -> Vec<i64>{
+        let mut state: u64 = seed;
+        (0..count).map(|_| {
+            state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            ((state >> 33) % 1_000) as i64
+        }).collect()
+    }
+    fn is_heap<T : Ord>(data : &[T] , kind : HeapKind) -> bool{
+        (1..data.len()).all(|i| !kind.precedes(&data[i], &data[parent(i)]))
+    }
+    #[test]
+    fn max_and_min_pop_in_priority_order(){
+        let values: Vec<i64> = pseudo_random(500, 7);
+        let mut max_heap: BinaryHeap<i64> = BinaryHeap::new_max();
+        let mut min_heap: BinaryHeap<i64> = BinaryHeap::new_min();
+        for &value in values.iter(){
+            max_heap.push(value);
+            min_heap.push(value);
+        }
+        let mut sorted: Vec<i64> = values.clone();
+        sorted.sort();
+        assert_eq!(min_heap.peek() , sorted.first());
+        assert_eq!(max_heap.peek() , sorted.last());
+        let ascending: Vec<i64> = std::iter::from_fn(|| min_heap.pop()).collect();
+        let descending: Vec<i64> = std::iter::from_fn(|| max_heap.pop()).collect();
+        assert_eq!(ascending , sorted);
+        sorted.reverse();
+        assert_eq!(descending , sorted);
+        assert!(max_heap.empty());
+        assert_eq!(max_heap.pop() , None);
+    }
+    #[test]
+    fn heapify_builds_a_valid_heap(){
+        for kind in [HeapKind::Min , HeapKind::Max]{
+            for count in [0 , 1 , 2 , 7 , 100]{
+                let heap: BinaryHeap<i64> = BinaryHeap::heapify(pseudo_random(count, count as u64), kind);
+                assert_eq!(heap.len() , count);
+                assert!(is_heap(heap.as_slice(), kind));
+            }
+        }
+    }
+    #[test]
+    fn into_sorted_vec_is_ascending_for_both_kinds(){
+        let values: Vec<i64> = pseudo_random(300, 11);
+        let mut sorted: Vec<i64> = values.clone();
+        sorted.sort();
+        assert_eq!(BinaryHeap::heapify(values.clone(), HeapKind::Max).into_sorted_vec() , sorted);
+        assert_eq!(BinaryHeap::heapify(values.clone(), HeapKind::Min).into_sorted_vec() , sorted);
+        assert_eq!(values.into_iter().collect::<BinaryHeap<i64>>().into_sorted_vec() , sorted);
+    }
+    #[test]
+    fn indexed_decrease_and_increase_key(){
+        let mut heap: IndexedHeap<i32> = IndexedHeap::new_min();
+        let a: Handle = heap.push(50);
+        let b: Handle = heap.push(30);
+        let c: Handle = heap.push(40);
+        assert_eq!(heap.peek() , Some((b , &30)));
+        heap.decrease_key(a, 10).unwrap();
+        assert_eq!(heap.peek() , Some((a , &10)));
+        heap.increase_key(a, 45).unwrap();
+        assert_eq!(heap.peek() , Some((b , &30)));
+        assert!(heap.decrease_key(c, 41).is_err());
+        assert!(heap.increase_key(c, 39).is_err());
+        assert_eq!(heap.pop() , Some((b , 30)));
+        assert_eq!(heap.pop() , Some((c , 40)));
+        assert_eq!(heap.pop() , Some((a , 45)));
+        assert!(heap.decrease_key(a, 0).is_err());
+        assert_eq!(heap.pop() , None);
+    }
+    #[test]
+    fn indexed_remove_by_handle(){
+        let mut heap: IndexedHeap<i64> = IndexedHeap::new_max();
+        let values: Vec<i64> = pseudo_random(200, 3);
+        let handles: Vec<Handle> = values.iter().map(|&value| heap.push(value)).collect();
+        let mut remaining: Vec<i64> = Vec::new();
+        for (i, (&handle, &value)) in handles.iter().zip(values.iter()).enumerate(){
+            if i % 3 == 0{
+                assert_eq!(heap.remove(handle) , Some(value));
+                assert!(!heap.contains(handle));
+                assert_eq!(heap.remove(handle) , None);
+            }else {
+                assert_eq!(heap.get(handle) , Some(&value));
+                remaining.push(value);
+            }
+        }
+        remaining.sort();
+        remaining.reverse();
+        let popped: Vec<i64> = std::iter::from_fn(|| heap.pop().map(|(_, value)| value)).collect();
+        assert_eq!(popped , remaining);
+    }
+    #[test]
+    fn indexed_slots_are_recycled(){
+        let mut heap: IndexedHeap<i64> = IndexedHeap::new_min();
+        let stale: Handle = heap.push(7);
+        assert_eq!(heap.pop() , Some((stale , 7)));
+        //El nuevo elemento reutiliza el slot pero el manejador viejo sigue siendo rechazado
+        let fresh: Handle = heap.push(3);
+        assert_ne!(fresh , stale);
+        assert!(!heap.contains(stale) && heap.get(fresh) == Some(&3));
+        assert!(heap.decrease_key(stale, 0).is_err());
+        assert_eq!(heap.remove(stale) , None);
+        for value in pseudo_random(1_000, 31){
+            let handle: Handle = heap.push(value);
+            assert_eq!(heap.remove(handle) , Some(value));
+        }
+        assert_eq!(heap.slots.len() , 2);
+        assert_eq!(heap.pop() , Some((fresh , 3)));
+    }
+    #[test]
+    fn indexed_random_updates_match_brute_force(){
+        let mut heap: IndexedHeap<i64> = IndexedHeap::new_min();
+        let mut model: Vec<Option<i64>> = Vec::new();
+        let mut handles: Vec<Handle> = Vec::new();
+        for (step, value) in pseudo_random(1_000, 23).into_iter().enumerate(){
+            match step % 4 {
+                0 | 1 => {
+                    handles.push(heap.push(value));
+                    model.push(Some(value));
+                },
+                2 => {
+                    let target: usize = value as usize % handles.len();
+                    if let Some(current) = model[target]{
+                        let lower: i64 = current - value;
+                        heap.decrease_key(handles[target], lower).unwrap();
+                        model[target] = Some(lower);
+                    }
+                },
+                _ => {
+                    let expected: Option<i64> = model.iter().flatten().min().copied();
+                    let popped: Option<(Handle, i64)> = heap.pop();
+                    assert_eq!(popped.map(|(_, value)| value) , expected);
+                    if let Some((handle, _)) = popped{
+                        model[handles.iter().position(|&h| h == handle).unwrap()] = None;
+                    }
+                }
+            }
+            assert_eq!(heap.len() , model.iter().flatten().count());
+        }
+    }
+}
@@ -53,7 +53,7 @@ use std::mem;
 /// ```
  
 #[derive(Debug)]
-struct NodeTree<T>{
+pub struct NodeTree<T>{
     value : T,
     left : Option<Box<NodeTree<T>>>,
     right : Option<Box<NodeTree<T>>>
@@ -273,6 +273,25 @@ where T : Integer + Clone + Copy + Display + Debug + Ord{
         }
 
     }
+    ///### Find_Mut_Node iterativo
+    /// Misma busqueda que `find_mut_node` pero descendiendo con un puntero mutable al enlace actual en lugar de recursión,
+    /// de esta manera la profundidad del arbol no consume stack, en un arbol degenerado la busqueda sigue siendo O(n) pero no desborda.
+    pub fn find_mut_node_iterative(&mut self , value : T) -> Result<&mut Option<Box<NodeTree<T>>> , String>{
+        let mut current: &mut Option<Box<NodeTree<T>>> = &mut self.root;
+        while current.as_ref().is_some_and(|n| n.value != value){
+            let node: &mut Box<NodeTree<T>> = current.as_mut().unwrap();
+            current = if value >= node.value{
+                &mut node.right
+            }else {
+                &mut node.left
+            };
+        }
+        if current.is_none(){
+            Err(String::from("No se encuentra el valor en el arbol"))
+        }else {
+            Ok(current)
+        }
+    }
     ///### Obtención del padre de un nodo en el arbol
     /// Este metodo del arbol permite obtener una referencia mutable al padre de un nodo en el arbol.
     pub fn find_parent_for_node(&mut self , value : T) -> Result<&mut Box<NodeTree<T>> , String>{
@@ -297,6 +316,23 @@ where T : Integer + Clone + Copy + Display + Debug + Ord{
             } 
         }
     }
+    ///### Obtención del padre de un nodo en el arbol (iterativo)
+    /// Version iterativa de `find_parent_for_node`, se avanza por el camino de busqueda hasta encontrar el nodo cuyo hijo
+    /// izquierdo o derecho contiene el valor.
+    pub fn find_parent_for_node_iterative(&mut self , value : T) -> Result<&mut Box<NodeTree<T>> , String>{
+        let mut current: &mut Option<Box<NodeTree<T>>> = &mut self.root;
+        while let Some(node) = current{
+            if node.right.as_ref().is_some_and(|n| n.value == value) || node.left.as_ref().is_some_and(|n| n.value == value){
+                return Ok(node);
+            }
+            current = if value >= node.value{
+                &mut node.right
+            }else {
+                &mut node.left
+            };
+        }
+        Err(String::from("No se encontro el nodo"))
+    }
     fn get_greater_left(node : &mut Option<Box<NodeTree<T>>>) -> Option<T>{
         let mut current_node: &mut Option<Box<NodeTree<T>>> = node;
         println!("Buscando el minimo en el subarbol derecho");
@@ -587,16 +623,9 @@ where T : Integer + Clone + Copy + Display + Debug + Ord{
                             //Encontramos el nodo
                             let deleted_node = match *node_unw {
                                 NodeTree { value, left: None, right: None } => None,
-                                NodeTree { value, left: Some(left_node), right: None } => {
-                                    node_unw.value = left_node.value;
-                                    node_unw.left = None;
-                                    Some(node_unw)
-                                },
-                                NodeTree { value, left: None, right: Some(right_node) } => {
-                                    node_unw.value = right_node.value;
-                                    node_unw.right = None;
-                                    Some(node_unw)
-                                },
+                                //Con un solo hijo el subarbol completo del hijo toma el lugar del nodo eliminado
+                                NodeTree { value, left: Some(left_node), right: None } => Some(left_node),
+                                NodeTree { value, left: None, right: Some(right_node) } => Some(right_node),
                                 NodeTree { value, ref mut left , ref mut  right } => {
                                     if let Some(n) = find_greater_left(left){
                                         *left = Self::remove_recursibly(left.take(), n);
@@ -629,6 +658,65 @@ where T : Integer + Clone + Copy + Display + Debug + Ord{
                 
             }
     } 
+    ///### Eliminar un nodo del arbol binario (iterativo)
+    /// Produce exactamente el mismo arbol que `remove_node_recursibly` pero sin recursión, los casos son los mismos.
+    /// - `Caso 1`: El nodo es hoja, el enlace del padre pasa a ser None.
+    /// - `Caso 2`: El nodo tiene un solo hijo, el subarbol del hijo toma su lugar.
+    /// - `Caso 3`: El nodo tiene dos hijos, se reemplaza su valor con el mayor del subarbol izquierdo y se continua
+    ///   eliminando ese valor dentro del subarbol izquierdo, lo que en la versión recursiva es una nueva llamada aqui es
+    ///   una nueva vuelta del ciclo.
+    /// ```text
+    ///                    remove_node_iterative(150)
+    ///                             +-------------------+
+    ///                         |---|LEFT | 150  | RIGHT|---|  <------ valor = 120
+    ///                         |  +--------------------+   |
+    ///             +-----------------+             +-------------------+
+    ///         |---|LEFT | 90 | RIGHT|---|    |---| LEFT | 200 | RIGHT|---|
+    ///         |   +-----------------+   |    |   +-------------------+   |
+    ///                          +-----------------+
+    ///                      |---|LEFT | 120 | RIGHT|---| <------ siguiente vuelta: eliminar 120 en el subarbol izquierdo
+    ///                          +-----------------+
+    /// ```
+    /// Retorna `true` si el valor se encontraba en el arbol.
+    pub fn remove_node_iterative(&mut self , value : T) -> bool{
+        let removed: bool = Self::remove_iterative(&mut self.root, value);
+        if removed{
+            self.size -= 1;
+        }
+        removed
+    }
+    fn remove_iterative(root : &mut Option<Box<NodeTree<T>>> , value : T) -> bool{
+        let mut current: &mut Option<Box<NodeTree<T>>> = root;
+        let mut target: T = value;
+        loop {
+            while current.as_ref().is_some_and(|n| n.value != target){
+                let node: &mut Box<NodeTree<T>> = current.as_mut().unwrap();
+                current = if target < node.value{
+                    &mut node.left
+                }else {
+                    &mut node.right
+                };
+            }
+            let have_two_children: bool = match current {
+                None => return false,
+                Some(node) => node.left_is_some() && node.right_is_some()
+            };
+            if have_two_children{
+                let node: &mut Box<NodeTree<T>> = current.as_mut().unwrap();
+                let mut greater_left: &NodeTree<T> = node.left.as_deref().unwrap();
+                while let Some(ref right) = greater_left.right{
+                    greater_left = right;
+                }
+                target = greater_left.value;
+                node.value = target;
+                current = &mut node.left;
+            }else {
+                let mut removed_node: Box<NodeTree<T>> = current.take().unwrap();
+                *current = removed_node.left.take().or_else(|| removed_node.right.take());
+                return true;
+            }
+        }
+    }
     /// ### Recorrido Inorder
     /// En el recorrido inorder se recorre primero recursivamente el subarbol izquierdo de la raiz, luego el nodo raiz
     /// y por ultimo recursivamente el subarbol derecho del nodo raiz
@@ -642,15 +730,44 @@ where T : Integer + Clone + Copy + Display + Debug + Ord{
     ///                  /__\                       /__\
     /// ```
     pub fn inorder_tree(&self){
-        Self::inorder(&self.root);
+        for value in self.inorder_recursibly(){
+            println!("{}" , value);
+        }
+    }
+    pub fn inorder_recursibly(&self) -> Vec<T>{
+        let mut values: Vec<T> = Vec::with_capacity(self.size);
+        Self::inorder(&self.root, &mut values);
+        values
     }
-    fn inorder(node : &Option<Box<NodeTree<T>>>){
+    fn inorder(node : &Option<Box<NodeTree<T>>> , values : &mut Vec<T>){
         if let Some( n) = node {
-                Self::inorder(&n.left);
-                println!("{}" , n.value);
-                Self::inorder(&n.right);
+                Self::inorder(&n.left, values);
+                values.push(n.value);
+                Self::inorder(&n.right, values);
         }
     }
+    ///### Recorrido Inorder iterativo
+    /// La pila de llamadas de la versión recursiva se reemplaza por una pila explicita, se desciende por la izquierda
+    /// apilando los nodos, al no haber más izquierda se visita el tope de la pila y se continua por su derecha.
+    pub fn inorder_iterative(&self) -> Vec<T>{
+        let mut values: Vec<T> = Vec::with_capacity(self.size);
+        let mut stack: Vec<&NodeTree<T>> = Vec::new();
+        let mut current: &Option<Box<NodeTree<T>>> = &self.root;
+        loop {
+            while let Some(node) = current{
+                stack.push(node);
+                current = &node.left;
+            }
+            match stack.pop() {
+                None => break,
+                Some(node) => {
+                    values.push(node.value);
+                    current = &node.right;
+                }
+            }
+        }
+        values
+    }
     /// ### Recorrido PostOrder
     /// En el recorrido inorder se recorre primero recursivamente el subarbol derecho de la raiz, luego el nodo raiz
     /// y por ultimo recursivamente el subarbol izquierdo del nodo raiz
@@ -664,19 +781,67 @@ where T : Integer + Clone + Copy + Display + Debug + Ord{
     ///                  /__\                       /__\
     /// ```
     pub fn postorder_tree(&self){
-        Self::postorder(&self.root);
+        for value in self.postorder_recursibly(){
+            println!("{}" , value);
+        }
+    }
+    pub fn postorder_recursibly(&self) -> Vec<T>{
+        let mut values: Vec<T> = Vec::with_capacity(self.size);
+        Self::postorder(&self.root, &mut values);
+        values
     }
-    fn postorder(node : &Option<Box<NodeTree<T>>>){
+    fn postorder(node : &Option<Box<NodeTree<T>>> , values : &mut Vec<T>){
         match node {
             Some(ref node) => {
-                Self::postorder(&node.right);
-                println!("{}" , node.as_ref().value);
-                Self::postorder(&node.left);
+                Self::postorder(&node.right, values);
+                values.push(node.as_ref().value);
+                Self::postorder(&node.left, values);
             }, 
             None => {}
             
         }
     }//Caso en el que nodo es hoja
+    ///### Recorrido PostOrder iterativo
+    /// Mismo orden que `postorder_tree` (subarbol derecho, raiz, subarbol izquierdo) utilizando una pila explicita.
+    pub fn postorder_iterative(&self) -> Vec<T>{
+        let mut values: Vec<T> = Vec::with_capacity(self.size);
+        let mut stack: Vec<&NodeTree<T>> = Vec::new();
+        let mut current: &Option<Box<NodeTree<T>>> = &self.root;
+        loop {
+            while let Some(node) = current{
+                stack.push(node);
+                current = &node.right;
+            }
+            match stack.pop() {
+                None => break,
+                Some(node) => {
+                    values.push(node.value);
+                    current = &node.left;
+                }
+            }
+        }
+        values
+    }
+}
+///### Drop
+/// La liberación por defecto de un `Option<Box<NodeTree<T>>>` desciende recursivamente por ambos subarboles, en el peor de los casos
+/// (inserción ordenada, el arbol degenera en una lista) la profundidad de la recursión es O(n) y desborda el stack.
+/// Se recorre el arbol con una pila propia desenlazando los hijos de cada nodo antes de liberarlo.
+impl<T> Drop for BinarySearchTree<T>{
+    fn drop(&mut self){
+        let mut stack: Vec<Box<NodeTree<T>>> = Vec::new();
+        if let Some(root) = self.root.take(){
+            stack.push(root);
+        }
+        while let Some(mut node) = stack.pop(){
+            if let Some(left) = node.left.take(){
+                stack.push(left);
+            }
+            if let Some(right) = node.right.take(){
+                stack.push(right);
+            }
+        }
+    }
 }
 #[cfg(test)]
 mod tests{
@@ -751,5 +916,127 @@ mod tests{
         println!("{}" , tree.len());
 
     }
+    #[test]
+    fn drop_degenerate_million_nodes(){
+        let mut tree : BinarySearchTree<i32> = BinarySearchTree::new();
+        //Se construye la cadena de hijos derechos que produciria una inserción ordenada de 1..=n
+        let mut root: Option<Box<NodeTree<i32>>> = None;
+        for i in (1..=1_000_000).rev(){
+            let mut node: Box<NodeTree<i32>> = Box::new(NodeTree::new(i));
+            node.right = root.take();
+            root = Some(node);
+        }
+        tree.root = root;
+        tree.size = 1_000_000;
+        assert_eq!(tree.len() , 1_000_000);
+        drop(tree);
+    }
+    //Secuencias de inserción para comparar las variantes recursivas e iterativas
+    fn insertion_cases() -> Vec<Vec<i32>>{
+        let mut pseudo_random: Vec<i32> = Vec::new();
+        let mut seed: i32 = 7;
+        for _ in 0..200{
+            seed = (seed * 1_103 + 12_345) % 1_009;
+            pseudo_random.push(seed);
+        }
+        vec![
+            vec![],
+            vec![50],
+            vec![50 , 30 , 70 , 20 , 40 , 60 , 80 , 35 , 45 , 65],
+            (1..=100).collect(),
+            (1..=100).rev().collect(),
+            vec![50 , 10 , 90 , 20 , 80 , 30 , 70 , 40 , 60],
+            vec![30 , 30 , 20 , 30 , 40 , 20 , 40 , 30],
+            pseudo_random,
+        ]
+    }
+    fn build_iterative(values : &[i32]) -> BinarySearchTree<i32>{
+        let mut tree : BinarySearchTree<i32> = BinarySearchTree::new();
+        values.iter().for_each(|v| tree.insert_node_iterative(*v));
+        tree
+    }
+    fn build_recursive(values : &[i32]) -> BinarySearchTree<i32>{
+        let mut tree : BinarySearchTree<i32> = BinarySearchTree::new();
+        values.iter().for_each(|v| tree.insert_node_recursibly(*v));
+        tree
+    }
+    #[test]
+    fn iterative_and_recursive_insertion_match(){
+        for case in insertion_cases(){
+            let iterative: BinarySearchTree<i32> = build_iterative(&case);
+            let recursive: BinarySearchTree<i32> = build_recursive(&case);
+            assert_eq!(format!("{:?}" , iterative) , format!("{:?}" , recursive) , "insercion {:?}" , case);
+        }
+    }
+    #[test]
+    fn iterative_and_recursive_traversals_match(){
+        for case in insertion_cases(){
+            let tree: BinarySearchTree<i32> = build_iterative(&case);
+            let mut sorted: Vec<i32> = case.clone();
+            sorted.sort();
+            assert_eq!(tree.inorder_iterative() , tree.inorder_recursibly());
+            assert_eq!(tree.inorder_iterative() , sorted);
+            assert_eq!(tree.postorder_iterative() , tree.postorder_recursibly());
+            sorted.reverse();
+            assert_eq!(tree.postorder_iterative() , sorted);
+        }
+    }
+    #[test]
+    fn iterative_and_recursive_search_match(){
+        for case in insertion_cases(){
+            let mut tree: BinarySearchTree<i32> = build_iterative(&case);
+            for value in case.iter().chain([-1 , 5_000].iter()){
+                let recursive: Option<*const NodeTree<i32>> = tree.find_mut_node(*value).ok().and_then(|n| n.as_deref().map(|n| n as *const NodeTree<i32>));
+                let iterative: Option<*const NodeTree<i32>> = tree.find_mut_node_iterative(*value).ok().and_then(|n| n.as_deref().map(|n| n as *const NodeTree<i32>));
+                assert_eq!(recursive , iterative , "busqueda de {}" , value);
+                let recursive: Option<*const NodeTree<i32>> = tree.find_parent_for_node(*value).ok().map(|n| n.as_ref() as *const NodeTree<i32>);
+                let iterative: Option<*const NodeTree<i32>> = tree.find_parent_for_node_iterative(*value).ok().map(|n| n.as_ref() as *const NodeTree<i32>);
+                assert_eq!(recursive , iterative , "padre de {}" , value);
+            }
+        }
+    }
+    #[test]
+    fn iterative_and_recursive_removal_match(){
+        for case in insertion_cases(){
+            let removal_orders: Vec<Vec<i32>> = vec![
+                case.clone(),
+                case.iter().rev().copied().collect(),
+                case.iter().step_by(2).chain(case.iter().skip(1).step_by(2)).copied().collect(),
+            ];
+            for order in removal_orders{
+                let mut iterative: BinarySearchTree<i32> = build_iterative(&case);
+                let mut recursive: BinarySearchTree<i32> = build_recursive(&case);
+                for value in order.iter(){
+                    assert!(iterative.remove_node_iterative(*value));
+                    recursive.remove_node_recursibly(*value);
+                    assert_eq!(format!("{:?}" , iterative) , format!("{:?}" , recursive) , "eliminando {} de {:?}" , value , case);
+                }
+                assert!(iterative.empty());
+                assert_eq!(iterative.len() , 0);
+                assert!(!iterative.remove_node_iterative(1));
+            }
+        }
+    }
+    #[test]
+    fn iterative_operations_on_degenerate_tree(){
+        let mut tree : BinarySearchTree<i32> = BinarySearchTree::new();
+        let mut root: Option<Box<NodeTree<i32>>> = None;
+        for i in (1..=1_000_000).rev(){
+            let mut node: Box<NodeTree<i32>> = Box::new(NodeTree::new(i));
+            node.right = root.take();
+            root = Some(node);
+        }
+        tree.root = root;
+        tree.size = 1_000_000;
+        assert_eq!(tree.inorder_iterative().len() , 1_000_000);
+        assert_eq!(tree.postorder_iterative()[0] , 1_000_000);
+        assert!(tree.find_mut_node_iterative(1_000_000).is_ok());
+        assert_eq!(tree.find_parent_for_node_iterative(1_000_000).unwrap().value , 999_999);
+        assert!(tree.remove_node_iterative(1_000_000));
+        assert!(tree.remove_node_iterative(500_000));
+        assert!(tree.remove_node_iterative(1));
+        tree.insert_node_iterative(1_000_001);
+        assert_eq!(tree.len() , 999_998);
+        assert_eq!(tree.find_parent_for_node_iterative(1_000_001).unwrap().value , 999_999);
+    }
 }
-
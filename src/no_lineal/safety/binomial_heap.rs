@@ -0,0 +1,279 @@
+//! ### Sobre los montículos binomiales (Binomial Heap)
+//! Un montículo binomial es una lista de árboles binomiales con grados distintos ordenados de menor a mayor. Un árbol
+//! binomial de grado `k` tiene `2^k` nodos y se forma enlazando dos árboles de grado `k - 1`, así un montículo de `n`
+//! elementos tiene un árbol por cada bit encendido de `n`.
+//! ```text
+//!   n = 13 = 0b1101            B0        B2              B3
+//!                              9         3               1
+//!                                       / \           /  |  \
+//!                                      7   4         2   5   6
+//!                                      |            / \  |
+//!                                      8           3   7 9
+//!                                                  |
+//!                                                  8
+//! ```
+//! - `meld` mezcla ambas listas de raíces como una suma binaria, enlazando los árboles de igual grado (el acarreo),
+//!   antes copia el arena más chico dentro del más grande, O(min(n, m)) (ver `priority_queue`).
+//! - `push` fusiona un montículo de un solo nodo.
+//! - `pop_min` retira la raíz menor y fusiona a sus hijos (que forman otro montículo binomial) con el resto.
+//! - `decrease_key` sube el valor intercambiandolo con su padre igual que en un montículo binario.
+//!
+//! Como `decrease_key` mueve valores entre nodos, los manejadores apuntan a un `slot` que sabe en qué nodo quedó su valor.
+//! `pop_min` libera el nodo retirado y el slot de su valor por separado, ambos se reutilizan en los siguientes `push` y
+//! la generación vive en el slot porque es lo que guardan los manejadores.
+use std::mem;
+use super::priority_queue::{Handle, HeapId, PriorityQueue};
+
+#[derive(Debug)]
+struct BinomialNode<T>{
+    value : Option<T>,
+    parent : Option<usize>,
+    child : Option<usize>,
+    sibling : Option<usize>,
+    degree : usize,
+    slot : usize
+}
+#[derive(Debug)]
+struct BinomialSlot{
+    node : usize,
+    generation : usize
+}
+///### BinomialHeap
+/// Montículo binomial de minimos con los nodos en un arena, los nodos y slots retirados se reutilizan en los
+/// siguientes `push`.
+#[derive(Debug)]
+pub struct BinomialHeap<T>{
+    nodes : Vec<BinomialNode<T>>,
+    //slots[manejador].node = nodo que guarda su valor
+    slots : Vec<BinomialSlot>,
+    free_nodes : Vec<usize>,
+    free_slots : Vec<usize>,
+    head : Option<usize>,
+    len : usize,
+    id : HeapId
+}
+impl<T : Ord> Default for BinomialHeap<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T : Ord> BinomialHeap<T>{
+    pub fn new() -> Self{
+        Self{
+            nodes : Vec::new(),
+            slots : Vec::new(),
+            free_nodes : Vec::new(),
+            free_slots : Vec::new(),
+            head : None,
+            len : 0,
+            id : HeapId::new()
+        }
+    }
+    fn value(&self , node : usize) -> &T{
+        self.nodes[node].value.as_ref().expect("Los nodos enlazados en el montículo siempre tienen valor")
+    }
+    fn resolve(&self , handle : Handle) -> Option<usize>{
+        let slot: usize = self.id.offset_of(handle.heap)? + handle.index;
+        let slot: &BinomialSlot = self.slots.get(slot).filter(|slot| slot.generation == handle.generation)?;
+        self.nodes[slot.node].value.as_ref().map(|_| slot.node)
+    }
+    ///Raíz suelta de grado cero con `value` y su slot, reutiliza los libres o los agrega al final.
+    fn allocate(&mut self , value : T) -> (usize, usize){
+        let node: usize = self.free_nodes.pop().unwrap_or(self.nodes.len());
+        let slot: usize = self.free_slots.pop().unwrap_or(self.slots.len());
+        let fresh: BinomialNode<T> = BinomialNode{ value : Some(value), parent : None, child : None, sibling : None, degree : 0, slot };
+        if node == self.nodes.len(){
+            self.nodes.push(fresh);
+        }else {
+            self.nodes[node] = fresh;
+        }
+        if slot == self.slots.len(){
+            self.slots.push(BinomialSlot{ node, generation : 0 });
+        }else {
+            self.slots[slot].node = node;
+        }
+        (node, slot)
+    }
+    ///Raíz con el menor valor junto a la raíz anterior en la lista.
+    fn min_root(&self) -> Option<(Option<usize>, usize)>{
+        let mut best: (Option<usize>, usize) = (None, self.head?);
+        let (mut prev, mut current): (usize, Option<usize>) = (best.1, self.nodes[best.1].sibling);
+        while let Some(node) = current{
+            if self.value(node) < self.value(best.1){
+                best = (Some(prev), node);
+            }
+            prev = node;
+            current = self.nodes[node].sibling;
+        }
+        Some(best)
+    }
+    ///`child` pasa a ser el primer hijo de `parent`, ambos deben tener el mismo grado.
+    fn link(&mut self , child : usize , parent : usize){
+        self.nodes[child].parent = Some(parent);
+        self.nodes[child].sibling = self.nodes[parent].child;
+        self.nodes[parent].child = Some(child);
+        self.nodes[parent].degree += 1;
+    }
+    ///Mezcla dos listas de raíces ordenadas por grado.
+    fn merge_roots(&mut self , mut a : Option<usize> , mut b : Option<usize>) -> Option<usize>{
+        let mut roots: Vec<usize> = Vec::new();
+        loop {
+            let next: usize = match (a, b) {
+                (Some(x), Some(y)) if self.nodes[x].degree <= self.nodes[y].degree => { a = self.nodes[x].sibling; x },
+                (_, Some(y)) => { b = self.nodes[y].sibling; y },
+                (Some(x), None) => { a = self.nodes[x].sibling; x },
+                (None, None) => break
+            };
+            roots.push(next);
+        }
+        for pair in roots.windows(2){
+            self.nodes[pair[0]].sibling = Some(pair[1]);
+        }
+        if let Some(&last) = roots.last(){
+            self.nodes[last].sibling = None;
+        }
+        roots.first().copied()
+    }
+    ///### Union
+    /// Mezcla las listas y enlaza los árboles de igual grado, cuando hay tres del mismo grado seguidos se deja pasar
+    /// al primero para que el acarreo enlace a los dos siguientes.
+    fn union(&mut self , a : Option<usize> , b : Option<usize>) -> Option<usize>{
+        let mut head: Option<usize> = self.merge_roots(a, b);
+        let mut prev: Option<usize> = None;
+        let mut current: usize = head?;
+        while let Some(next) = self.nodes[current].sibling{
+            let after: Option<usize> = self.nodes[next].sibling;
+            let same_degree: bool = self.nodes[current].degree == self.nodes[next].degree;
+            let carry_ahead: bool = after.is_some_and(|after| self.nodes[after].degree == self.nodes[current].degree);
+            if !same_degree || carry_ahead{
+                prev = Some(current);
+                current = next;
+            }else if self.value(current) <= self.value(next){
+                self.nodes[current].sibling = after;
+                self.link(next, current);
+            }else {
+                match prev {
+                    Some(prev) => self.nodes[prev].sibling = Some(next),
+                    None => head = Some(next)
+                }
+                self.link(current, next);
+                current = next;
+            }
+        }
+        head
+    }
+    ///Intercambia los valores de dos nodos manteniendo a los manejadores apuntando a su valor.
+    fn swap_values(&mut self , a : usize , b : usize){
+        let value: Option<T> = self.nodes[a].value.take();
+        self.nodes[a].value = self.nodes[b].value.take();
+        self.nodes[b].value = value;
+        let slot: usize = self.nodes[a].slot;
+        self.nodes[a].slot = self.nodes[b].slot;
+        self.nodes[b].slot = slot;
+        self.slots[self.nodes[a].slot].node = a;
+        self.slots[self.nodes[b].slot].node = b;
+    }
+}
+impl<T : Ord> PriorityQueue<T> for BinomialHeap<T>{
+    type Handle = Handle;
+    fn push(&mut self , value : T) -> Handle{
+        let (node, slot): (usize, usize) = self.allocate(value);
+        self.head = self.union(self.head, Some(node));
+        self.len += 1;
+        self.id.handle(slot, self.slots[slot].generation)
+    }
+    fn pop_min(&mut self) -> Option<T>{
+        let (prev, min): (Option<usize>, usize) = self.min_root()?;
+        let sibling: Option<usize> = self.nodes[min].sibling;
+        match prev {
+            Some(prev) => self.nodes[prev].sibling = sibling,
+            None => self.head = sibling
+        }
+        //Los hijos quedan de mayor a menor grado, invertidos forman una lista de raíces valida
+        let mut children: Option<usize> = None;
+        let mut current: Option<usize> = self.nodes[min].child.take();
+        while let Some(node) = current{
+            current = self.nodes[node].sibling;
+            self.nodes[node].sibling = children;
+            self.nodes[node].parent = None;
+            children = Some(node);
+        }
+        self.head = self.union(self.head, children);
+        let value: Option<T> = self.nodes[min].value.take();
+        //La nueva generación del slot invalida a los manejadores del valor retirado
+        let slot: usize = self.nodes[min].slot;
+        self.slots[slot].generation += 1;
+        self.free_slots.push(slot);
+        self.free_nodes.push(min);
+        self.len -= 1;
+        value
+    }
+    fn peek_min(&self) -> Option<&T>{
+        self.min_root().map(|(_, node)| self.value(node))
+    }
+    fn meld(&mut self , mut other : Self){
+        //Se copia el arena más chico dentro del más grande, cada elemento cambia de arena a lo más O(log n) veces
+        if other.nodes.len() > self.nodes.len(){
+            mem::swap(self, &mut other);
+        }
+        if other.len == 0{
+            return;
+        }
+        let node_offset: usize = self.nodes.len();
+        let slot_offset: usize = self.slots.len();
+        let shift = |index : Option<usize>| index.map(|index| index + node_offset);
+        for node in other.nodes{
+            self.nodes.push(BinomialNode{
+                value : node.value,
+                parent : shift(node.parent),
+                child : shift(node.child),
+                sibling : shift(node.sibling),
+                degree : node.degree,
+                slot : node.slot + slot_offset
+            });
+        }
+        self.slots.extend(other.slots.into_iter().map(|slot| BinomialSlot{ node : slot.node + node_offset, generation : slot.generation }));
+        self.free_nodes.extend(other.free_nodes.into_iter().map(|node| node + node_offset));
+        self.free_slots.extend(other.free_slots.into_iter().map(|slot| slot + slot_offset));
+        self.id.absorb(other.id, slot_offset);
+        self.head = self.union(self.head, shift(other.head));
+        self.len += other.len;
+    }
+    fn decrease_key(&mut self , handle : Handle , value : T) -> Result<(), String>{
+        let mut node: usize = self.resolve(handle).ok_or("El manejador no pertenece al montículo")?;
+        if value > *self.value(node){
+            return Err(String::from("El nuevo valor es mayor al actual"));
+        }
+        self.nodes[node].value = Some(value);
+        while let Some(parent) = self.nodes[node].parent{
+            if self.value(parent) <= self.value(node){
+                break;
+            }
+            self.swap_values(node, parent);
+            node = parent;
+        }
+        Ok(())
+    }
+    fn len(&self) -> usize{
+        self.len
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    crate::no_lineal::priority_queue_tests::priority_queue_tests!(BinomialHeap, arena: |heap| heap.nodes.len().max(heap.slots.len()));
+    #[test]
+    fn one_tree_per_bit_of_len(){
+        let mut heap : BinomialHeap<i32> = BinomialHeap::new();
+        for i in 0..13{
+            heap.push(i);
+        }
+        let mut degrees : Vec<usize> = Vec::new();
+        let mut current : Option<usize> = heap.head;
+        while let Some(node) = current{
+            degrees.push(heap.nodes[node].degree);
+            current = heap.nodes[node].sibling;
+        }
+        assert_eq!(degrees , vec![0 , 2 , 3]);
+    }
+}
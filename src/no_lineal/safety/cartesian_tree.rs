@@ -0,0 +1,188 @@
+//! ### Sobre los Cartesian Tree
+//! El arbol cartesiano de un arreglo es un heap de mínimos cuyo recorrido inorden devuelve el arreglo original: la raíz
+//! es la posición del mínimo, su subárbol izquierdo el arbol cartesiano de lo que está a su izquierda y lo mismo a la
+//! derecha.
+//! ```text
+//!   valores : 5  2  7  1  8  3
+//!   indice  : 0  1  2  3  4  5
+//!
+//!                  3 (1)
+//!                /       \
+//!            1 (2)       5 (3)
+//!           /    \       /
+//!        0 (5)  2 (7)  4 (8)
+//! ```
+//! El mínimo de `[l, r]` es el ancestro común más bajo (LCA) de `l` y `r`, así RMQ y LCA son el mismo problema. Se
+//! construye en O(n) con una pila que guarda la rama derecha: cada nuevo elemento desapila los mayores, el último
+//! desapilado pasa a ser su hijo izquierdo y él queda como hijo derecho del tope.
+//!
+//! Las consultas LCA recorren un tour de Euler del arbol (`2n - 1` visitas) con una `SparseTable` sobre
+//! `(profundidad, nodo)`, O(n log n) de preproceso y O(1) por consulta. Con valores repetidos el de más a la izquierda
+//! queda como ancestro, por lo que el RMQ devuelve el primer mínimo.
+use std::ops::RangeBounds;
+use super::segment_tree::{bounds, Min};
+use super::sparse_table::SparseTable;
+
+///### CartesianTree
+/// Solo guarda la forma del arbol, los nodos son los indices del arreglo original.
+#[derive(Debug)]
+pub struct CartesianTree{
+    root : Option<usize>,
+    parent : Vec<Option<usize>>,
+    left : Vec<Option<usize>>,
+    right : Vec<Option<usize>>,
+    ///Primera aparición de cada nodo en el tour de Euler.
+    first : Vec<usize>,
+    euler : SparseTable<(usize, usize), Min>
+}
+impl CartesianTree{
+    ///Construye el arbol de mínimos de `values` en O(n) y prepara las consultas en O(n log n).
+    pub fn from_slice<T : Ord>(values : &[T]) -> Self{
+        let len: usize = values.len();
+        let mut parent: Vec<Option<usize>> = vec![None; len];
+        let mut left: Vec<Option<usize>> = vec![None; len];
+        let mut right: Vec<Option<usize>> = vec![None; len];
+        //Rama derecha del arbol construido hasta ahora, de la raíz a la hoja
+        let mut spine: Vec<usize> = Vec::with_capacity(len);
+        for index in 0..len{
+            let mut last: Option<usize> = None;
+            //Desapilar solo los estrictamente mayores deja a los iguales anteriores como ancestros
+            while let Some(&top) = spine.last(){
+                if values[top] <= values[index]{
+                    break;
+                }
+                last = spine.pop();
+            }
+            if let Some(child) = last{
+                left[index] = Some(child);
+                parent[child] = Some(index);
+            }
+            if let Some(&top) = spine.last(){
+                right[top] = Some(index);
+                parent[index] = Some(top);
+            }
+            spine.push(index);
+        }
+        let root: Option<usize> = spine.first().copied();
+        let mut tree: CartesianTree = Self{ root, parent, left, right, first : vec![0; len], euler : SparseTable::from_slice(&[]) };
+        tree.euler_tour();
+        tree
+    }
+    ///Recorrido iterativo que anota `(profundidad, nodo)` al entrar a cada nodo y al volver de cada hijo.
+    fn euler_tour(&mut self){
+        let mut tour: Vec<(usize, usize)> = Vec::with_capacity(2 * self.len());
+        //(nodo, profundidad, hijos ya visitados)
+        let mut stack: Vec<(usize, usize, usize)> = self.root.map(|root| (root, 0, 0)).into_iter().collect();
+        while let Some((node, depth, visited)) = stack.pop(){
+            if visited == 0{
+                self.first[node] = tour.len();
+            }
+            tour.push((depth, node));
+            if let Some(child) = [self.left[node], self.right[node]].into_iter().flatten().nth(visited){
+                stack.push((node, depth, visited + 1));
+                stack.push((child, depth + 1, 0));
+            }
+        }
+        self.euler = SparseTable::from_slice(&tour);
+    }
+    pub fn len(&self) -> usize{
+        self.parent.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.parent.is_empty()
+    }
+    ///Indice del mínimo de todo el arreglo.
+    pub fn root(&self) -> Option<usize>{
+        self.root
+    }
+    pub fn parent(&self , node : usize) -> Option<usize>{
+        self.parent[node]
+    }
+    pub fn left(&self , node : usize) -> Option<usize>{
+        self.left[node]
+    }
+    pub fn right(&self , node : usize) -> Option<usize>{
+        self.right[node]
+    }
+    ///Ancestro común más bajo de `a` y `b` en O(1).
+    pub fn lca(&self , a : usize , b : usize) -> usize{
+        assert!(a < self.len() && b < self.len(), "nodos {} y {} fuera de rango para largo {}", a, b, self.len());
+        let (from, to) = (self.first[a].min(self.first[b]), self.first[a].max(self.first[b]));
+        self.euler.query(from..=to).1
+    }
+    ///Indice del primer mínimo del rango, `None` si está vacío.
+    pub fn range_min<R : RangeBounds<usize>>(&self , range : R) -> Option<usize>{
+        let (start, end) = bounds(range, self.len());
+        (start < end).then(|| self.lca(start, end - 1))
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::random::XorShift64;
+
+    fn depth(tree : &CartesianTree , mut node : usize) -> usize{
+        let mut depth: usize = 0;
+        while let Some(parent) = tree.parent(node){
+            node = parent;
+            depth += 1;
+        }
+        depth
+    }
+    fn naive_lca(tree : &CartesianTree , mut a : usize , mut b : usize) -> usize{
+        while depth(tree, a) > depth(tree, b){
+            a = tree.parent(a).unwrap();
+        }
+        while depth(tree, b) > depth(tree, a){
+            b = tree.parent(b).unwrap();
+        }
+        while a != b{
+            a = tree.parent(a).unwrap();
+            b = tree.parent(b).unwrap();
+        }
+        a
+    }
+    fn inorder(tree : &CartesianTree , node : Option<usize> , out : &mut Vec<usize>){
+        if let Some(node) = node{
+            inorder(tree, tree.left(node), out);
+            out.push(node);
+            inorder(tree, tree.right(node), out);
+        }
+    }
+    #[test]
+    fn example_shape(){
+        let tree: CartesianTree = CartesianTree::from_slice(&[5, 2, 7, 1, 8, 3]);
+        assert_eq!(tree.root() , Some(3));
+        assert_eq!((tree.left(3), tree.right(3)) , (Some(1), Some(5)));
+        assert_eq!((tree.left(1), tree.right(1)) , (Some(0), Some(2)));
+        assert_eq!((tree.left(5), tree.right(5)) , (Some(4), None));
+        assert_eq!(tree.lca(0, 2) , 1);
+        assert_eq!(tree.range_min(4..=5) , Some(5));
+        assert_eq!(tree.range_min(2..2) , None);
+        assert!(CartesianTree::from_slice::<i32>(&[]).root().is_none());
+    }
+    #[test]
+    fn matches_brute_force(){
+        let mut rng: XorShift64 = XorShift64::new(34);
+        for len in [1, 2, 5, 16, 60]{
+            //Pocos valores distintos para forzar empates
+            let values: Vec<u64> = (0..len).map(|_| rng.next_u64() % 10).collect();
+            let tree: CartesianTree = CartesianTree::from_slice(&values);
+            let mut order: Vec<usize> = Vec::new();
+            inorder(&tree, tree.root(), &mut order);
+            assert_eq!(order , (0..len).collect::<Vec<usize>>());
+            for node in 0..len{
+                assert!(tree.parent(node).is_none_or(|parent| values[parent] <= values[node]));
+            }
+            for start in 0..len{
+                for end in start + 1..=len{
+                    let window: &[u64] = &values[start..end];
+                    let minimum: u64 = *window.iter().min().unwrap();
+                    let expected: usize = start + window.iter().position(|&value| value == minimum).unwrap();
+                    assert_eq!(tree.range_min(start..end) , Some(expected));
+                    assert_eq!(tree.lca(end - 1, start) , naive_lca(&tree, start, end - 1));
+                }
+            }
+        }
+    }
+}
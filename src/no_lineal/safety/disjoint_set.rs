@@ -0,0 +1,405 @@
+//! ### Sobre los conjuntos disjuntos (Union-Find)
+//! Mantiene una partición de los elementos `0..n` en conjuntos disjuntos, cada conjunto es un arbol cuyos nodos
+//! apuntan a su padre y la raíz es el representante del conjunto.
+//! ```text
+//!   union(1, 3) , union(3, 4) , union(0, 2)
+//!
+//!        3           2            find(4) = 3     find(0) = 2
+//!       / \          |            connected(1, 4) = true
+//!      1   4         0            count_sets = 2
+//! ```
+//! - Compresión de caminos: `find` hace que todos los nodos recorridos apunten directo a la raíz.
+//! - Unión por rango o por tamaño: la raíz del arbol más bajo (o más chico) cuelga de la del otro, así la altura es
+//!   O(log n).
+//!
+//! Con ambas optimizaciones cada operación cuesta O(α(n)) amortizado, donde α es la inversa de Ackermann.
+//!
+//! `KeyedDisjointSet` traduce claves arbitrarias a indices con un `ChainedHashMap`, y `RollbackDisjointSet` renuncia a
+//! la compresión de caminos para poder deshacer uniones en orden inverso, util en algoritmos fuera de linea.
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash};
+use crate::lineal::safety::chained_hash_map::ChainedHashMap;
+
+///Criterio para decidir qué raíz queda arriba al unir dos conjuntos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnionBy{
+    ///Cuelga el arbol de menor rango (cota de su altura).
+    Rank,
+    ///Cuelga el arbol con menos elementos.
+    Size
+}
+///### DisjointSet
+/// Conjuntos disjuntos sobre los indices `0..len`, un indice fuera de rango provoca un pánico igual que un `Vec`.
+#[derive(Debug, Clone)]
+pub struct DisjointSet{
+    parent : Vec<usize>,
+    rank : Vec<u8>,
+    size : Vec<usize>,
+    sets : usize,
+    strategy : UnionBy
+}
+impl Default for DisjointSet{
+    fn default() -> Self{
+        Self::new(0)
+    }
+}
+impl DisjointSet{
+    ///`count` conjuntos unitarios unidos por tamaño.
+    pub fn new(count : usize) -> Self{
+        Self::with_strategy(count, UnionBy::Size)
+    }
+    pub fn with_strategy(count : usize , strategy : UnionBy) -> Self{
+        Self{
+            parent : (0..count).collect(),
+            rank : vec![0; count],
+            size : vec![1; count],
+            sets : count,
+            strategy
+        }
+    }
+    ///Cantidad de elementos.
+    pub fn len(&self) -> usize{
+        self.parent.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.parent.is_empty()
+    }
+    pub fn strategy(&self) -> UnionBy{
+        self.strategy
+    }
+    ///Cantidad de conjuntos distintos.
+    pub fn count_sets(&self) -> usize{
+        self.sets
+    }
+    ///Agrega un conjunto unitario y retorna su indice.
+    pub fn make_set(&mut self) -> usize{
+        self.parent.push(self.parent.len());
+        self.rank.push(0);
+        self.size.push(1);
+        self.sets += 1;
+        self.parent.len() - 1
+    }
+    ///Representante del conjunto de `element`, comprime el camino recorrido.
+    pub fn find(&mut self , element : usize) -> usize{
+        let mut root: usize = element;
+        while self.parent[root] != root{
+            root = self.parent[root];
+        }
+        //Segunda pasada: todo el camino apunta a la raíz
+        let mut current: usize = element;
+        while self.parent[current] != root{
+            let next: usize = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+    ///Une los conjuntos de `first` y `second`, retorna `false` si ya eran el mismo.
+    pub fn union(&mut self , first : usize , second : usize) -> bool{
+        let (mut first, mut second) = (self.find(first), self.find(second));
+        if first == second{
+            return false;
+        }
+        //`second` queda colgando de `first`
+        let swap: bool = match self.strategy{
+            UnionBy::Rank => self.rank[first] < self.rank[second],
+            UnionBy::Size => self.size[first] < self.size[second]
+        };
+        if swap{
+            std::mem::swap(&mut first, &mut second);
+        }
+        self.parent[second] = first;
+        self.size[first] += self.size[second];
+        if self.rank[first] == self.rank[second]{
+            self.rank[first] += 1;
+        }
+        self.sets -= 1;
+        true
+    }
+    pub fn connected(&mut self , first : usize , second : usize) -> bool{
+        self.find(first) == self.find(second)
+    }
+    ///Cantidad de elementos en el conjunto de `element`.
+    pub fn set_size(&mut self , element : usize) -> usize{
+        let root: usize = self.find(element);
+        self.size[root]
+    }
+    ///Conjuntos como listas de indices, en el orden de su menor elemento.
+    pub fn sets(&mut self) -> Vec<Vec<usize>>{
+        let mut slot: Vec<Option<usize>> = vec![None; self.len()];
+        let mut sets: Vec<Vec<usize>> = Vec::with_capacity(self.sets);
+        for element in 0..self.len(){
+            let root: usize = self.find(element);
+            let index: usize = *slot[root].get_or_insert_with(|| {
+                sets.push(Vec::new());
+                sets.len() - 1
+            });
+            sets[index].push(element);
+        }
+        sets
+    }
+}
+///### KeyedDisjointSet
+/// Conjuntos disjuntos sobre claves arbitrarias, cada clave nueva recibe el siguiente indice del `DisjointSet` interno.
+pub struct KeyedDisjointSet<T, S = RandomState>{
+    index : ChainedHashMap<T, usize, S>,
+    keys : Vec<T>,
+    sets : DisjointSet
+}
+impl<T> KeyedDisjointSet<T, RandomState>{
+    pub fn new() -> Self{
+        Self::with_hasher(RandomState::new())
+    }
+}
+impl<T, S : Default> Default for KeyedDisjointSet<T, S>{
+    fn default() -> Self{
+        Self{ index : ChainedHashMap::default(), keys : Vec::new(), sets : DisjointSet::default() }
+    }
+}
+impl<T, S> KeyedDisjointSet<T, S>{
+    pub fn with_hasher(hash_builder : S) -> Self{
+        Self{ index : ChainedHashMap::with_hasher(hash_builder), keys : Vec::new(), sets : DisjointSet::default() }
+    }
+    pub fn len(&self) -> usize{
+        self.keys.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.keys.is_empty()
+    }
+    pub fn count_sets(&self) -> usize{
+        self.sets.count_sets()
+    }
+}
+impl<T : Hash + Eq + Clone, S : BuildHasher> KeyedDisjointSet<T, S>{
+    ///Agrega `key` como conjunto unitario, retorna `false` si ya existía.
+    pub fn insert(&mut self , key : T) -> bool{
+        if self.index.contains_key(&key){
+            return false;
+        }
+        self.slot(key);
+        true
+    }
+    fn slot(&mut self , key : T) -> usize{
+        if let Some(&index) = self.index.get(&key){
+            return index;
+        }
+        let index: usize = self.sets.make_set();
+        self.index.insert(key.clone(), index);
+        self.keys.push(key);
+        index
+    }
+    pub fn contains<Q>(&self , key : &Q) -> bool
+    where T : Borrow<Q> , Q : Hash + Eq + ?Sized{
+        self.index.contains_key(key)
+    }
+    ///Clave representante del conjunto de `key`.
+    pub fn find<Q>(&mut self , key : &Q) -> Option<&T>
+    where T : Borrow<Q> , Q : Hash + Eq + ?Sized{
+        let index: usize = *self.index.get(key)?;
+        let root: usize = self.sets.find(index);
+        Some(&self.keys[root])
+    }
+    ///Une los conjuntos de ambas claves agregando las que no existan, retorna `false` si ya estaban juntas.
+    pub fn union(&mut self , first : T , second : T) -> bool{
+        let (first, second) = (self.slot(first), self.slot(second));
+        self.sets.union(first, second)
+    }
+    ///`false` si alguna de las claves no existe.
+    pub fn connected<Q>(&mut self , first : &Q , second : &Q) -> bool
+    where T : Borrow<Q> , Q : Hash + Eq + ?Sized{
+        match (self.index.get(first).copied(), self.index.get(second).copied()){
+            (Some(first), Some(second)) => self.sets.connected(first, second),
+            _ => false
+        }
+    }
+    pub fn set_size<Q>(&mut self , key : &Q) -> Option<usize>
+    where T : Borrow<Q> , Q : Hash + Eq + ?Sized{
+        let index: usize = *self.index.get(key)?;
+        Some(self.sets.set_size(index))
+    }
+}
+///### RollbackDisjointSet
+/// Unión por tamaño sin compresión de caminos: `find` cuesta O(log n) pero cada unión modifica una sola raíz, así
+/// puede deshacerse guardando esa raíz en el historial.
+/// ```text
+///   let mark = sets.snapshot();      historial : [(3 <- 1)]
+///   sets.union(0, 2);                historial : [(3 <- 1) , (2 <- 0)]
+///   sets.rollback(mark);             historial : [(3 <- 1)]
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RollbackDisjointSet{
+    parent : Vec<usize>,
+    size : Vec<usize>,
+    sets : usize,
+    ///Raíces colgadas por cada unión efectiva, en orden.
+    history : Vec<usize>
+}
+impl RollbackDisjointSet{
+    pub fn new(count : usize) -> Self{
+        Self{
+            parent : (0..count).collect(),
+            size : vec![1; count],
+            sets : count,
+            history : Vec::new()
+        }
+    }
+    pub fn len(&self) -> usize{
+        self.parent.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.parent.is_empty()
+    }
+    pub fn count_sets(&self) -> usize{
+        self.sets
+    }
+    pub fn find(&self , element : usize) -> usize{
+        let mut root: usize = element;
+        while self.parent[root] != root{
+            root = self.parent[root];
+        }
+        root
+    }
+    pub fn union(&mut self , first : usize , second : usize) -> bool{
+        let (mut first, mut second) = (self.find(first), self.find(second));
+        if first == second{
+            return false;
+        }
+        if self.size[first] < self.size[second]{
+            std::mem::swap(&mut first, &mut second);
+        }
+        self.parent[second] = first;
+        self.size[first] += self.size[second];
+        self.sets -= 1;
+        self.history.push(second);
+        true
+    }
+    pub fn connected(&self , first : usize , second : usize) -> bool{
+        self.find(first) == self.find(second)
+    }
+    pub fn set_size(&self , element : usize) -> usize{
+        self.size[self.find(element)]
+    }
+    ///Marca del estado actual para `rollback`, es la cantidad de uniones efectivas hechas.
+    pub fn snapshot(&self) -> usize{
+        self.history.len()
+    }
+    ///Deshace la ultima unión efectiva, retorna `false` si no hay ninguna.
+    pub fn undo(&mut self) -> bool{
+        let Some(child) = self.history.pop() else { return false };
+        let root: usize = self.parent[child];
+        self.size[root] -= self.size[child];
+        self.parent[child] = child;
+        self.sets += 1;
+        true
+    }
+    ///Deshace uniones hasta volver al estado de `snapshot`.
+    pub fn rollback(&mut self , snapshot : usize){
+        while self.history.len() > snapshot{
+            self.undo();
+        }
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::random::XorShift64;
+
+    ///Partición de referencia: cada elemento guarda su etiqueta y una unión reetiqueta todo un conjunto.
+    struct Labels(Vec<usize>);
+    impl Labels{
+        fn union(&mut self , first : usize , second : usize) -> bool{
+            let (from, to) = (self.0[second], self.0[first]);
+            for label in self.0.iter_mut().filter(|label| **label == from){
+                *label = to;
+            }
+            from != to
+        }
+        fn size(&self , element : usize) -> usize{
+            self.0.iter().filter(|&&label| label == self.0[element]).count()
+        }
+        fn count(&self) -> usize{
+            let mut labels: Vec<usize> = self.0.clone();
+            labels.sort();
+            labels.dedup();
+            labels.len()
+        }
+    }
+    #[test]
+    fn matches_brute_force(){
+        for strategy in [UnionBy::Rank, UnionBy::Size]{
+            let mut rng: XorShift64 = XorShift64::new(17);
+            let mut sets: DisjointSet = DisjointSet::with_strategy(60, strategy);
+            let mut labels: Labels = Labels((0..60).collect());
+            for _ in 0..300{
+                let (first, second) = (rng.next_u64() as usize % 60, rng.next_u64() as usize % 60);
+                if rng.next_u64() % 3 == 0{
+                    assert_eq!(sets.union(first, second) , labels.union(first, second));
+                }else{
+                    assert_eq!(sets.connected(first, second) , labels.0[first] == labels.0[second]);
+                    assert_eq!(sets.set_size(first) , labels.size(first));
+                }
+                assert_eq!(sets.count_sets() , labels.count());
+            }
+            let total: usize = sets.sets().iter().map(Vec::len).sum();
+            assert_eq!(total , 60);
+        }
+    }
+    #[test]
+    fn make_set_and_deep_chain(){
+        let mut sets: DisjointSet = DisjointSet::default();
+        for _ in 0..100_000{
+            let element: usize = sets.make_set();
+            if element > 0{
+                sets.union(element - 1, element);
+            }
+        }
+        assert_eq!(sets.count_sets() , 1);
+        assert_eq!(sets.set_size(0) , 100_000);
+        assert!(sets.connected(0, 99_999));
+    }
+    #[test]
+    fn keyed_sets(){
+        let mut sets: KeyedDisjointSet<String> = KeyedDisjointSet::new();
+        assert!(sets.insert(String::from("ana")));
+        assert!(!sets.insert(String::from("ana")));
+        assert!(sets.union(String::from("ana"), String::from("beto")));
+        assert!(sets.union(String::from("carla"), String::from("diego")));
+        assert!(!sets.union(String::from("beto"), String::from("ana")));
+        assert_eq!((sets.len(), sets.count_sets()) , (4, 2));
+        assert!(sets.connected("ana", "beto"));
+        assert!(!sets.connected("ana", "carla"));
+        assert!(!sets.connected("ana", "eva"));
+        assert_eq!(sets.set_size("diego") , Some(2));
+        assert_eq!(sets.set_size("eva") , None);
+        sets.union(String::from("beto"), String::from("diego"));
+        let representative: String = sets.find("ana").unwrap().clone();
+        assert_eq!(sets.find("carla") , Some(&representative));
+        assert_eq!(sets.count_sets() , 1);
+    }
+    #[test]
+    fn rollback_restores_previous_states(){
+        let mut rng: XorShift64 = XorShift64::new(5);
+        let mut sets: RollbackDisjointSet = RollbackDisjointSet::new(30);
+        let mut states: Vec<(usize, Vec<usize>)> = Vec::new();
+        for _ in 0..40{
+            states.push((sets.snapshot(), (0..30).map(|element| sets.find(element)).collect()));
+            sets.union(rng.next_u64() as usize % 30, rng.next_u64() as usize % 30);
+        }
+        //Volver a cada marca en orden inverso deja exactamente la misma partición
+        while let Some((mark, roots)) = states.pop(){
+            sets.rollback(mark);
+            for first in 0..30{
+                for second in 0..30{
+                    assert_eq!(sets.connected(first, second) , roots[first] == roots[second]);
+                }
+            }
+        }
+        assert_eq!(sets.count_sets() , 30);
+        assert!(!sets.undo());
+        assert!(sets.union(1, 2));
+        assert_eq!(sets.set_size(2) , 2);
+        assert!(sets.undo());
+        assert_eq!(sets.set_size(2) , 1);
+    }
+}
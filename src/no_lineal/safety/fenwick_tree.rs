@@ -0,0 +1,176 @@
+//! ### Sobre los Fenwick Tree (Binary Indexed Tree)
+//! Un Fenwick tree guarda sumas parciales en un solo arreglo: la posición `i` (contando desde 1) acumula los
+//! `lowbit(i)` elementos que terminan en `i`, donde `lowbit(i) = i & -i` es el bit encendido más bajo.
+//! ```text
+//!   i        :  1    2    3    4    5    6    7    8
+//!   cubre    : [1]  [1,2] [3] [1,4] [5] [5,6] [7] [1,8]
+//!
+//!   prefix(7) = tree[7] + tree[6] + tree[4]      7 -> 6 -> 4 -> 0  (i -= lowbit(i))
+//!   add(3)    : tree[3] , tree[4] , tree[8]      3 -> 4 -> 8       (i += lowbit(i))
+//! ```
+//! Suma de prefijos y actualización puntual en O(log n) con un código más simple que un segment tree, a cambio solo
+//! sirve para operaciones invertibles como la suma.
+use std::ops::{Add, RangeBounds, Sub};
+use num::Zero;
+use super::segment_tree::bounds;
+
+fn lowbit(index : usize) -> usize{
+    index & index.wrapping_neg()
+}
+///### FenwickTree
+#[derive(Debug, Clone)]
+pub struct FenwickTree<T>{
+    ///`tree[0]` no se usa para que los indices empiecen en 1.
+    tree : Vec<T>,
+    ///Valor actual de cada posición.
+    values : Vec<T>
+}
+impl<T : Copy + Zero + Add<Output = T> + Sub<Output = T>> FenwickTree<T>{
+    ///`len` ceros.
+    pub fn new(len : usize) -> Self{
+        Self{ tree : vec![T::zero(); len + 1], values : vec![T::zero(); len] }
+    }
+    ///Construye el arbol en O(n) empujando cada acumulado a su padre directo.
+    pub fn from_slice(values : &[T]) -> Self{
+        let mut tree: Vec<T> = Vec::with_capacity(values.len() + 1);
+        tree.push(T::zero());
+        tree.extend_from_slice(values);
+        for index in 1..tree.len(){
+            let parent: usize = index + lowbit(index);
+            if parent < tree.len(){
+                tree[parent] = tree[parent] + tree[index];
+            }
+        }
+        Self{ tree, values : values.to_vec() }
+    }
+    pub fn len(&self) -> usize{
+        self.values.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.len() == 0
+    }
+    ///Suma `delta` a la posición `index`.
+    pub fn add(&mut self , index : usize , delta : T){
+        assert!(index < self.len(), "indice {} fuera de rango para largo {}", index, self.len());
+        self.values[index] = self.values[index] + delta;
+        let mut node: usize = index + 1;
+        while node < self.tree.len(){
+            self.tree[node] = self.tree[node] + delta;
+            node += lowbit(node);
+        }
+    }
+    ///Resta `delta` a la posición `index`, el valor de la posición debe ser al menos `delta`.
+    fn subtract(&mut self , index : usize , delta : T){
+        self.values[index] = self.values[index] - delta;
+        let mut node: usize = index + 1;
+        while node < self.tree.len(){
+            self.tree[node] = self.tree[node] - delta;
+            node += lowbit(node);
+        }
+    }
+    ///Suma de las primeras `end` posiciones.
+    pub fn prefix_sum(&self , end : usize) -> T{
+        assert!(end <= self.len(), "el prefijo {} excede el largo {}", end, self.len());
+        let mut total: T = T::zero();
+        let mut node: usize = end;
+        while node > 0{
+            total = total + self.tree[node];
+            node -= lowbit(node);
+        }
+        total
+    }
+    pub fn range_sum<R : RangeBounds<usize>>(&self , range : R) -> T{
+        let (start, end) = bounds(range, self.len());
+        self.prefix_sum(end) - self.prefix_sum(start)
+    }
+    pub fn get(&self , index : usize) -> T{
+        assert!(index < self.len(), "indice {} fuera de rango para largo {}", index, self.len());
+        self.values[index]
+    }
+}
+impl<T : Copy + Zero + PartialOrd + Add<Output = T> + Sub<Output = T>> FenwickTree<T>{
+    ///Reemplaza la posición `index` por `value`, suma o resta solo la diferencia positiva así sirve con tipos sin signo.
+    pub fn set(&mut self , index : usize , value : T){
+        let current: T = self.get(index);
+        if value >= current{
+            self.add(index, value - current);
+        }else{
+            self.subtract(index, current - value);
+        }
+    }
+}
+impl<T : Copy + Zero + Ord + Add<Output = T> + Sub<Output = T>> FenwickTree<T>{
+    ///Menor `end` tal que `prefix_sum(end) >= target`, requiere valores no negativos. Desciende por potencias de dos
+    ///en O(log n), retorna `None` si ni la suma total alcanza.
+    pub fn lower_bound(&self , target : T) -> Option<usize>{
+        if target <= T::zero(){
+            return Some(0);
+        }
+        let mut position: usize = 0;
+        let mut remaining: T = target;
+        let mut step: usize = self.len().checked_next_power_of_two().unwrap_or(0);
+        while step > 0{
+            let next: usize = position + step;
+            if next < self.tree.len() && self.tree[next] < remaining{
+                position = next;
+                remaining = remaining - self.tree[next];
+            }
+            step /= 2;
+        }
+        (position < self.len()).then_some(position + 1)
+    }
+}
+impl<T : Copy + Zero + Add<Output = T> + Sub<Output = T>> FromIterator<T> for FenwickTree<T>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        Self::from_slice(&iter.into_iter().collect::<Vec<T>>())
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::random::XorShift64;
+
+    #[test]
+    fn matches_brute_force(){
+        let mut rng: XorShift64 = XorShift64::new(13);
+        for len in [1, 2, 9, 64, 100]{
+            let mut values: Vec<i64> = (0..len).map(|_| (rng.next_u64() % 50) as i64).collect();
+            let mut tree: FenwickTree<i64> = FenwickTree::from_slice(&values);
+            let mut incremental: FenwickTree<i64> = FenwickTree::new(len);
+            for (index, &value) in values.iter().enumerate(){
+                incremental.add(index, value);
+            }
+            for _ in 0..300{
+                let (a, b) = (rng.next_u64() as usize % (len + 1), rng.next_u64() as usize % (len + 1));
+                let (start, end) = (a.min(b), a.max(b));
+                assert_eq!(tree.range_sum(start..end) , values[start..end].iter().sum::<i64>());
+                assert_eq!(incremental.prefix_sum(end) , values[..end].iter().sum::<i64>());
+                //Con valores no negativos los prefijos crecen y `lower_bound` es una búsqueda binaria
+                let target: i64 = (rng.next_u64() % (values.iter().sum::<i64>() as u64 + 10)) as i64;
+                let expected: Option<usize> = (0..=len).find(|&end| values[..end].iter().sum::<i64>() >= target);
+                assert_eq!(tree.lower_bound(target) , expected);
+                let index: usize = rng.next_u64() as usize % len;
+                let value: i64 = (rng.next_u64() % 50) as i64;
+                values[index] = value;
+                tree.set(index, value);
+                incremental.set(index, value);
+            }
+        }
+    }
+    #[test]
+    fn set_lowers_unsigned_values(){
+        let mut rng: XorShift64 = XorShift64::new(29);
+        let mut values: Vec<u32> = (0..40).map(|_| (rng.next_u64() % 100) as u32).collect();
+        let mut tree: FenwickTree<u32> = values.iter().copied().collect();
+        for _ in 0..500{
+            let index: usize = rng.next_u64() as usize % values.len();
+            //La mitad de las veces baja el valor, con la resta directa eso desbordaba
+            let value: u32 = if rng.next_u64() % 2 == 0{ values[index] / 2 }else{ (rng.next_u64() % 100) as u32 };
+            values[index] = value;
+            tree.set(index, value);
+            let end: usize = rng.next_u64() as usize % (values.len() + 1);
+            assert_eq!(tree.prefix_sum(end) , values[..end].iter().sum::<u32>());
+            assert_eq!(tree.get(index) , value);
+        }
+    }
+}
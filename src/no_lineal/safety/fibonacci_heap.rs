@@ -0,0 +1,284 @@
+//! ### Sobre los montículos de Fibonacci (Fibonacci Heap)
+//! Un montículo de Fibonacci es una colección de árboles cuyas raíces forman una lista circular doblemente enlazada,
+//! `min` apunta a la menor raíz. Los hijos de cada nodo forman a su vez otra lista circular.
+//! ```text
+//!                  min
+//!                   ↓
+//!   ... <-> 23 <-> 7 <-> 3 <-> 17 <-> 24 <-> ...
+//!                       /|\     |     / \
+//!                     18 52 38  30   26  46
+//!                     |      |        |
+//!                     39     41       35
+//! ```
+//! - `push` agrega a la lista de raíces en O(1), `meld` también solo une las listas de raíces pero antes copia el arena
+//!   más chico dentro del más grande, O(min(n, m)) (ver `priority_queue`).
+//! - `pop_min` sube los hijos del minimo a la lista de raíces y consolida: enlaza raíces del mismo grado hasta que no
+//!   queden dos iguales, O(log n) amortizado.
+//! - `decrease_key` corta el nodo si queda menor que su padre, si el padre ya había perdido un hijo (`mark`) también se
+//!   corta en cascada, O(1) amortizado.
+use std::mem;
+use super::priority_queue::{Handle, HeapId, PriorityQueue};
+
+#[derive(Debug)]
+struct FibonacciNode<T>{
+    value : Option<T>,
+    parent : Option<usize>,
+    child : Option<usize>,
+    left : usize,
+    right : usize,
+    degree : usize,
+    mark : bool,
+    generation : usize
+}
+///### FibonacciHeap
+/// Montículo de Fibonacci de minimos con los nodos en un arena, los nodos retirados se reutilizan en los siguientes
+/// `push`.
+#[derive(Debug)]
+pub struct FibonacciHeap<T>{
+    nodes : Vec<FibonacciNode<T>>,
+    free : Vec<usize>,
+    min : Option<usize>,
+    len : usize,
+    id : HeapId
+}
+impl<T : Ord> Default for FibonacciHeap<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T : Ord> FibonacciHeap<T>{
+    pub fn new() -> Self{
+        Self{ nodes : Vec::new(), free : Vec::new(), min : None, len : 0, id : HeapId::new() }
+    }
+    fn value(&self , node : usize) -> &T{
+        self.nodes[node].value.as_ref().expect("Los nodos enlazados en el montículo siempre tienen valor")
+    }
+    fn resolve(&self , handle : Handle) -> Option<usize>{
+        let node: usize = self.id.offset_of(handle.heap)? + handle.index;
+        self.nodes.get(node).filter(|n| n.value.is_some() && n.generation == handle.generation).map(|_| node)
+    }
+    ///Nodo suelto con `value`, reutiliza uno libre manteniendo su generación o agrega uno nuevo al arena.
+    fn allocate(&mut self , value : T) -> usize{
+        let node: usize = self.free.pop().unwrap_or(self.nodes.len());
+        let generation: usize = self.nodes.get(node).map_or(0, |n| n.generation);
+        let fresh: FibonacciNode<T> = FibonacciNode{
+            value : Some(value), parent : None, child : None, left : node, right : node, degree : 0, mark : false, generation
+        };
+        if node == self.nodes.len(){
+            self.nodes.push(fresh);
+        }else {
+            self.nodes[node] = fresh;
+        }
+        node
+    }
+    ///Une las listas circulares que contienen a `a` y a `b`.
+    fn splice(&mut self , a : usize , b : usize){
+        let a_right: usize = self.nodes[a].right;
+        let b_left: usize = self.nodes[b].left;
+        self.nodes[a].right = b;
+        self.nodes[b].left = a;
+        self.nodes[b_left].right = a_right;
+        self.nodes[a_right].left = b_left;
+    }
+    ///Saca al nodo de su lista circular dejandolo enlazado consigo mismo.
+    fn unlink(&mut self , node : usize){
+        let (left, right): (usize, usize) = (self.nodes[node].left, self.nodes[node].right);
+        self.nodes[left].right = right;
+        self.nodes[right].left = left;
+        self.nodes[node].left = node;
+        self.nodes[node].right = node;
+    }
+    ///Agrega un nodo suelto a la lista de raíces actualizando el minimo.
+    fn add_root(&mut self , node : usize){
+        self.nodes[node].parent = None;
+        match self.min {
+            Some(min) => {
+                self.splice(min, node);
+                if self.value(node) < self.value(min){
+                    self.min = Some(node);
+                }
+            },
+            None => self.min = Some(node)
+        }
+    }
+    ///Los nodos de la lista circular que contiene a `start`.
+    fn list(&self , start : usize) -> Vec<usize>{
+        let mut nodes: Vec<usize> = vec![start];
+        let mut current: usize = self.nodes[start].right;
+        while current != start{
+            nodes.push(current);
+            current = self.nodes[current].right;
+        }
+        nodes
+    }
+    ///`child` (una raíz) pasa a ser hijo de `parent`.
+    fn link(&mut self , child : usize , parent : usize){
+        self.unlink(child);
+        self.nodes[child].parent = Some(parent);
+        self.nodes[child].mark = false;
+        match self.nodes[parent].child {
+            Some(first) => self.splice(first, child),
+            None => self.nodes[parent].child = Some(child)
+        }
+        self.nodes[parent].degree += 1;
+    }
+    ///### Consolidate
+    /// Enlaza raíces del mismo grado, `by_degree[d]` guarda la raíz de grado `d` vista hasta el momento.
+    fn consolidate(&mut self , start : usize){
+        let mut by_degree: Vec<Option<usize>> = Vec::new();
+        for root in self.list(start){
+            let mut node: usize = root;
+            let mut degree: usize = self.nodes[node].degree;
+            while let Some(other) = by_degree.get_mut(degree).and_then(Option::take){
+                let (parent, child): (usize, usize) = if self.value(other) < self.value(node) { (other, node) } else { (node, other) };
+                self.link(child, parent);
+                node = parent;
+                degree += 1;
+            }
+            if by_degree.len() <= degree{
+                by_degree.resize(degree + 1, None);
+            }
+            by_degree[degree] = Some(node);
+        }
+        self.min = None;
+        for root in by_degree.into_iter().flatten(){
+            if self.min.is_none_or(|min| self.value(root) < self.value(min)){
+                self.min = Some(root);
+            }
+        }
+    }
+    ///Corta a `node` de su padre `parent` y lo sube a la lista de raíces.
+    fn cut(&mut self , node : usize , parent : usize){
+        if self.nodes[parent].child == Some(node){
+            let right: usize = self.nodes[node].right;
+            self.nodes[parent].child = if right == node { None } else { Some(right) };
+        }
+        self.unlink(node);
+        self.nodes[parent].degree -= 1;
+        self.nodes[node].mark = false;
+        self.add_root(node);
+    }
+    fn cascading_cut(&mut self , mut node : usize){
+        while let Some(parent) = self.nodes[node].parent{
+            if !self.nodes[node].mark{
+                self.nodes[node].mark = true;
+                return;
+            }
+            self.cut(node, parent);
+            node = parent;
+        }
+    }
+}
+impl<T : Ord> PriorityQueue<T> for FibonacciHeap<T>{
+    type Handle = Handle;
+    fn push(&mut self , value : T) -> Handle{
+        let node: usize = self.allocate(value);
+        self.add_root(node);
+        self.len += 1;
+        self.id.handle(node, self.nodes[node].generation)
+    }
+    fn pop_min(&mut self) -> Option<T>{
+        let min: usize = self.min?;
+        if let Some(child) = self.nodes[min].child.take(){
+            for node in self.list(child){
+                self.nodes[node].parent = None;
+            }
+            self.splice(min, child);
+        }
+        let next: usize = self.nodes[min].right;
+        self.unlink(min);
+        if next == min{
+            self.min = None;
+        }else {
+            self.consolidate(next);
+        }
+        let value: Option<T> = self.nodes[min].value.take();
+        //La nueva generación invalida a los manejadores que todavía apuntan al nodo
+        self.nodes[min].generation += 1;
+        self.free.push(min);
+        self.len -= 1;
+        value
+    }
+    fn peek_min(&self) -> Option<&T>{
+        self.min.map(|min| self.value(min))
+    }
+    fn meld(&mut self , mut other : Self){
+        //Se copia el arena más chico dentro del más grande, cada elemento cambia de arena a lo más O(log n) veces
+        if other.nodes.len() > self.nodes.len(){
+            mem::swap(self, &mut other);
+        }
+        if other.len == 0{
+            return;
+        }
+        let offset: usize = self.nodes.len();
+        let shift = |index : Option<usize>| index.map(|index| index + offset);
+        for node in other.nodes{
+            self.nodes.push(FibonacciNode{
+                value : node.value,
+                parent : shift(node.parent),
+                child : shift(node.child),
+                left : node.left + offset,
+                right : node.right + offset,
+                degree : node.degree,
+                mark : node.mark,
+                generation : node.generation
+            });
+        }
+        self.free.extend(other.free.into_iter().map(|node| node + offset));
+        self.id.absorb(other.id, offset);
+        let other_min: usize = other.min.expect("Un montículo con elementos tiene minimo") + offset;
+        match self.min {
+            Some(min) => {
+                self.splice(min, other_min);
+                if self.value(other_min) < self.value(min){
+                    self.min = Some(other_min);
+                }
+            },
+            None => self.min = Some(other_min)
+        }
+        self.len += other.len;
+    }
+    fn decrease_key(&mut self , handle : Handle , value : T) -> Result<(), String>{
+        let node: usize = self.resolve(handle).ok_or("El manejador no pertenece al montículo")?;
+        if value > *self.value(node){
+            return Err(String::from("El nuevo valor es mayor al actual"));
+        }
+        self.nodes[node].value = Some(value);
+        match self.nodes[node].parent {
+            Some(parent) if self.value(node) < self.value(parent) => {
+                self.cut(node, parent);
+                self.cascading_cut(parent);
+            },
+            Some(_) => {},
+            None => {
+                if self.min.is_some_and(|min| self.value(node) < self.value(min)){
+                    self.min = Some(node);
+                }
+            }
+        }
+        Ok(())
+    }
+    fn len(&self) -> usize{
+        self.len
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    crate::no_lineal::priority_queue_tests::priority_queue_tests!(FibonacciHeap, arena: |heap| heap.nodes.len());
+    #[test]
+    fn consolidate_leaves_distinct_degrees(){
+        let mut heap : FibonacciHeap<i32> = FibonacciHeap::new();
+        for i in 0..33{
+            heap.push(i);
+        }
+        heap.pop_min();
+        let degrees : Vec<usize> = heap.list(heap.min.unwrap()).into_iter().map(|root| heap.nodes[root].degree).collect();
+        let mut unique : Vec<usize> = degrees.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len() , degrees.len());
+        //32 elementos forman un único árbol de grado 5
+        assert_eq!(degrees , vec![5]);
+    }
+}
@@ -0,0 +1,319 @@
+//! ### Sobre los Grafos
+//! Un grafo es un conjunto de nodos unidos por aristas, a diferencia de los arboles puede tener ciclos, varios
+//! componentes y aristas en ambos sentidos.
+//! ```text
+//!        Dirigido                     No dirigido
+//!     A -----> B                     A ------ B
+//!     ^        |                     |        |
+//!     |        v                     |        |
+//!     D <----- C                     D ------ C
+//! ```
+//! `Graph<N, E, S>` guarda el dato de cada nodo (`N`) y de cada arista (`E`) en arenas indexadas, y delega la
+//! topología (quién es vecino de quién) a un `GraphStorage` intercambiable:
+//! - `AdjacencyList`: una lista de `(vecino, arista)` por nodo, memoria O(V + E), recorrer los vecinos es O(grado).
+//! - `AdjacencyMatrix`: una matriz V x V con la arista de cada par, memoria O(V²), consultar si existe una arista es
+//!   O(1) pero recorrer vecinos es O(V), no admite aristas paralelas.
+//!
+//! ###### IDs estables
+//! `NodeId` y `EdgeId` son indices que nunca se reutilizan: eliminar un nodo o una arista no cambia el ID de los demás,
+//! así un ID guardado por el usuario sigue siendo valido (o inexistente) sin importar las eliminaciones.
+//!
+//! En un grafo no dirigido cada arista aparece en la adyacencia de sus dos extremos con el mismo `EdgeId`.
+use std::fmt::{self, Debug};
+use super::adjacency_list::AdjacencyList;
+
+///Identificador estable de un nodo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(pub(crate) usize);
+impl NodeId{
+    ///Posición del nodo en las arenas, util para indexar vectores auxiliares de tamaño `node_bound`.
+    pub fn index(&self) -> usize{
+        self.0
+    }
+}
+///Identificador estable de una arista.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EdgeId(pub(crate) usize);
+impl EdgeId{
+    pub fn index(&self) -> usize{
+        self.0
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction{
+    Directed,
+    Undirected
+}
+///### GraphStorage
+/// Representación de la topología del grafo. Los nodos se identifican por el orden en que se agregaron, `Graph` se
+/// encarga de validar los IDs y de duplicar las aristas no dirigidas.
+pub trait GraphStorage : Default{
+    type Neighbors<'a> : Iterator<Item = (NodeId, EdgeId)> where Self : 'a;
+    ///Reserva espacio para el nodo `node_bound`.
+    fn add_node(&mut self);
+    ///Enlaza `from -> to`.
+    fn add_edge(&mut self , from : NodeId , to : NodeId , edge : EdgeId) -> Result<(), String>;
+    ///Quita el enlace `from -> to` de la arista `edge`.
+    fn remove_edge(&mut self , from : NodeId , to : NodeId , edge : EdgeId);
+    ///Pares `(vecino, arista)` salientes de `node`.
+    fn neighbors(&self , node : NodeId) -> Self::Neighbors<'_>;
+    fn find_edge(&self , from : NodeId , to : NodeId) -> Option<EdgeId>;
+}
+#[derive(Debug, Clone)]
+struct EdgeEntry<E>{
+    from : NodeId,
+    to : NodeId,
+    weight : E
+}
+///### Graph
+/// Grafo dirigido o no dirigido con datos en nodos y aristas.
+pub struct Graph<N, E, S = AdjacencyList>{
+    nodes : Vec<Option<N>>,
+    edges : Vec<Option<EdgeEntry<E>>>,
+    ///Aristas que llegan a cada nodo, solo se usa en grafos dirigidos.
+    in_degree : Vec<usize>,
+    node_count : usize,
+    edge_count : usize,
+    direction : Direction,
+    storage : S
+}
+impl<N, E, S : GraphStorage> Graph<N, E, S>{
+    pub fn new(direction : Direction) -> Self{
+        Self{
+            nodes : Vec::new(),
+            edges : Vec::new(),
+            in_degree : Vec::new(),
+            node_count : 0,
+            edge_count : 0,
+            direction,
+            storage : S::default()
+        }
+    }
+    pub fn directed() -> Self{
+        Self::new(Direction::Directed)
+    }
+    pub fn undirected() -> Self{
+        Self::new(Direction::Undirected)
+    }
+    pub fn direction(&self) -> Direction{
+        self.direction
+    }
+    pub fn is_directed(&self) -> bool{
+        self.direction == Direction::Directed
+    }
+    pub fn node_count(&self) -> usize{
+        self.node_count
+    }
+    pub fn edge_count(&self) -> usize{
+        self.edge_count
+    }
+    ///Cota superior (exclusiva) de los indices de nodo, incluye a los eliminados.
+    pub fn node_bound(&self) -> usize{
+        self.nodes.len()
+    }
+    ///Cota superior (exclusiva) de los indices de arista, incluye a las eliminadas.
+    pub fn edge_bound(&self) -> usize{
+        self.edges.len()
+    }
+    pub fn add_node(&mut self , weight : N) -> NodeId{
+        self.nodes.push(Some(weight));
+        self.in_degree.push(0);
+        self.storage.add_node();
+        self.node_count += 1;
+        NodeId(self.nodes.len() - 1)
+    }
+    pub fn contains_node(&self , node : NodeId) -> bool{
+        self.node(node).is_some()
+    }
+    pub fn node(&self , node : NodeId) -> Option<&N>{
+        self.nodes.get(node.0).and_then(Option::as_ref)
+    }
+    pub fn node_mut(&mut self , node : NodeId) -> Option<&mut N>{
+        self.nodes.get_mut(node.0).and_then(Option::as_mut)
+    }
+    ///Elimina el nodo y todas sus aristas, recorre la arena de aristas en O(E).
+    pub fn remove_node(&mut self , node : NodeId) -> Option<N>{
+        let weight: N = self.nodes.get_mut(node.0)?.take()?;
+        let incident: Vec<EdgeId> = self.edge_ids()
+            .filter(|&edge| self.edge_endpoints(edge).is_some_and(|(from, to)| from == node || to == node))
+            .collect();
+        for edge in incident{
+            self.remove_edge(edge);
+        }
+        self.node_count -= 1;
+        Some(weight)
+    }
+    ///Agrega la arista `from -> to` (o `from -- to` si no es dirigido), retorna error si algún nodo no existe o si la
+    ///representación no la admite.
+    pub fn add_edge(&mut self , from : NodeId , to : NodeId , weight : E) -> Result<EdgeId, String>{
+        if !self.contains_node(from) || !self.contains_node(to){
+            return Err(format!("la arista {:?} -> {:?} usa un nodo inexistente", from, to));
+        }
+        let edge: EdgeId = EdgeId(self.edges.len());
+        self.storage.add_edge(from, to, edge)?;
+        if !self.is_directed() && from != to{
+            if let Err(error) = self.storage.add_edge(to, from, edge){
+                self.storage.remove_edge(from, to, edge);
+                return Err(error);
+            }
+        }
+        self.edges.push(Some(EdgeEntry{ from, to, weight }));
+        self.in_degree[to.0] += 1;
+        self.edge_count += 1;
+        Ok(edge)
+    }
+    pub fn remove_edge(&mut self , edge : EdgeId) -> Option<E>{
+        let entry: EdgeEntry<E> = self.edges.get_mut(edge.0)?.take()?;
+        self.storage.remove_edge(entry.from, entry.to, edge);
+        if !self.is_directed() && entry.from != entry.to{
+            self.storage.remove_edge(entry.to, entry.from, edge);
+        }
+        self.in_degree[entry.to.0] -= 1;
+        self.edge_count -= 1;
+        Some(entry.weight)
+    }
+    pub fn edge(&self , edge : EdgeId) -> Option<&E>{
+        self.edges.get(edge.0).and_then(Option::as_ref).map(|entry| &entry.weight)
+    }
+    pub fn edge_mut(&mut self , edge : EdgeId) -> Option<&mut E>{
+        self.edges.get_mut(edge.0).and_then(Option::as_mut).map(|entry| &mut entry.weight)
+    }
+    ///Extremos `(origen, destino)` tal como se agregó la arista.
+    pub fn edge_endpoints(&self , edge : EdgeId) -> Option<(NodeId, NodeId)>{
+        self.edges.get(edge.0).and_then(Option::as_ref).map(|entry| (entry.from, entry.to))
+    }
+    ///Alguna arista `from -> to`, en un grafo no dirigido también encuentra `to -- from`.
+    pub fn find_edge(&self , from : NodeId , to : NodeId) -> Option<EdgeId>{
+        if !self.contains_node(from) || !self.contains_node(to){
+            return None;
+        }
+        self.storage.find_edge(from, to)
+    }
+    pub fn node_ids(&self) -> impl Iterator<Item = NodeId> + '_{
+        self.nodes.iter().enumerate().filter(|(_, node)| node.is_some()).map(|(index, _)| NodeId(index))
+    }
+    pub fn edge_ids(&self) -> impl Iterator<Item = EdgeId> + '_{
+        self.edges.iter().enumerate().filter(|(_, edge)| edge.is_some()).map(|(index, _)| EdgeId(index))
+    }
+    ///Pares `(vecino, arista)` alcanzables desde `node` por una arista.
+    pub fn neighbors(&self , node : NodeId) -> S::Neighbors<'_>{
+        self.storage.neighbors(node)
+    }
+    ///Triplas `(vecino, arista, dato)` alcanzables desde `node`.
+    pub fn edges(&self , node : NodeId) -> impl Iterator<Item = (NodeId, EdgeId, &E)> + '_{
+        self.neighbors(node).map(move |(neighbor, edge)| (neighbor, edge, self.edge(edge).expect("la adyacencia solo guarda aristas vivas")))
+    }
+    ///Aristas que salen del nodo, en un grafo no dirigido es igual a `degree` salvo por los lazos.
+    pub fn out_degree(&self , node : NodeId) -> usize{
+        self.neighbors(node).count()
+    }
+    ///Aristas que llegan al nodo, en un grafo no dirigido es igual a `out_degree`.
+    pub fn in_degree(&self , node : NodeId) -> usize{
+        match self.direction{
+            Direction::Directed => self.in_degree.get(node.0).copied().unwrap_or(0),
+            Direction::Undirected => self.out_degree(node)
+        }
+    }
+    ///Extremos de arista que tocan al nodo: en un grafo dirigido `in + out`, en uno no dirigido un lazo cuenta dos veces.
+    pub fn degree(&self , node : NodeId) -> usize{
+        match self.direction{
+            Direction::Directed => self.in_degree(node) + self.out_degree(node),
+            Direction::Undirected => self.neighbors(node).map(|(neighbor, _)| if neighbor == node { 2 } else { 1 }).sum()
+        }
+    }
+}
+impl<N : Debug, E : Debug, S : GraphStorage> Debug for Graph<N, E, S>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_struct("Graph")
+            .field("direction", &self.direction)
+            .field("nodes", &self.node_ids().map(|node| (node, self.node(node).expect("id vivo"))).collect::<Vec<_>>())
+            .field("edges", &self.edges.iter().flatten().map(|entry| (entry.from, entry.to, &entry.weight)).collect::<Vec<_>>())
+            .finish()
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::no_lineal::safety::adjacency_matrix::AdjacencyMatrix;
+
+    fn sorted_neighbors<S : GraphStorage>(graph : &Graph<&str, u32, S> , node : NodeId) -> Vec<NodeId>{
+        let mut neighbors: Vec<NodeId> = graph.neighbors(node).map(|(neighbor, _)| neighbor).collect();
+        neighbors.sort();
+        neighbors
+    }
+    fn directed_graph<S : GraphStorage>(){
+        let mut graph: Graph<&str, u32, S> = Graph::directed();
+        let a: NodeId = graph.add_node("a");
+        let b: NodeId = graph.add_node("b");
+        let c: NodeId = graph.add_node("c");
+        let ab: EdgeId = graph.add_edge(a, b, 1).unwrap();
+        graph.add_edge(b, c, 2).unwrap();
+        let ca: EdgeId = graph.add_edge(c, a, 3).unwrap();
+        graph.add_edge(a, c, 4).unwrap();
+        assert_eq!((graph.node_count(), graph.edge_count()) , (3, 4));
+        assert_eq!(sorted_neighbors(&graph, a) , vec![b, c]);
+        assert_eq!((graph.out_degree(a), graph.in_degree(a), graph.degree(a)) , (2, 1, 3));
+        assert_eq!(graph.find_edge(a, b) , Some(ab));
+        assert_eq!(graph.find_edge(b, a) , None);
+        assert_eq!(graph.edge_endpoints(ca) , Some((c, a)));
+        *graph.edge_mut(ab).unwrap() += 10;
+        assert_eq!(graph.remove_edge(ab) , Some(11));
+        assert_eq!(graph.remove_edge(ab) , None);
+        assert_eq!(graph.in_degree(b) , 0);
+        //Eliminar un nodo borra sus aristas pero no cambia los IDs del resto
+        assert_eq!(graph.remove_node(c) , Some("c"));
+        assert_eq!((graph.node_count(), graph.edge_count()) , (2, 0));
+        assert_eq!(graph.out_degree(a) + graph.out_degree(b) , 0);
+        let d: NodeId = graph.add_node("d");
+        assert_ne!(d , c);
+        assert_eq!(graph.node(b) , Some(&"b"));
+        assert_eq!(graph.node(c) , None);
+        assert!(graph.add_edge(a, c, 0).is_err());
+        assert_eq!(graph.node_ids().collect::<Vec<NodeId>>() , vec![a, b, d]);
+    }
+    fn undirected_graph<S : GraphStorage>(){
+        let mut graph: Graph<&str, u32, S> = Graph::undirected();
+        let a: NodeId = graph.add_node("a");
+        let b: NodeId = graph.add_node("b");
+        let c: NodeId = graph.add_node("c");
+        let ab: EdgeId = graph.add_edge(a, b, 5).unwrap();
+        graph.add_edge(b, c, 6).unwrap();
+        graph.add_edge(c, c, 7).unwrap();
+        assert_eq!(sorted_neighbors(&graph, b) , vec![a, c]);
+        assert_eq!(graph.find_edge(b, a) , Some(ab));
+        assert_eq!((graph.degree(b), graph.in_degree(b), graph.out_degree(b)) , (2, 2, 2));
+        //El lazo cuenta dos veces en el grado
+        assert_eq!(graph.degree(c) , 3);
+        let weights: u32 = graph.edges(c).map(|(_, _, weight)| *weight).sum();
+        assert_eq!(weights , 13);
+        graph.remove_edge(ab);
+        assert_eq!(sorted_neighbors(&graph, a) , vec![]);
+        assert_eq!(sorted_neighbors(&graph, b) , vec![c]);
+        graph.remove_node(c);
+        assert_eq!(graph.degree(b) , 0);
+        assert_eq!(graph.edge_count() , 0);
+    }
+    #[test]
+    fn adjacency_list_backend(){
+        directed_graph::<AdjacencyList>();
+        undirected_graph::<AdjacencyList>();
+        //La lista admite aristas paralelas
+        let mut graph: Graph<(), u8> = Graph::directed();
+        let a: NodeId = graph.add_node(());
+        let b: NodeId = graph.add_node(());
+        graph.add_edge(a, b, 1).unwrap();
+        graph.add_edge(a, b, 2).unwrap();
+        assert_eq!(graph.out_degree(a) , 2);
+    }
+    #[test]
+    fn adjacency_matrix_backend(){
+        directed_graph::<AdjacencyMatrix>();
+        undirected_graph::<AdjacencyMatrix>();
+        let mut graph: Graph<(), u8, AdjacencyMatrix> = Graph::undirected();
+        let a: NodeId = graph.add_node(());
+        let b: NodeId = graph.add_node(());
+        graph.add_edge(a, b, 1).unwrap();
+        assert!(graph.add_edge(b, a, 2).is_err());
+        assert_eq!(graph.edge_count() , 1);
+    }
+}
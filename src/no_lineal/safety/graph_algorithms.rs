@@ -0,0 +1,621 @@
+//! ### Algoritmos estructurales sobre grafos
+//! - Orden topológico: Kahn (por grados de entrada con la `Queue` del crate) y DFS (post orden invertido), ambos
+//!   reportan un ciclo cuando el grafo no es aciclico.
+//! - Componentes fuertemente conexos: Tarjan (una pasada con `low links`) y Kosaraju (dos pasadas, la segunda sobre el
+//!   grafo transpuesto).
+//! - Arbol de expansión mínima: Kruskal (aristas ordenadas más `DisjointSet`) y Prim (`IndexedHeap` con
+//!   `decrease_key`), en grafos no conexos producen un bosque.
+//! - Puntos de articulación y puentes: nodos y aristas cuya eliminación desconecta su componente.
+//! - Bipartición: 2-coloreo por BFS, si falla retorna un ciclo impar.
+//! ```text
+//!    low link : menor tiempo de descubrimiento alcanzable bajando por el arbol y subiendo por una arista de retroceso
+//!
+//!      1 ─── 2          disc : 1 2 3 4     la arista 3 ── 4 es puente porque low(4) = 4 > disc(3) = 3
+//!       \   /           low  : 1 1 1 4     el nodo 3 es articulación porque low(4) >= disc(3)
+//!         3 ─── 4
+//! ```
+//! Los ciclos se retornan como la secuencia de nodos en orden de recorrido, existe una arista del ultimo al primero.
+//! En un grafo no dirigido cada arista ya es un ciclo de dos nodos para el orden topológico, y los componentes
+//! fuertemente conexos coinciden con los componentes conexos.
+use num::Zero;
+use crate::lineal::safety::queue::Queue;
+use crate::lineal::safety::stack::Stack;
+use super::binary_heap::{Handle, IndexedHeap};
+use super::disjoint_set::DisjointSet;
+use super::graph::{EdgeId, Graph, GraphStorage, NodeId};
+use super::graph_traversal::{depth_first_forest, depth_first_visit, DfsEvent};
+
+///Aristas que llegan a cada nodo, en un grafo no dirigido coincide con la adyacencia.
+fn incoming_edges<N, E, S : GraphStorage>(graph : &Graph<N, E, S>) -> Vec<Vec<(NodeId, EdgeId)>>{
+    let mut incoming: Vec<Vec<(NodeId, EdgeId)>> = vec![Vec::new(); graph.node_bound()];
+    for node in graph.node_ids(){
+        for (next, edge) in graph.neighbors(node){
+            incoming[next.index()].push((node, edge));
+        }
+    }
+    incoming
+}
+///En un grafo no dirigido la primera arista ya es un ciclo.
+fn undirected_cycle<N, E, S : GraphStorage>(graph : &Graph<N, E, S>) -> Option<Vec<NodeId>>{
+    if graph.is_directed(){
+        return None;
+    }
+    let (from, to) = graph.edge_endpoints(graph.edge_ids().next()?)?;
+    Some(if from == to { vec![from] } else { vec![from, to] })
+}
+///### Kahn
+/// Orden topológico retirando repetidamente los nodos sin aristas de entrada, O(V + E).
+/// # Errores
+/// Un ciclo formado por nodos que nunca llegaron a grado de entrada cero.
+pub fn topological_sort_kahn<N, E, S : GraphStorage>(graph : &Graph<N, E, S>) -> Result<Vec<NodeId>, Vec<NodeId>>{
+    if let Some(cycle) = undirected_cycle(graph){
+        return Err(cycle);
+    }
+    let mut pending: Vec<usize> = vec![0; graph.node_bound()];
+    let mut queue: Queue<NodeId> = Queue::new();
+    for node in graph.node_ids(){
+        pending[node.index()] = graph.in_degree(node);
+        if pending[node.index()] == 0{
+            queue.push_back(node);
+        }
+    }
+    let mut order: Vec<NodeId> = Vec::with_capacity(graph.node_count());
+    while let Some(node) = queue.pop_front(){
+        order.push(node);
+        for (next, _) in graph.neighbors(node){
+            pending[next.index()] -= 1;
+            if pending[next.index()] == 0{
+                queue.push_back(next);
+            }
+        }
+    }
+    if order.len() == graph.node_count(){
+        Ok(order)
+    }else{
+        Err(pending_cycle(graph, &pending))
+    }
+}
+///Todo nodo con entradas pendientes tiene un predecesor que también quedó pendiente, retroceder por ellos termina
+///repitiendo un nodo y el tramo entre ambas visitas es un ciclo.
+fn pending_cycle<N, E, S : GraphStorage>(graph : &Graph<N, E, S> , pending : &[usize]) -> Vec<NodeId>{
+    let mut predecessor: Vec<Option<NodeId>> = vec![None; graph.node_bound()];
+    for node in graph.node_ids().filter(|node| pending[node.index()] > 0){
+        for (next, _) in graph.neighbors(node){
+            if pending[next.index()] > 0{
+                predecessor[next.index()] = Some(node);
+            }
+        }
+    }
+    let mut position: Vec<Option<usize>> = vec![None; graph.node_bound()];
+    let mut walk: Vec<NodeId> = Vec::new();
+    let mut current: NodeId = graph.node_ids().find(|node| pending[node.index()] > 0).expect("hay nodos pendientes");
+    while position[current.index()].is_none(){
+        position[current.index()] = Some(walk.len());
+        walk.push(current);
+        current = predecessor[current.index()].expect("un nodo pendiente tiene un predecesor pendiente");
+    }
+    let mut cycle: Vec<NodeId> = walk.split_off(position[current.index()].expect("el nodo repetido ya fue visitado"));
+    cycle.reverse();
+    cycle
+}
+///### Orden topológico por DFS
+/// El post orden invertido de un DFS completo es topológico si no aparece ninguna arista de retroceso, O(V + E).
+/// # Errores
+/// El ciclo cerrado por la primera arista de retroceso encontrada.
+pub fn topological_sort_dfs<N, E, S : GraphStorage>(graph : &Graph<N, E, S>) -> Result<Vec<NodeId>, Vec<NodeId>>{
+    if let Some(cycle) = undirected_cycle(graph){
+        return Err(cycle);
+    }
+    let mut parent: Vec<Option<NodeId>> = vec![None; graph.node_bound()];
+    let mut postorder: Vec<NodeId> = Vec::with_capacity(graph.node_count());
+    let mut cycle: Option<Vec<NodeId>> = None;
+    depth_first_visit(graph, graph.node_ids(), |event| match event{
+        DfsEvent::TreeEdge(from, to, _) => parent[to.index()] = Some(from),
+        DfsEvent::BackEdge(from, to, _) if cycle.is_none() => {
+            //`to` es un ancestro de `from` en la pila, subir por los padres recupera el tramo del ciclo
+            let mut nodes: Vec<NodeId> = vec![from];
+            let mut current: NodeId = from;
+            while current != to{
+                current = parent[current.index()].expect("el destino de una arista de retroceso es un ancestro");
+                nodes.push(current);
+            }
+            nodes.reverse();
+            cycle = Some(nodes);
+        },
+        DfsEvent::Finish(node) => postorder.push(node),
+        _ => {}
+    });
+    match cycle{
+        Some(cycle) => Err(cycle),
+        None => {
+            postorder.reverse();
+            Ok(postorder)
+        }
+    }
+}
+///### Componentes conexos
+/// Agrupa los nodos unidos por alguna arista sin importar su dirección (componentes débiles en un grafo dirigido).
+/// Los componentes aparecen en el orden de su primer nodo.
+pub fn connected_components<N, E, S : GraphStorage>(graph : &Graph<N, E, S>) -> Vec<Vec<NodeId>>{
+    let mut sets: DisjointSet = DisjointSet::new(graph.node_bound());
+    for edge in graph.edge_ids(){
+        let (from, to) = graph.edge_endpoints(edge).expect("id vivo");
+        sets.union(from.index(), to.index());
+    }
+    let mut slot: Vec<Option<usize>> = vec![None; graph.node_bound()];
+    let mut components: Vec<Vec<NodeId>> = Vec::new();
+    for node in graph.node_ids(){
+        let root: usize = sets.find(node.index());
+        let index: usize = *slot[root].get_or_insert_with(|| {
+            components.push(Vec::new());
+            components.len() - 1
+        });
+        components[index].push(node);
+    }
+    components
+}
+///### Tarjan
+/// Componentes fuertemente conexos en una sola pasada DFS, O(V + E). Cada nodo se apila al descubrirse y un componente
+/// se desapila completo cuando su raíz termina con `low == disc`. Los componentes salen en orden topológico inverso
+/// del grafo de componentes.
+pub fn tarjan_scc<N, E, S : GraphStorage>(graph : &Graph<N, E, S>) -> Vec<Vec<NodeId>>{
+    if !graph.is_directed(){
+        return connected_components(graph);
+    }
+    let mut discovery: Vec<usize> = vec![0; graph.node_bound()];
+    let mut low: Vec<usize> = vec![0; graph.node_bound()];
+    let mut parent: Vec<Option<NodeId>> = vec![None; graph.node_bound()];
+    let mut on_stack: Vec<bool> = vec![false; graph.node_bound()];
+    let mut stack: Stack<NodeId> = Stack::new();
+    let mut time: usize = 0;
+    let mut components: Vec<Vec<NodeId>> = Vec::new();
+    depth_first_visit(graph, graph.node_ids(), |event| match event{
+        DfsEvent::Discover(node) => {
+            discovery[node.index()] = time;
+            low[node.index()] = time;
+            time += 1;
+            stack.push(node);
+            on_stack[node.index()] = true;
+        },
+        DfsEvent::TreeEdge(from, to, _) => parent[to.index()] = Some(from),
+        DfsEvent::BackEdge(from, to, _) | DfsEvent::CrossEdge(from, to, _) => {
+            if on_stack[to.index()]{
+                low[from.index()] = low[from.index()].min(discovery[to.index()]);
+            }
+        },
+        DfsEvent::Finish(node) => {
+            if low[node.index()] == discovery[node.index()]{
+                let mut component: Vec<NodeId> = Vec::new();
+                while let Some(member) = stack.pop(){
+                    on_stack[member.index()] = false;
+                    component.push(member);
+                    if member == node{
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+            if let Some(parent) = parent[node.index()]{
+                low[parent.index()] = low[parent.index()].min(low[node.index()]);
+            }
+        }
+    });
+    components
+}
+///### Kosaraju
+/// Componentes fuertemente conexos con dos pasadas, O(V + E): un DFS completo para el post orden y luego, en orden
+/// inverso de termino, un recorrido por las aristas de entrada que encierra exactamente un componente. Los componentes
+/// salen en orden topológico del grafo de componentes.
+pub fn kosaraju_scc<N, E, S : GraphStorage>(graph : &Graph<N, E, S>) -> Vec<Vec<NodeId>>{
+    if !graph.is_directed(){
+        return connected_components(graph);
+    }
+    let finished: Vec<NodeId> = depth_first_forest(graph).postorder;
+    let incoming: Vec<Vec<(NodeId, EdgeId)>> = incoming_edges(graph);
+    let mut assigned: Vec<bool> = vec![false; graph.node_bound()];
+    let mut stack: Stack<NodeId> = Stack::new();
+    let mut components: Vec<Vec<NodeId>> = Vec::new();
+    for &root in finished.iter().rev(){
+        if assigned[root.index()]{
+            continue;
+        }
+        let mut component: Vec<NodeId> = Vec::new();
+        assigned[root.index()] = true;
+        stack.push(root);
+        while let Some(node) = stack.pop(){
+            component.push(node);
+            for &(previous, _) in incoming[node.index()].iter(){
+                if !assigned[previous.index()]{
+                    assigned[previous.index()] = true;
+                    stack.push(previous);
+                }
+            }
+        }
+        components.push(component);
+    }
+    components
+}
+///Bosque de expansión y la suma de sus pesos.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanningTree<W>{
+    pub weight : W,
+    pub edges : Vec<EdgeId>
+}
+///### Kruskal
+/// Recorre las aristas de menor a mayor peso y conserva las que unen dos componentes distintos, O(E log E).
+/// # Errores
+/// Si el grafo es dirigido.
+pub fn kruskal<N, E, S, W, F>(graph : &Graph<N, E, S> , weight : F) -> Result<SpanningTree<W>, String>
+where S : GraphStorage , W : Copy + Ord + Zero , F : Fn(&E) -> W{
+    if graph.is_directed(){
+        return Err(String::from("El arbol de expansión mínima solo se define en grafos no dirigidos"));
+    }
+    let mut edges: Vec<(W, EdgeId)> = graph.edge_ids().map(|edge| (weight(graph.edge(edge).expect("id vivo")), edge)).collect();
+    edges.sort();
+    let mut sets: DisjointSet = DisjointSet::new(graph.node_bound());
+    let mut tree: SpanningTree<W> = SpanningTree{ weight : W::zero(), edges : Vec::new() };
+    for (cost, edge) in edges{
+        let (from, to) = graph.edge_endpoints(edge).expect("id vivo");
+        if sets.union(from.index(), to.index()){
+            tree.weight = tree.weight + cost;
+            tree.edges.push(edge);
+        }
+    }
+    Ok(tree)
+}
+///### Prim
+/// Hace crecer el arbol desde cada nodo no alcanzado tomando siempre la arista más liviana que sale de él,
+/// O((V + E) log V) con `decrease_key`.
+/// # Errores
+/// Si el grafo es dirigido.
+pub fn prim<N, E, S, W, F>(graph : &Graph<N, E, S> , weight : F) -> Result<SpanningTree<W>, String>
+where S : GraphStorage , W : Copy + Ord + Zero , F : Fn(&E) -> W{
+    if graph.is_directed(){
+        return Err(String::from("El arbol de expansión mínima solo se define en grafos no dirigidos"));
+    }
+    let mut heap: IndexedHeap<(W, NodeId)> = IndexedHeap::new_min();
+    let mut handles: Vec<Option<Handle>> = vec![None; graph.node_bound()];
+    let mut best: Vec<Option<(W, EdgeId)>> = vec![None; graph.node_bound()];
+    let mut in_tree: Vec<bool> = vec![false; graph.node_bound()];
+    let mut tree: SpanningTree<W> = SpanningTree{ weight : W::zero(), edges : Vec::new() };
+    for root in graph.node_ids(){
+        if in_tree[root.index()]{
+            continue;
+        }
+        handles[root.index()] = Some(heap.push((W::zero(), root)));
+        while let Some((_, (_, node))) = heap.pop(){
+            handles[node.index()] = None;
+            in_tree[node.index()] = true;
+            if let Some((cost, edge)) = best[node.index()]{
+                tree.weight = tree.weight + cost;
+                tree.edges.push(edge);
+            }
+            for (next, edge, data) in graph.edges(node){
+                let cost: W = weight(data);
+                if in_tree[next.index()] || best[next.index()].is_some_and(|(current, _)| current <= cost){
+                    continue;
+                }
+                best[next.index()] = Some((cost, edge));
+                match handles[next.index()]{
+                    Some(handle) => heap.decrease_key(handle, (cost, next))?,
+                    None => handles[next.index()] = Some(heap.push((cost, next)))
+                }
+            }
+        }
+    }
+    Ok(tree)
+}
+///Puntos de articulación y puentes calculados en la misma pasada DFS.
+fn low_links<N, E, S : GraphStorage>(graph : &Graph<N, E, S>) -> Result<(Vec<NodeId>, Vec<EdgeId>), String>{
+    if graph.is_directed(){
+        return Err(String::from("Los puntos de articulación y los puentes solo se definen en grafos no dirigidos"));
+    }
+    let mut discovery: Vec<usize> = vec![0; graph.node_bound()];
+    let mut low: Vec<usize> = vec![0; graph.node_bound()];
+    let mut parent: Vec<Option<(NodeId, EdgeId)>> = vec![None; graph.node_bound()];
+    let mut children: Vec<usize> = vec![0; graph.node_bound()];
+    let mut articulation: Vec<bool> = vec![false; graph.node_bound()];
+    let mut bridges: Vec<EdgeId> = Vec::new();
+    let mut time: usize = 0;
+    depth_first_visit(graph, graph.node_ids(), |event| match event{
+        DfsEvent::Discover(node) => {
+            discovery[node.index()] = time;
+            low[node.index()] = time;
+            time += 1;
+        },
+        DfsEvent::TreeEdge(from, to, edge) => {
+            parent[to.index()] = Some((from, edge));
+            children[from.index()] += 1;
+        },
+        DfsEvent::BackEdge(from, to, _) => low[from.index()] = low[from.index()].min(discovery[to.index()]),
+        DfsEvent::Finish(node) => match parent[node.index()]{
+            Some((above, edge)) => {
+                low[above.index()] = low[above.index()].min(low[node.index()]);
+                if low[node.index()] > discovery[above.index()]{
+                    bridges.push(edge);
+                }
+                //La raíz se decide por su cantidad de hijos
+                if parent[above.index()].is_some() && low[node.index()] >= discovery[above.index()]{
+                    articulation[above.index()] = true;
+                }
+            },
+            None => articulation[node.index()] = children[node.index()] >= 2
+        },
+        DfsEvent::CrossEdge(..) => {}
+    });
+    let points: Vec<NodeId> = graph.node_ids().filter(|node| articulation[node.index()]).collect();
+    Ok((points, bridges))
+}
+///### Puntos de articulación
+/// Nodos cuya eliminación aumenta la cantidad de componentes conexos, O(V + E).
+/// # Errores
+/// Si el grafo es dirigido.
+pub fn articulation_points<N, E, S : GraphStorage>(graph : &Graph<N, E, S>) -> Result<Vec<NodeId>, String>{
+    low_links(graph).map(|(points, _)| points)
+}
+///### Puentes
+/// Aristas cuya eliminación aumenta la cantidad de componentes conexos, O(V + E). Una arista con una paralela nunca
+/// es puente.
+/// # Errores
+/// Si el grafo es dirigido.
+pub fn bridges<N, E, S : GraphStorage>(graph : &Graph<N, E, S>) -> Result<Vec<EdgeId>, String>{
+    low_links(graph).map(|(_, bridges)| bridges)
+}
+///Los dos lados de un grafo bipartito, toda arista une un nodo de `left` con uno de `right`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bipartition{
+    pub left : Vec<NodeId>,
+    pub right : Vec<NodeId>
+}
+///### Bipartición
+/// Colorea cada componente por BFS alternando lados, la dirección de las aristas se ignora. O(V + E).
+/// # Errores
+/// Un ciclo de largo impar, el cual impide cualquier bipartición.
+pub fn bipartition<N, E, S : GraphStorage>(graph : &Graph<N, E, S>) -> Result<Bipartition, Vec<NodeId>>{
+    let incoming: Vec<Vec<(NodeId, EdgeId)>> = if graph.is_directed() { incoming_edges(graph) } else { vec![Vec::new(); graph.node_bound()] };
+    let mut side: Vec<Option<bool>> = vec![None; graph.node_bound()];
+    let mut parent: Vec<Option<NodeId>> = vec![None; graph.node_bound()];
+    let mut queue: Queue<NodeId> = Queue::new();
+    for root in graph.node_ids(){
+        if side[root.index()].is_some(){
+            continue;
+        }
+        side[root.index()] = Some(false);
+        queue.push_back(root);
+        while let Some(node) = queue.pop_front(){
+            let color: Option<bool> = side[node.index()];
+            for (next, _) in graph.neighbors(node).chain(incoming[node.index()].iter().copied()){
+                match side[next.index()]{
+                    None => {
+                        side[next.index()] = color.map(|color| !color);
+                        parent[next.index()] = Some(node);
+                        queue.push_back(next);
+                    },
+                    Some(other) if Some(other) == color => return Err(odd_cycle(&parent, node, next)),
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+    let (left, right): (Vec<NodeId>, Vec<NodeId>) = graph.node_ids().partition(|node| side[node.index()] == Some(false));
+    Ok(Bipartition{ left, right })
+}
+///En BFS una arista entre nodos del mismo color une dos nodos de igual profundidad, subiendo a la vez desde ambos
+///se llega al ancestro común y los dos tramos más la arista forman un ciclo impar.
+fn odd_cycle(parent : &[Option<NodeId>] , first : NodeId , second : NodeId) -> Vec<NodeId>{
+    if first == second{
+        return vec![first];
+    }
+    let (mut up_first, mut up_second): (Vec<NodeId>, Vec<NodeId>) = (vec![first], vec![second]);
+    let (mut current_first, mut current_second): (NodeId, NodeId) = (first, second);
+    while current_first != current_second{
+        current_first = parent[current_first.index()].expect("ambos nodos tienen la misma profundidad");
+        current_second = parent[current_second.index()].expect("ambos nodos tienen la misma profundidad");
+        up_first.push(current_first);
+        if current_first != current_second{
+            up_second.push(current_second);
+        }
+    }
+    up_first.reverse();
+    up_first.extend(up_second);
+    up_first
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::no_lineal::safety::adjacency_matrix::AdjacencyMatrix;
+    use crate::no_lineal::safety::graph_traversal::{breadth_first, BreadthFirst};
+    use crate::random::XorShift64;
+
+    fn random_graph(seed : u64 , nodes : usize , edges : usize , directed : bool) -> (Graph<usize, u32>, Vec<NodeId>){
+        let mut rng: XorShift64 = XorShift64::new(seed);
+        let mut graph: Graph<usize, u32> = if directed { Graph::directed() } else { Graph::undirected() };
+        let ids: Vec<NodeId> = (0..nodes).map(|value| graph.add_node(value)).collect();
+        for _ in 0..edges{
+            let from: NodeId = ids[rng.next_u64() as usize % nodes];
+            let to: NodeId = ids[rng.next_u64() as usize % nodes];
+            graph.add_edge(from, to, (rng.next_u64() % 50) as u32).unwrap();
+        }
+        (graph, ids)
+    }
+    fn assert_cycle<N, E, S : GraphStorage>(graph : &Graph<N, E, S> , cycle : &[NodeId]){
+        assert!(!cycle.is_empty());
+        for (index, &node) in cycle.iter().enumerate(){
+            assert!(graph.find_edge(node, cycle[(index + 1) % cycle.len()]).is_some());
+        }
+    }
+    fn normalized(mut components : Vec<Vec<NodeId>>) -> Vec<Vec<NodeId>>{
+        for component in components.iter_mut(){
+            component.sort();
+        }
+        components.sort();
+        components
+    }
+    #[test]
+    fn topological_orders(){
+        let mut rng: XorShift64 = XorShift64::new(3);
+        let mut graph: Graph<usize, ()> = Graph::directed();
+        let ids: Vec<NodeId> = (0..40).map(|value| graph.add_node(value)).collect();
+        //Las aristas van de un valor menor a uno mayor, así el grafo es aciclico
+        for _ in 0..120{
+            let (a, b) = (rng.next_u64() as usize % 40, rng.next_u64() as usize % 40);
+            if a != b{
+                graph.add_edge(ids[a.min(b)], ids[a.max(b)], ()).unwrap();
+            }
+        }
+        for order in [topological_sort_kahn(&graph).unwrap(), topological_sort_dfs(&graph).unwrap()]{
+            let mut position: Vec<usize> = vec![0; graph.node_bound()];
+            for (index, node) in order.iter().enumerate(){
+                position[node.index()] = index;
+            }
+            assert_eq!(order.len() , 40);
+            for edge in graph.edge_ids(){
+                let (from, to) = graph.edge_endpoints(edge).unwrap();
+                assert!(position[from.index()] < position[to.index()]);
+            }
+        }
+        //Una arista de regreso cierra un ciclo
+        graph.add_edge(ids[0], ids[39], ()).unwrap();
+        let closing: EdgeId = graph.add_edge(ids[39], ids[0], ()).unwrap();
+        assert_cycle(&graph, &topological_sort_kahn(&graph).unwrap_err());
+        assert_cycle(&graph, &topological_sort_dfs(&graph).unwrap_err());
+        graph.remove_edge(closing);
+        let lonely: NodeId = graph.add_node(40);
+        graph.add_edge(lonely, lonely, ()).unwrap();
+        assert_eq!(topological_sort_kahn(&graph).unwrap_err() , vec![lonely]);
+        assert_eq!(topological_sort_dfs(&graph).unwrap_err() , vec![lonely]);
+        let (undirected, _) = random_graph(1, 5, 3, false);
+        assert_cycle(&undirected, &topological_sort_kahn(&undirected).unwrap_err());
+    }
+    #[test]
+    fn strongly_connected_components_match_reachability(){
+        for seed in 1..=10{
+            let (graph, ids) = random_graph(seed, 25, 35, true);
+            let reach: Vec<BreadthFirst> = ids.iter().map(|&node| breadth_first(&graph, node)).collect();
+            let mut expected: Vec<Vec<NodeId>> = Vec::new();
+            let mut assigned: Vec<bool> = vec![false; ids.len()];
+            for (index, &node) in ids.iter().enumerate(){
+                if assigned[index]{
+                    continue;
+                }
+                let component: Vec<NodeId> = ids.iter().copied()
+                    .filter(|other| reach[index].reached(*other) && reach[other.index()].reached(node))
+                    .collect();
+                for member in component.iter(){
+                    assigned[member.index()] = true;
+                }
+                expected.push(component);
+            }
+            let expected: Vec<Vec<NodeId>> = normalized(expected);
+            let tarjan: Vec<Vec<NodeId>> = tarjan_scc(&graph);
+            let kosaraju: Vec<Vec<NodeId>> = kosaraju_scc(&graph);
+            //Kosaraju sale en orden topológico y Tarjan en el inverso: ninguna arista retrocede de componente
+            for (components, forward) in [(&kosaraju, true), (&tarjan, false)]{
+                let mut position: Vec<usize> = vec![0; ids.len()];
+                for (index, component) in components.iter().enumerate(){
+                    for node in component{
+                        position[node.index()] = index;
+                    }
+                }
+                for edge in graph.edge_ids(){
+                    let (from, to) = graph.edge_endpoints(edge).unwrap();
+                    let (from, to) = (position[from.index()], position[to.index()]);
+                    assert!(if forward { from <= to } else { from >= to });
+                }
+            }
+            assert_eq!(normalized(tarjan) , expected);
+            assert_eq!(normalized(kosaraju) , expected);
+        }
+        let (undirected, _) = random_graph(4, 20, 12, false);
+        assert_eq!(normalized(tarjan_scc(&undirected)) , normalized(connected_components(&undirected)));
+    }
+    #[test]
+    fn spanning_trees_agree(){
+        for seed in 1..=10{
+            let (graph, _) = random_graph(seed, 30, 60, false);
+            let kruskal_tree: SpanningTree<u32> = kruskal(&graph, |weight| *weight).unwrap();
+            let prim_tree: SpanningTree<u32> = prim(&graph, |weight| *weight).unwrap();
+            assert_eq!(kruskal_tree.weight , prim_tree.weight);
+            let forest_edges: usize = graph.node_count() - connected_components(&graph).len();
+            assert_eq!(kruskal_tree.edges.len() , forest_edges);
+            assert_eq!(prim_tree.edges.len() , forest_edges);
+            let total: u32 = prim_tree.edges.iter().map(|&edge| *graph.edge(edge).unwrap()).sum();
+            assert_eq!(total , prim_tree.weight);
+        }
+        let (directed, _) = random_graph(1, 4, 4, true);
+        assert!(kruskal(&directed, |weight| *weight).is_err());
+        assert!(prim(&directed, |weight| *weight).is_err());
+    }
+    ///Componentes conexos ignorando un nodo o una arista.
+    fn components_without(graph : &Graph<usize, u32> , skip_node : Option<NodeId> , skip_edge : Option<EdgeId>) -> usize{
+        let mut seen: Vec<bool> = vec![false; graph.node_bound()];
+        let mut count: usize = 0;
+        for root in graph.node_ids().filter(|&node| Some(node) != skip_node){
+            if seen[root.index()]{
+                continue;
+            }
+            count += 1;
+            seen[root.index()] = true;
+            let mut pending: Vec<NodeId> = vec![root];
+            while let Some(node) = pending.pop(){
+                for (next, edge) in graph.neighbors(node){
+                    if Some(next) != skip_node && Some(edge) != skip_edge && !seen[next.index()]{
+                        seen[next.index()] = true;
+                        pending.push(next);
+                    }
+                }
+            }
+        }
+        count
+    }
+    #[test]
+    fn articulation_points_and_bridges_match_brute_force(){
+        for seed in 1..=15{
+            let (graph, _) = random_graph(seed, 18, 22, false);
+            let base: usize = components_without(&graph, None, None);
+            let expected_points: Vec<NodeId> = graph.node_ids().filter(|&node| components_without(&graph, Some(node), None) > base).collect();
+            let mut expected_bridges: Vec<EdgeId> = graph.edge_ids().filter(|&edge| components_without(&graph, None, Some(edge)) > base).collect();
+            let mut found_bridges: Vec<EdgeId> = bridges(&graph).unwrap();
+            expected_bridges.sort();
+            found_bridges.sort();
+            assert_eq!(articulation_points(&graph).unwrap() , expected_points);
+            assert_eq!(found_bridges , expected_bridges);
+        }
+        let (directed, _) = random_graph(1, 4, 4, true);
+        assert!(bridges(&directed).is_err());
+    }
+    #[test]
+    fn bipartite_and_odd_cycles(){
+        //Un ciclo par es bipartito y uno impar no
+        for (length, bipartite) in [(6, true), (7, false)]{
+            let mut graph: Graph<usize, (), AdjacencyMatrix> = Graph::undirected();
+            let ids: Vec<NodeId> = (0..length).map(|value| graph.add_node(value)).collect();
+            for index in 0..length{
+                graph.add_edge(ids[index], ids[(index + 1) % length], ()).unwrap();
+            }
+            match bipartition(&graph){
+                Ok(sides) => {
+                    assert!(bipartite);
+                    assert_eq!((sides.left.len(), sides.right.len()) , (3, 3));
+                    for edge in graph.edge_ids(){
+                        let (from, to) = graph.edge_endpoints(edge).unwrap();
+                        assert_ne!(sides.left.contains(&from) , sides.left.contains(&to));
+                    }
+                },
+                Err(cycle) => {
+                    assert!(!bipartite);
+                    assert_eq!(cycle.len() % 2 , 1);
+                    assert_cycle(&graph, &cycle);
+                }
+            }
+        }
+        //En un grafo dirigido la dirección no importa
+        let mut graph: Graph<(), ()> = Graph::directed();
+        let (a, b, c) = (graph.add_node(()), graph.add_node(()), graph.add_node(()));
+        graph.add_edge(a, b, ()).unwrap();
+        graph.add_edge(c, b, ()).unwrap();
+        assert_eq!(bipartition(&graph).unwrap() , Bipartition{ left : vec![a, c], right : vec![b] });
+        graph.add_edge(c, a, ()).unwrap();
+        assert_eq!(bipartition(&graph).unwrap_err().len() , 3);
+    }
+}
@@ -0,0 +1,267 @@
+//! ### Recorridos de grafos
+//! - BFS (anchura): visita los nodos por capas usando la `Queue` del crate, el arbol resultante tiene los caminos con
+//!   menos aristas desde el origen.
+//! - DFS (profundidad): iterativo sobre la `Stack` del crate, cada marco guarda el iterador de vecinos pendiente así
+//!   que no depende de la pila de llamadas y emite eventos de pre y post orden.
+//! ```text
+//!        A               BFS desde A : A B C D E
+//!      /   \             DFS desde A : pre  A B D C E
+//!     B     C                          post D B E C A
+//!     |     |
+//!     D     E
+//! ```
+//! ###### Clasificación de aristas en DFS
+//! Cada nodo pasa por blanco (sin descubrir), gris (en la pila) y negro (terminado). Una arista hacia un nodo blanco
+//! es de arbol, hacia uno gris es de retroceso (cierra un ciclo) y hacia uno negro es de avance o cruce. En un grafo no
+//! dirigido la arista que lleva al padre y las que llegan a nodos negros ya se clasificaron desde el otro extremo y
+//! se omiten.
+use crate::lineal::safety::queue::Queue;
+use crate::lineal::safety::stack::Stack;
+use super::graph::{EdgeId, Graph, GraphStorage, NodeId};
+
+///Reconstruye el camino desde la raíz del arbol de predecesores hasta `target`, retorna nodos y aristas.
+pub(crate) fn walk_back(parent : &[Option<(NodeId, EdgeId)>] , target : NodeId) -> (Vec<NodeId>, Vec<EdgeId>){
+    let mut nodes: Vec<NodeId> = vec![target];
+    let mut edges: Vec<EdgeId> = Vec::new();
+    let mut current: NodeId = target;
+    while let Some((previous, edge)) = parent[current.index()]{
+        nodes.push(previous);
+        edges.push(edge);
+        current = previous;
+    }
+    nodes.reverse();
+    edges.reverse();
+    (nodes, edges)
+}
+///### BreadthFirst
+/// Resultado de un BFS: orden de visita y arbol de caminos con menos aristas.
+#[derive(Debug, Clone)]
+pub struct BreadthFirst{
+    pub order : Vec<NodeId>,
+    parent : Vec<Option<(NodeId, EdgeId)>>,
+    depth : Vec<Option<usize>>
+}
+impl BreadthFirst{
+    ///Cantidad de aristas entre el origen y `node`, `None` si no es alcanzable.
+    pub fn depth(&self , node : NodeId) -> Option<usize>{
+        self.depth.get(node.index()).copied().flatten()
+    }
+    pub fn reached(&self , node : NodeId) -> bool{
+        self.depth(node).is_some()
+    }
+    ///Nodo y arista por los que se descubrió `node`.
+    pub fn parent(&self , node : NodeId) -> Option<(NodeId, EdgeId)>{
+        self.parent.get(node.index()).copied().flatten()
+    }
+    pub fn path_to(&self , node : NodeId) -> Option<Vec<NodeId>>{
+        self.reached(node).then(|| walk_back(&self.parent, node).0)
+    }
+}
+///### BFS
+/// Recorre en anchura desde `start` en O(V + E), si `start` no existe el recorrido queda vacío.
+pub fn breadth_first<N, E, S : GraphStorage>(graph : &Graph<N, E, S> , start : NodeId) -> BreadthFirst{
+    let mut search: BreadthFirst = BreadthFirst{
+        order : Vec::new(),
+        parent : vec![None; graph.node_bound()],
+        depth : vec![None; graph.node_bound()]
+    };
+    if !graph.contains_node(start){
+        return search;
+    }
+    let mut queue: Queue<NodeId> = Queue::new();
+    search.depth[start.index()] = Some(0);
+    queue.push_back(start);
+    while let Some(node) = queue.pop_front(){
+        search.order.push(node);
+        let depth: usize = search.depth[node.index()].expect("los nodos encolados ya fueron descubiertos");
+        for (next, edge) in graph.neighbors(node){
+            if search.depth[next.index()].is_none(){
+                search.depth[next.index()] = Some(depth + 1);
+                search.parent[next.index()] = Some((node, edge));
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+///Eventos que emite `depth_first_visit` en el orden en que ocurren.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DfsEvent{
+    ///El nodo entra a la pila (preorden).
+    Discover(NodeId),
+    ///`from -> to` descubrió a `to`.
+    TreeEdge(NodeId, NodeId, EdgeId),
+    ///`from -> to` llega a un ancestro en la pila, cierra un ciclo.
+    BackEdge(NodeId, NodeId, EdgeId),
+    ///`from -> to` llega a un nodo ya terminado, solo ocurre en grafos dirigidos.
+    CrossEdge(NodeId, NodeId, EdgeId),
+    ///Todos los vecinos del nodo fueron procesados (postorden).
+    Finish(NodeId)
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color{
+    White,
+    Gray,
+    Black
+}
+///### DFS con eventos
+/// Recorre en profundidad desde cada raíz aún no visitada de `roots`, llamando a `visitor` con cada evento.
+/// Pasar `graph.node_ids()` como raíces recorre el bosque completo.
+pub fn depth_first_visit<N, E, S, I, F>(graph : &Graph<N, E, S> , roots : I , mut visitor : F)
+where S : GraphStorage , I : IntoIterator<Item = NodeId> , F : FnMut(DfsEvent){
+    let mut color: Vec<Color> = vec![Color::White; graph.node_bound()];
+    //Cada marco es (nodo, arista por la que se llegó, vecinos pendientes)
+    let mut stack: Stack<(NodeId, Option<EdgeId>, S::Neighbors<'_>)> = Stack::new();
+    for root in roots{
+        if !graph.contains_node(root) || color[root.index()] != Color::White{
+            continue;
+        }
+        color[root.index()] = Color::Gray;
+        visitor(DfsEvent::Discover(root));
+        stack.push((root, None, graph.neighbors(root)));
+        //El cursor de vecinos avanza en la cima sin sacar el marco, solo sale cuando se agota
+        while let Some((node, via, step)) = stack.peek_mut().map(|mut frame| {
+            let (node, via, neighbors) = &mut *frame;
+            (*node, *via, neighbors.next())
+        }){
+            let Some((next, edge)) = step else {
+                stack.pop();
+                color[node.index()] = Color::Black;
+                visitor(DfsEvent::Finish(node));
+                continue;
+            };
+            if !graph.is_directed() && (via == Some(edge) || color[next.index()] == Color::Black){
+                continue;
+            }
+            match color[next.index()]{
+                Color::White => {
+                    visitor(DfsEvent::TreeEdge(node, next, edge));
+                    color[next.index()] = Color::Gray;
+                    visitor(DfsEvent::Discover(next));
+                    stack.push((next, Some(edge), graph.neighbors(next)));
+                },
+                Color::Gray => visitor(DfsEvent::BackEdge(node, next, edge)),
+                Color::Black => visitor(DfsEvent::CrossEdge(node, next, edge))
+            }
+        }
+    }
+}
+///### DepthFirst
+/// Resultado de un DFS: pre orden, post orden y el arbol (o bosque) de descubrimiento.
+#[derive(Debug, Clone)]
+pub struct DepthFirst{
+    pub preorder : Vec<NodeId>,
+    pub postorder : Vec<NodeId>,
+    parent : Vec<Option<(NodeId, EdgeId)>>,
+    discovered : Vec<bool>
+}
+impl DepthFirst{
+    fn collect<N, E, S : GraphStorage>(graph : &Graph<N, E, S> , roots : impl IntoIterator<Item = NodeId>) -> Self{
+        let mut search: DepthFirst = DepthFirst{
+            preorder : Vec::new(),
+            postorder : Vec::new(),
+            parent : vec![None; graph.node_bound()],
+            discovered : vec![false; graph.node_bound()]
+        };
+        depth_first_visit(graph, roots, |event| match event{
+            DfsEvent::Discover(node) => {
+                search.discovered[node.index()] = true;
+                search.preorder.push(node);
+            },
+            DfsEvent::TreeEdge(from, to, edge) => search.parent[to.index()] = Some((from, edge)),
+            DfsEvent::Finish(node) => search.postorder.push(node),
+            _ => {}
+        });
+        search
+    }
+    pub fn reached(&self , node : NodeId) -> bool{
+        self.discovered.get(node.index()).copied().unwrap_or(false)
+    }
+    pub fn parent(&self , node : NodeId) -> Option<(NodeId, EdgeId)>{
+        self.parent.get(node.index()).copied().flatten()
+    }
+    ///Camino por el arbol de descubrimiento desde la raíz que alcanzó a `node`.
+    pub fn path_to(&self , node : NodeId) -> Option<Vec<NodeId>>{
+        self.reached(node).then(|| walk_back(&self.parent, node).0)
+    }
+}
+///Recorre en profundidad desde `start` en O(V + E).
+pub fn depth_first<N, E, S : GraphStorage>(graph : &Graph<N, E, S> , start : NodeId) -> DepthFirst{
+    DepthFirst::collect(graph, [start])
+}
+///Recorre en profundidad todos los nodos, iniciando un arbol nuevo en cada nodo aún no visitado.
+pub fn depth_first_forest<N, E, S : GraphStorage>(graph : &Graph<N, E, S>) -> DepthFirst{
+    DepthFirst::collect(graph, graph.node_ids())
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::no_lineal::safety::adjacency_list::AdjacencyList;
+    use crate::no_lineal::safety::adjacency_matrix::AdjacencyMatrix;
+    use crate::no_lineal::safety::graph::Direction;
+
+    ///El arbol del diagrama del modulo más la arista `E -> A` y un nodo aislado.
+    fn sample<S : GraphStorage>(direction : Direction) -> (Graph<char, (), S>, Vec<NodeId>){
+        let mut graph: Graph<char, (), S> = Graph::new(direction);
+        let ids: Vec<NodeId> = "ABCDEF".chars().map(|name| graph.add_node(name)).collect();
+        for (from, to) in [(0, 1), (0, 2), (1, 3), (2, 4), (4, 0)]{
+            graph.add_edge(ids[from], ids[to], ()).unwrap();
+        }
+        (graph, ids)
+    }
+    fn names(graph : &Graph<char, (), impl GraphStorage> , nodes : &[NodeId]) -> String{
+        nodes.iter().map(|&node| *graph.node(node).unwrap()).collect()
+    }
+    #[test]
+    fn bfs_layers(){
+        let (graph, ids) = sample::<AdjacencyMatrix>(Direction::Directed);
+        let search: BreadthFirst = breadth_first(&graph, ids[0]);
+        assert_eq!(names(&graph, &search.order) , "ABCDE");
+        assert_eq!(search.depth(ids[4]) , Some(2));
+        assert_eq!(names(&graph, &search.path_to(ids[4]).unwrap()) , "ACE");
+        assert_eq!(search.path_to(ids[5]) , None);
+        //En el no dirigido E alcanza a A directamente
+        let (graph, ids) = sample::<AdjacencyMatrix>(Direction::Undirected);
+        let search: BreadthFirst = breadth_first(&graph, ids[4]);
+        assert_eq!(names(&graph, &search.order) , "EACBD");
+        assert_eq!(search.depth(ids[3]) , Some(3));
+    }
+    #[test]
+    fn dfs_orders_and_events(){
+        let (graph, ids) = sample::<AdjacencyList>(Direction::Directed);
+        let search: DepthFirst = depth_first(&graph, ids[0]);
+        assert_eq!(names(&graph, &search.preorder) , "ABDCE");
+        assert_eq!(names(&graph, &search.postorder) , "DBECA");
+        assert_eq!(names(&graph, &search.path_to(ids[4]).unwrap()) , "ACE");
+        let mut back_edges: Vec<(NodeId, NodeId)> = Vec::new();
+        depth_first_visit(&graph, [ids[0]], |event| if let DfsEvent::BackEdge(from, to, _) = event{
+            back_edges.push((from, to));
+        });
+        assert_eq!(back_edges , vec![(ids[4], ids[0])]);
+        let forest: DepthFirst = depth_first_forest(&graph);
+        assert_eq!(names(&graph, &forest.postorder) , "DBECAF");
+    }
+    #[test]
+    fn dfs_undirected_edges_are_classified_once(){
+        let (graph, ids) = sample::<AdjacencyList>(Direction::Undirected);
+        let mut events: Vec<DfsEvent> = Vec::new();
+        depth_first_visit(&graph, graph.node_ids(), |event| events.push(event));
+        let tree: usize = events.iter().filter(|event| matches!(event, DfsEvent::TreeEdge(..))).count();
+        let back: Vec<&DfsEvent> = events.iter().filter(|event| matches!(event, DfsEvent::BackEdge(..))).collect();
+        assert_eq!(tree , 4);
+        assert_eq!(back.len() , 1);
+        assert!(!events.iter().any(|event| matches!(event, DfsEvent::CrossEdge(..))));
+        //Cada nodo se descubre y termina una sola vez
+        assert_eq!(events.iter().filter(|event| matches!(event, DfsEvent::Finish(_))).count() , ids.len());
+    }
+    #[test]
+    fn deep_path_does_not_overflow(){
+        let mut graph: Graph<usize, ()> = Graph::directed();
+        let ids: Vec<NodeId> = (0..100_000).map(|value| graph.add_node(value)).collect();
+        for pair in ids.windows(2){
+            graph.add_edge(pair[0], pair[1], ()).unwrap();
+        }
+        let search: DepthFirst = depth_first(&graph, ids[0]);
+        assert_eq!(search.postorder.first() , ids.last());
+    }
+}
@@ -0,0 +1,326 @@
+//! ### Sobre los Interval Tree
+//! Un arbol de intervalos es un AVL ordenado por el inicio de cada intervalo donde cada nodo guarda además el mayor
+//! extremo final de su subárbol (`max_end`).
+//! ```text
+//!                    [15, 23) max 30
+//!                 /                  \
+//!        [5, 20) max 20          [17, 19) max 30
+//!          /        \                  \
+//!   [0, 3) max 3  [6, 10) max 10    [26, 30) max 30
+//! ```
+//! Con ese dato una consulta descarta subárboles completos: si `max_end <= inicio` de la consulta nada de ahí la
+//! alcanza, y como el recorrido inorden crece por inicio se detiene al ver un inicio mayor o igual al final buscado.
+//! Buscar algún solapamiento es O(log n) y listar los `k` solapamientos O(k · log n).
+//!
+//! `max_end` vive dentro del valor del nodo e implementa `Augmented`, por lo que las rotaciones de `AVLTree` lo
+//! recalculan en el mismo paso que la altura.
+use std::cmp::Ordering;
+use std::fmt::{self, Debug};
+use std::ops::Range;
+use super::avl_tree::{AVLNode, AVLTree, Augmented};
+
+///Intervalo semiabierto no vacío `[start, end)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T>{
+    pub start : T,
+    pub end : T
+}
+impl<T : Ord + Copy> Interval<T>{
+    pub fn new(start : T , end : T) -> Self{
+        assert!(start < end, "un intervalo debe cumplir start < end");
+        Self{ start, end }
+    }
+    ///Comparten algún punto, intervalos que solo se tocan en un extremo no se solapan.
+    pub fn overlaps(&self , other : &Interval<T>) -> bool{
+        self.start < other.end && other.start < self.end
+    }
+    pub fn contains(&self , point : T) -> bool{
+        self.start <= point && point < self.end
+    }
+}
+impl<T : Ord + Copy> From<Range<T>> for Interval<T>{
+    fn from(range : Range<T>) -> Self{
+        Self::new(range.start, range.end)
+    }
+}
+///Valor guardado en el AVL: el intervalo y el mayor extremo de su subárbol.
+#[derive(Debug)]
+struct MaxEndpoint<T>{
+    interval : Interval<T>,
+    max_end : T
+}
+impl<T : Ord + Copy> Augmented for MaxEndpoint<T>{
+    fn refresh(&mut self , left : Option<&Self> , right : Option<&Self>){
+        self.max_end = [left, right].into_iter().flatten().map(|child| child.max_end).fold(self.interval.end, T::max);
+    }
+}
+type Link<T> = Option<Box<AVLNode<MaxEndpoint<T>>>>;
+
+///### IntervalTree
+/// Admite intervalos repetidos, `remove` quita una sola copia.
+pub struct IntervalTree<T>{
+    tree : AVLTree<MaxEndpoint<T>>
+}
+impl<T : Ord + Copy> Default for IntervalTree<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T : Ord + Copy> IntervalTree<T>{
+    pub fn new() -> Self{
+        Self{ tree : AVLTree::new() }
+    }
+    pub fn len(&self) -> usize{
+        self.tree.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.tree.len() == 0
+    }
+    pub fn insert(&mut self , interval : Interval<T>){
+        self.tree.root = Self::insert_recursive(self.tree.root.take(), interval);
+        self.tree.size += 1;
+    }
+    fn insert_recursive(node : Link<T> , interval : Interval<T>) -> Link<T>{
+        let mut current: Box<AVLNode<MaxEndpoint<T>>> = match node{
+            None => return Some(Box::new(AVLNode::new(MaxEndpoint{ interval, max_end : interval.end }))),
+            Some(current) => current
+        };
+        if interval < current.value.interval{
+            current.left = Self::insert_recursive(current.left.take(), interval);
+        }else{
+            current.right = Self::insert_recursive(current.right.take(), interval);
+        }
+        let mut node: Link<T> = Some(current);
+        AVLTree::update_height_node(&mut node);
+        AVLTree::rebalance(node)
+    }
+    ///Elimina una copia de `interval`, retorna si estaba.
+    pub fn remove(&mut self , interval : &Interval<T>) -> bool{
+        let mut removed: bool = false;
+        self.tree.root = Self::remove_recursive(self.tree.root.take(), interval, &mut removed);
+        if removed{
+            self.tree.size -= 1;
+        }
+        removed
+    }
+    fn remove_recursive(node : Link<T> , interval : &Interval<T> , removed : &mut bool) -> Link<T>{
+        let mut current: Box<AVLNode<MaxEndpoint<T>>> = node?;
+        match interval.cmp(&current.value.interval){
+            Ordering::Less => current.left = Self::remove_recursive(current.left.take(), interval, removed),
+            Ordering::Greater => current.right = Self::remove_recursive(current.right.take(), interval, removed),
+            Ordering::Equal => {
+                *removed = true;
+                match (current.left.take(), current.right.take()){
+                    (None, None) => return None,
+                    (Some(child), None) | (None, Some(child)) => return Some(child),
+                    (Some(left), right) => {
+                        //El mayor del subárbol izquierdo ocupa el lugar del eliminado
+                        let mut predecessor: &AVLNode<MaxEndpoint<T>> = &left;
+                        while let Some(next) = predecessor.right.as_deref(){
+                            predecessor = next;
+                        }
+                        let replacement: Interval<T> = predecessor.value.interval;
+                        current.left = Self::remove_recursive(Some(left), &replacement, &mut false);
+                        current.right = right;
+                        current.value.interval = replacement;
+                    }
+                }
+            }
+        }
+        let mut node: Link<T> = Some(current);
+        AVLTree::update_height_node(&mut node);
+        AVLTree::rebalance(node)
+    }
+    pub fn contains(&self , interval : &Interval<T>) -> bool{
+        let mut current: Option<&AVLNode<MaxEndpoint<T>>> = self.tree.root.as_deref();
+        while let Some(node) = current{
+            current = match interval.cmp(&node.value.interval){
+                Ordering::Equal => return true,
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => node.right.as_deref()
+            };
+        }
+        false
+    }
+    ///Intervalos almacenados que se solapan con `interval`, ordenados.
+    pub fn overlapping(&self , interval : &Interval<T>) -> Overlapping<'_, T>{
+        Overlapping::new(self.tree.root.as_deref(), Query::Overlap(*interval))
+    }
+    ///Intervalos almacenados que contienen `point`, ordenados.
+    pub fn stabbing(&self , point : T) -> Overlapping<'_, T>{
+        Overlapping::new(self.tree.root.as_deref(), Query::Stab(point))
+    }
+    ///Todos los intervalos ordenados por inicio y luego por final.
+    pub fn iter(&self) -> Overlapping<'_, T>{
+        Overlapping::new(self.tree.root.as_deref(), Query::All)
+    }
+    ///Si algún intervalo se solapa con `interval`, en O(log n): si el subárbol izquierdo llega más allá del inicio
+    ///buscado y no contiene un solapamiento, el derecho tampoco (todos empiezan después del final buscado).
+    pub fn any_overlap(&self , interval : &Interval<T>) -> bool{
+        let mut current: Option<&AVLNode<MaxEndpoint<T>>> = self.tree.root.as_deref();
+        while let Some(node) = current{
+            if node.value.interval.overlaps(interval){
+                return true;
+            }
+            current = match node.left.as_deref(){
+                Some(left) if left.value.max_end > interval.start => Some(left),
+                _ => node.right.as_deref()
+            };
+        }
+        false
+    }
+}
+#[derive(Clone, Copy)]
+enum Query<T>{
+    Overlap(Interval<T>),
+    Stab(T),
+    All
+}
+impl<T : Ord + Copy> Query<T>{
+    ///Ningún intervalo de un subárbol con ese `max_end` puede coincidir.
+    fn prunes(&self , max_end : T) -> bool{
+        match self{
+            Query::Overlap(interval) => max_end <= interval.start,
+            Query::Stab(point) => max_end <= *point,
+            Query::All => false
+        }
+    }
+    ///Ni este intervalo ni los que empiezan después pueden coincidir.
+    fn past(&self , start : T) -> bool{
+        match self{
+            Query::Overlap(interval) => start >= interval.end,
+            Query::Stab(point) => start > *point,
+            Query::All => false
+        }
+    }
+    fn matches(&self , interval : &Interval<T>) -> bool{
+        match self{
+            Query::Overlap(query) => query.overlaps(interval),
+            Query::Stab(point) => interval.contains(*point),
+            Query::All => true
+        }
+    }
+}
+///Recorrido inorden con una pila que poda los subárboles que no pueden coincidir con la consulta.
+pub struct Overlapping<'a, T>{
+    stack : Vec<&'a AVLNode<MaxEndpoint<T>>>,
+    query : Query<T>
+}
+impl<'a, T : Ord + Copy> Overlapping<'a, T>{
+    fn new(root : Option<&'a AVLNode<MaxEndpoint<T>>> , query : Query<T>) -> Self{
+        let mut iter: Overlapping<'a, T> = Self{ stack : Vec::new(), query };
+        iter.push_left(root);
+        iter
+    }
+    fn push_left(&mut self , mut node : Option<&'a AVLNode<MaxEndpoint<T>>>){
+        while let Some(current) = node{
+            if self.query.prunes(current.value.max_end){
+                break;
+            }
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+impl<'a, T : Ord + Copy> Iterator for Overlapping<'a, T>{
+    type Item = &'a Interval<T>;
+    fn next(&mut self) -> Option<Self::Item>{
+        while let Some(node) = self.stack.pop(){
+            if self.query.past(node.value.interval.start){
+                self.stack.clear();
+                return None;
+            }
+            self.push_left(node.right.as_deref());
+            if self.query.matches(&node.value.interval){
+                return Some(&node.value.interval);
+            }
+        }
+        None
+    }
+}
+impl<T : Ord + Copy + Debug> Debug for IntervalTree<T>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl<T : Ord + Copy> FromIterator<Interval<T>> for IntervalTree<T>{
+    fn from_iter<I : IntoIterator<Item = Interval<T>>>(iter : I) -> Self{
+        let mut tree: IntervalTree<T> = IntervalTree::new();
+        for interval in iter{
+            tree.insert(interval);
+        }
+        tree
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::random::XorShift64;
+
+    ///Verifica altura, balance y `max_end` de cada nodo, retorna la altura.
+    fn check(node : &Link<u32>) -> isize{
+        match node{
+            None => -1,
+            Some(node) => {
+                let (left, right) = (check(&node.left), check(&node.right));
+                assert!((left - right).abs() <= 1, "no cumple la propiedad AVL");
+                assert_eq!(node.height , left.max(right) + 1);
+                let expected: u32 = [&node.left, &node.right].into_iter().flatten().map(|child| child.value.max_end)
+                    .fold(node.value.interval.end, u32::max);
+                assert_eq!(node.value.max_end , expected);
+                node.height
+            }
+        }
+    }
+    #[test]
+    fn time_windows(){
+        let tree: IntervalTree<u32> = [15..23, 5..20, 17..19, 0..3, 6..10, 26..30].into_iter().map(Interval::from).collect();
+        let found: Vec<Interval<u32>> = tree.overlapping(&Interval::new(9, 16)).copied().collect();
+        assert_eq!(found , vec![Interval::new(5, 20), Interval::new(6, 10), Interval::new(15, 23)]);
+        //Los extremos abiertos no cuentan
+        assert!(!tree.any_overlap(&Interval::new(23, 26)));
+        assert!(tree.any_overlap(&Interval::new(29, 40)));
+        assert_eq!(tree.stabbing(18).count() , 3);
+        assert_eq!(tree.stabbing(3).count() , 0);
+        assert_eq!(tree.iter().next() , Some(&Interval::new(0, 3)));
+    }
+    #[test]
+    fn matches_brute_force(){
+        let mut rng: XorShift64 = XorShift64::new(49);
+        let mut tree: IntervalTree<u32> = IntervalTree::new();
+        let mut expected: Vec<Interval<u32>> = Vec::new();
+        let random_interval = |rng : &mut XorShift64| -> Interval<u32>{
+            let start: u32 = (rng.next_u64() % 200) as u32;
+            Interval::new(start, start + 1 + (rng.next_u64() % 30) as u32)
+        };
+        for round in 0..2000{
+            let interval: Interval<u32> = random_interval(&mut rng);
+            if rng.next_u64() % 3 == 0 && !expected.is_empty(){
+                //La mitad de las veces elimina uno existente
+                let target: Interval<u32> = if rng.next_u64() % 2 == 0{ expected[rng.next_u64() as usize % expected.len()] }else{ interval };
+                let position: Option<usize> = expected.iter().position(|current| *current == target);
+                assert_eq!(tree.remove(&target) , position.is_some());
+                if let Some(position) = position{
+                    expected.remove(position);
+                }
+            }else{
+                tree.insert(interval);
+                expected.push(interval);
+            }
+            assert_eq!(tree.len() , expected.len());
+            if round % 20 == 0{
+                check(&tree.tree.root);
+            }
+            expected.sort();
+            let query: Interval<u32> = random_interval(&mut rng);
+            let brute: Vec<Interval<u32>> = expected.iter().filter(|current| current.overlaps(&query)).copied().collect();
+            assert_eq!(tree.overlapping(&query).copied().collect::<Vec<_>>() , brute);
+            assert_eq!(tree.any_overlap(&query) , !brute.is_empty());
+            let point: u32 = (rng.next_u64() % 240) as u32;
+            let stabbed: Vec<Interval<u32>> = expected.iter().filter(|current| current.contains(point)).copied().collect();
+            assert_eq!(tree.stabbing(point).copied().collect::<Vec<_>>() , stabbed);
+            assert_eq!(tree.contains(&query) , expected.contains(&query));
+        }
+        assert_eq!(tree.iter().copied().collect::<Vec<_>>() , expected);
+    }
+}
@@ -0,0 +1,279 @@
+//! ### Sobre los k-d Tree
+//! Un k-d tree es un arbol binario de puntos en `K` dimensiones donde cada nivel divide el espacio con un hiperplano
+//! perpendicular a un eje, los ejes se alternan con la profundidad (`eje = profundidad % K`). Los puntos del subárbol
+//! izquierdo tienen la coordenada del eje menor o igual a la del nodo y los del derecho mayor o igual.
+//! ```text
+//!   K = 2, puntos (2,3) (5,4) (9,6) (4,7) (8,1) (7,2)
+//!
+//!                 (7,2)  x          y
+//!                /     \            |        (4,7)
+//!           (5,4) y   (9,6) y       |               (9,6)
+//!           /    \     /            |     (5,4)
+//!       (2,3)  (4,7) (8,1)          | (2,3)      (7,2)
+//!                                   |                (8,1)
+//!                                   +--------------------- x
+//! ```
+//! `from_points` elige la mediana de cada eje con `select_nth_unstable_by`, así el arbol queda balanceado en
+//! O(n log n). `insert` baja comparando por el eje de cada nivel y no rebalancea.
+//!
+//! Las búsquedas descartan un subárbol cuando la distancia de la consulta al hiperplano del nodo ya supera la cota
+//! actual (el radio o la peor de las `k` mejores distancias).
+use std::cmp::Ordering;
+use super::binary_heap::BinaryHeap;
+
+#[derive(Debug, Clone)]
+struct KdNode<const K: usize>{
+    point : [f64; K],
+    axis : usize,
+    left : Option<usize>,
+    right : Option<usize>
+}
+fn squared_distance<const K: usize>(a : &[f64; K] , b : &[f64; K]) -> f64{
+    a.iter().zip(b.iter()).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+///Candidato a vecino más cercano, ordenado por distancia para el montículo de máximos.
+#[derive(Debug, Clone, Copy)]
+struct Candidate{
+    squared : f64,
+    node : usize
+}
+impl PartialEq for Candidate{
+    fn eq(&self , other : &Self) -> bool{
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for Candidate{}
+impl PartialOrd for Candidate{
+    fn partial_cmp(&self , other : &Self) -> Option<Ordering>{
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Candidate{
+    fn cmp(&self , other : &Self) -> Ordering{
+        self.squared.total_cmp(&other.squared).then(self.node.cmp(&other.node))
+    }
+}
+///### KdTree
+/// Los nodos viven en un arreglo y se referencian por indice.
+#[derive(Debug, Clone)]
+pub struct KdTree<const K: usize>{
+    nodes : Vec<KdNode<K>>,
+    root : Option<usize>
+}
+impl<const K: usize> Default for KdTree<K>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<const K: usize> KdTree<K>{
+    const VALID_DIMENSION : () = assert!(K > 0, "un k-d tree necesita al menos una dimensión");
+
+    pub fn new() -> Self{
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_DIMENSION;
+        Self{ nodes : Vec::new(), root : None }
+    }
+    ///Construye un arbol balanceado, la mediana de cada eje queda como raíz de su subárbol.
+    pub fn from_points(mut points : Vec<[f64; K]>) -> Self{
+        assert!(points.iter().flatten().all(|coordinate| !coordinate.is_nan()), "las coordenadas no pueden ser NaN");
+        let mut tree: KdTree<K> = Self::new();
+        tree.nodes.reserve(points.len());
+        tree.root = tree.build(&mut points, 0);
+        tree
+    }
+    fn build(&mut self , points : &mut [[f64; K]] , depth : usize) -> Option<usize>{
+        if points.is_empty(){
+            return None;
+        }
+        let axis: usize = depth % K;
+        let middle: usize = points.len() / 2;
+        points.select_nth_unstable_by(middle, |a, b| a[axis].total_cmp(&b[axis]));
+        let index: usize = self.nodes.len();
+        self.nodes.push(KdNode{ point : points[middle], axis, left : None, right : None });
+        let (lower, upper) = points.split_at_mut(middle);
+        self.nodes[index].left = self.build(lower, depth + 1);
+        self.nodes[index].right = self.build(&mut upper[1..], depth + 1);
+        Some(index)
+    }
+    pub fn len(&self) -> usize{
+        self.nodes.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.nodes.is_empty()
+    }
+    ///Cantidad de niveles, 0 si está vacío.
+    pub fn height(&self) -> usize{
+        let mut height: usize = 0;
+        let mut stack: Vec<(usize, usize)> = self.root.map(|root| (root, 1)).into_iter().collect();
+        while let Some((node, depth)) = stack.pop(){
+            height = height.max(depth);
+            for child in [self.nodes[node].left, self.nodes[node].right].into_iter().flatten(){
+                stack.push((child, depth + 1));
+            }
+        }
+        height
+    }
+    pub fn insert(&mut self , point : [f64; K]){
+        assert!(point.iter().all(|coordinate| !coordinate.is_nan()), "las coordenadas no pueden ser NaN");
+        let index: usize = self.nodes.len();
+        let mut depth: usize = 0;
+        let mut current: Option<usize> = self.root;
+        let mut parent: Option<(usize, bool)> = None;
+        while let Some(node) = current{
+            let axis: usize = self.nodes[node].axis;
+            let goes_left: bool = point[axis] < self.nodes[node].point[axis];
+            parent = Some((node, goes_left));
+            current = if goes_left{ self.nodes[node].left }else{ self.nodes[node].right };
+            depth += 1;
+        }
+        self.nodes.push(KdNode{ point, axis : depth % K, left : None, right : None });
+        match parent{
+            None => self.root = Some(index),
+            Some((node, true)) => self.nodes[node].left = Some(index),
+            Some((node, false)) => self.nodes[node].right = Some(index)
+        }
+    }
+    ///Puntos en el orden en que están guardados.
+    pub fn iter(&self) -> impl Iterator<Item = &[f64; K]>{
+        self.nodes.iter().map(|node| &node.point)
+    }
+    ///Los `k` puntos más cercanos a `query` con su distancia euclidiana, de menor a mayor distancia.
+    pub fn nearest(&self , query : &[f64; K] , k : usize) -> Vec<(f64, &[f64; K])>{
+        if k == 0{
+            return Vec::new();
+        }
+        //Montículo de máximos con las k mejores, su raíz es la cota para podar
+        let mut best: BinaryHeap<Candidate> = BinaryHeap::new_max();
+        //Cada entrada lleva una cota inferior de la distancia a su subárbol
+        let mut stack: Vec<(usize, f64)> = self.root.map(|root| (root, 0.0)).into_iter().collect();
+        while let Some((node, bound)) = stack.pop(){
+            if best.len() == k && best.peek().is_some_and(|worst| bound >= worst.squared){
+                continue;
+            }
+            let current: &KdNode<K> = &self.nodes[node];
+            let candidate: Candidate = Candidate{ squared : squared_distance(&current.point, query), node };
+            if best.len() < k{
+                best.push(candidate);
+            }else if best.peek().is_some_and(|worst| candidate < *worst){
+                best.pop();
+                best.push(candidate);
+            }
+            let difference: f64 = query[current.axis] - current.point[current.axis];
+            let (near, far) = if difference < 0.0{ (current.left, current.right) }else{ (current.right, current.left) };
+            //El lado cercano se apila último para visitarlo primero y ajustar la cota antes de ver el lejano
+            if let Some(far) = far{
+                stack.push((far, bound.max(difference * difference)));
+            }
+            if let Some(near) = near{
+                stack.push((near, bound));
+            }
+        }
+        best.into_sorted_vec().into_iter().map(|candidate| (candidate.squared.sqrt(), &self.nodes[candidate.node].point)).collect()
+    }
+    ///Puntos a distancia euclidiana menor o igual a `radius` de `query`. Ningún punto está a distancia negativa, con un
+    ///radio negativo (o `NaN`) el resultado es vacio, igual que `range` con una caja invertida.
+    pub fn within_radius(&self , query : &[f64; K] , radius : f64) -> Vec<&[f64; K]>{
+        //Sin este corte `radius * radius` sería positivo y la poda por eje descartaría ramas arbitrarias
+        if radius.is_nan() || radius < 0.0{
+            return Vec::new();
+        }
+        let limit: f64 = radius * radius;
+        let mut found: Vec<&[f64; K]> = Vec::new();
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(node) = stack.pop(){
+            let current: &KdNode<K> = &self.nodes[node];
+            if squared_distance(&current.point, query) <= limit{
+                found.push(&current.point);
+            }
+            let difference: f64 = query[current.axis] - current.point[current.axis];
+            if difference <= radius{
+                stack.extend(current.left);
+            }
+            if difference >= -radius{
+                stack.extend(current.right);
+            }
+        }
+        found
+    }
+    ///Puntos dentro de la caja `[lower, upper]` incluyendo los bordes.
+    pub fn range(&self , lower : &[f64; K] , upper : &[f64; K]) -> Vec<&[f64; K]>{
+        let mut found: Vec<&[f64; K]> = Vec::new();
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(node) = stack.pop(){
+            let current: &KdNode<K> = &self.nodes[node];
+            if (0..K).all(|axis| lower[axis] <= current.point[axis] && current.point[axis] <= upper[axis]){
+                found.push(&current.point);
+            }
+            let split: f64 = current.point[current.axis];
+            if lower[current.axis] <= split{
+                stack.extend(current.left);
+            }
+            if upper[current.axis] >= split{
+                stack.extend(current.right);
+            }
+        }
+        found
+    }
+}
+impl<const K: usize> FromIterator<[f64; K]> for KdTree<K>{
+    fn from_iter<I : IntoIterator<Item = [f64; K]>>(iter : I) -> Self{
+        Self::from_points(iter.into_iter().collect())
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::random::XorShift64;
+
+    fn random_point<const K: usize>(rng : &mut XorShift64) -> [f64; K]{
+        //Coordenadas enteras pequeñas para provocar empates en ejes y distancias
+        std::array::from_fn(|_| (rng.next_u64() % 20) as f64)
+    }
+    fn sorted(mut points : Vec<[f64; 3]>) -> Vec<[f64; 3]>{
+        points.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        points
+    }
+    #[test]
+    fn example_points(){
+        let tree: KdTree<2> = [[2.0, 3.0], [5.0, 4.0], [9.0, 6.0], [4.0, 7.0], [8.0, 1.0], [7.0, 2.0]].into_iter().collect();
+        assert_eq!(tree.height() , 3);
+        let nearest: Vec<(f64, &[f64; 2])> = tree.nearest(&[9.0, 2.0], 2);
+        assert_eq!(nearest , vec![(1.4142135623730951, &[8.0, 1.0]), (2.0, &[7.0, 2.0])]);
+        assert_eq!(tree.nearest(&[0.0, 0.0], 10).len() , 6);
+        assert_eq!(tree.range(&[4.0, 2.0], &[7.0, 7.0]).len() , 3);
+        assert!(KdTree::<2>::new().nearest(&[0.0, 0.0], 1).is_empty());
+        assert_eq!(tree.within_radius(&[5.0, 4.0], 0.0) , vec![&[5.0, 4.0]]);
+        assert!(tree.within_radius(&[5.0, 4.0], -3.0).is_empty());
+        assert!(tree.within_radius(&[5.0, 4.0], f64::NAN).is_empty());
+    }
+    #[test]
+    fn matches_brute_force(){
+        let mut rng: XorShift64 = XorShift64::new(50);
+        let initial: Vec<[f64; 3]> = (0..300).map(|_| random_point(&mut rng)).collect();
+        let mut tree: KdTree<3> = KdTree::from_points(initial.clone());
+        //Construcción balanceada: altura mínima para 300 nodos
+        assert_eq!(tree.height() , 9);
+        let mut points: Vec<[f64; 3]> = initial;
+        for round in 0..200{
+            let point: [f64; 3] = random_point(&mut rng);
+            tree.insert(point);
+            points.push(point);
+            let query: [f64; 3] = random_point(&mut rng);
+            let k: usize = 1 + round % 12;
+            //Con empates los puntos pueden variar, las distancias no
+            let mut distances: Vec<f64> = points.iter().map(|point| squared_distance(point, &query).sqrt()).collect();
+            distances.sort_by(f64::total_cmp);
+            let found: Vec<f64> = tree.nearest(&query, k).into_iter().map(|(distance, _)| distance).collect();
+            assert_eq!(found , distances[..k]);
+            let radius: f64 = (rng.next_u64() % 8) as f64;
+            let brute: Vec<[f64; 3]> = points.iter().filter(|point| squared_distance(point, &query) <= radius * radius).copied().collect();
+            assert_eq!(sorted(tree.within_radius(&query, radius).into_iter().copied().collect()) , sorted(brute));
+            let (a, b) = (random_point::<3>(&mut rng), random_point::<3>(&mut rng));
+            let lower: [f64; 3] = std::array::from_fn(|axis| a[axis].min(b[axis]));
+            let upper: [f64; 3] = std::array::from_fn(|axis| a[axis].max(b[axis]));
+            let inside: Vec<[f64; 3]> = points.iter().filter(|point| (0..3).all(|axis| lower[axis] <= point[axis] && point[axis] <= upper[axis])).copied().collect();
+            assert_eq!(sorted(tree.range(&lower, &upper).into_iter().copied().collect()) , sorted(inside));
+        }
+        assert_eq!(tree.len() , 500);
+    }
+}
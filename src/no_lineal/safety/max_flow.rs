@@ -0,0 +1,461 @@
+//! ### Flujo máximo y emparejamiento bipartito
+//! Cada arista del grafo tiene una capacidad entera, el flujo máximo de `source` a `sink` se calcula sobre la red
+//! residual: cada arista se representa con un arco de ida y uno de vuelta emparejados (`arco ^ 1`), empujar flujo por
+//! uno libera la misma cantidad en el otro.
+//! ```text
+//!   arista u -> v (cap 5)            residual tras empujar 3
+//!                                     u --2--> v
+//!   u --5--> v   u <--0-- v           u <--3-- v
+//! ```
+//! - Edmonds-Karp: aumenta por el camino residual más corto (BFS con la `Queue` del crate), O(V * E²).
+//! - Dinic: arma niveles por BFS y satura un flujo bloqueante sobre ellos, O(V² * E) y O(E * √V) en redes unitarias.
+//! - Corte mínimo: los nodos alcanzables desde `source` en la red residual final, las aristas que salen de ese lado
+//!   suman exactamente el flujo máximo.
+//! - Hopcroft-Karp: emparejamiento máximo en un grafo bipartito aumentando por varios caminos mínimos disjuntos en cada
+//!   fase, O(E * √V).
+//!
+//! En un grafo no dirigido ambos arcos de una arista empiezan con la capacidad completa.
+use std::ops::Sub;
+use num::Zero;
+use crate::lineal::safety::queue::Queue;
+use super::graph::{EdgeId, Graph, GraphStorage, NodeId};
+use super::graph_algorithms::bipartition;
+
+#[derive(Debug, Clone)]
+struct Arc<C>{
+    to : usize,
+    residual : C
+}
+///Red residual indexada por la posición de los nodos, el arco `2 * i` es la ida de la arista `edges[i]`.
+struct Residual<C>{
+    arcs : Vec<Arc<C>>,
+    adjacency : Vec<Vec<usize>>,
+    edges : Vec<(EdgeId, C)>,
+    source : usize,
+    sink : usize
+}
+impl<C : Copy + Ord + Zero + Sub<Output = C>> Residual<C>{
+    fn new<N, E, S, F>(graph : &Graph<N, E, S> , source : NodeId , sink : NodeId , capacity : &F) -> Result<Self, String>
+    where S : GraphStorage , F : Fn(&E) -> C{
+        if !graph.contains_node(source) || !graph.contains_node(sink){
+            return Err(String::from("El origen y el destino deben existir en el grafo"));
+        }
+        if source == sink{
+            return Err(String::from("El origen y el destino deben ser distintos"));
+        }
+        let mut network: Residual<C> = Residual{
+            arcs : Vec::with_capacity(2 * graph.edge_count()),
+            adjacency : vec![Vec::new(); graph.node_bound()],
+            edges : Vec::with_capacity(graph.edge_count()),
+            source : source.index(),
+            sink : sink.index()
+        };
+        for edge in graph.edge_ids(){
+            let (from, to) = graph.edge_endpoints(edge).expect("id vivo");
+            let limit: C = capacity(graph.edge(edge).expect("id vivo"));
+            if limit < C::zero(){
+                return Err(format!("La arista {:?} tiene capacidad negativa", edge));
+            }
+            let back: C = if graph.is_directed() { C::zero() } else { limit };
+            network.adjacency[from.index()].push(network.arcs.len());
+            network.arcs.push(Arc{ to : to.index(), residual : limit });
+            network.adjacency[to.index()].push(network.arcs.len());
+            network.arcs.push(Arc{ to : from.index(), residual : back });
+            network.edges.push((edge, limit));
+        }
+        Ok(network)
+    }
+    ///Distancia en arcos con residual positivo desde `source`, `None` si no es alcanzable.
+    fn levels(&self) -> Vec<Option<usize>>{
+        let mut level: Vec<Option<usize>> = vec![None; self.adjacency.len()];
+        let mut queue: Queue<usize> = Queue::new();
+        level[self.source] = Some(0);
+        queue.push_back(self.source);
+        while let Some(node) = queue.pop_front(){
+            let depth: usize = level[node].expect("los nodos encolados tienen nivel");
+            for &arc in self.adjacency[node].iter(){
+                let next: usize = self.arcs[arc].to;
+                if level[next].is_none() && self.arcs[arc].residual > C::zero(){
+                    level[next] = Some(depth + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        level
+    }
+    fn augment(&mut self , path : &[usize]) -> C{
+        let bottleneck: C = path.iter().map(|&arc| self.arcs[arc].residual).min().expect("un camino tiene al menos un arco");
+        for &arc in path{
+            self.arcs[arc].residual = self.arcs[arc].residual - bottleneck;
+            self.arcs[arc ^ 1].residual = self.arcs[arc ^ 1].residual + bottleneck;
+        }
+        bottleneck
+    }
+    fn into_flow<N, E, S : GraphStorage>(self , graph : &Graph<N, E, S> , value : C) -> MaxFlow<C>{
+        let reachable: Vec<bool> = self.levels().into_iter().map(|level| level.is_some()).collect();
+        let mut flow: Vec<Option<C>> = vec![None; graph.edge_bound()];
+        let mut cut: Vec<EdgeId> = Vec::new();
+        for (index, &(edge, limit)) in self.edges.iter().enumerate(){
+            let (forward, backward) = (&self.arcs[2 * index], &self.arcs[2 * index + 1]);
+            //Si el flujo va en contra de la arista (solo en no dirigidos) la ida queda con más que su capacidad
+            let carried: C = if forward.residual <= limit { limit - forward.residual } else { forward.residual - limit };
+            flow[edge.index()] = Some(carried);
+            let (from, to) = (backward.to, forward.to);
+            if reachable[from] != reachable[to] && (reachable[from] || !graph.is_directed()){
+                cut.push(edge);
+            }
+        }
+        let source_side: Vec<NodeId> = graph.node_ids().filter(|node| reachable[node.index()]).collect();
+        MaxFlow{ value, flow, cut, source_side }
+    }
+}
+///### MaxFlow
+/// Valor del flujo máximo, el flujo por cada arista y un corte mínimo.
+#[derive(Debug, Clone)]
+pub struct MaxFlow<C>{
+    value : C,
+    flow : Vec<Option<C>>,
+    cut : Vec<EdgeId>,
+    source_side : Vec<NodeId>
+}
+impl<C : Copy> MaxFlow<C>{
+    pub fn value(&self) -> C{
+        self.value
+    }
+    ///Flujo que atraviesa la arista, en un grafo no dirigido es la magnitud sin importar el sentido.
+    pub fn flow(&self , edge : EdgeId) -> Option<C>{
+        self.flow.get(edge.index()).copied().flatten()
+    }
+    ///Aristas del corte mínimo, van del lado de `source` al de `sink` y sus capacidades suman `value`.
+    pub fn min_cut(&self) -> &[EdgeId]{
+        &self.cut
+    }
+    ///Nodos alcanzables desde `source` en la red residual final.
+    pub fn source_side(&self) -> &[NodeId]{
+        &self.source_side
+    }
+}
+///### Edmonds-Karp
+/// Flujo máximo aumentando por el camino residual con menos arcos.
+/// # Errores
+/// Si `source` o `sink` no existen, son iguales o alguna capacidad es negativa.
+pub fn edmonds_karp<N, E, S, C, F>(graph : &Graph<N, E, S> , source : NodeId , sink : NodeId , capacity : F) -> Result<MaxFlow<C>, String>
+where S : GraphStorage , C : Copy + Ord + Zero + Sub<Output = C> , F : Fn(&E) -> C{
+    let mut network: Residual<C> = Residual::new(graph, source, sink, &capacity)?;
+    let mut value: C = C::zero();
+    loop{
+        //BFS guardando el arco por el que se llegó a cada nodo
+        let mut through: Vec<Option<usize>> = vec![None; network.adjacency.len()];
+        let mut queue: Queue<usize> = Queue::new();
+        queue.push_back(network.source);
+        while let Some(node) = queue.pop_front(){
+            if node == network.sink{
+                break;
+            }
+            for &arc in network.adjacency[node].iter(){
+                let next: usize = network.arcs[arc].to;
+                if next != network.source && through[next].is_none() && network.arcs[arc].residual > C::zero(){
+                    through[next] = Some(arc);
+                    queue.push_back(next);
+                }
+            }
+        }
+        if through[network.sink].is_none(){
+            break;
+        }
+        let mut path: Vec<usize> = Vec::new();
+        let mut node: usize = network.sink;
+        while let Some(arc) = through[node]{
+            path.push(arc);
+            node = network.arcs[arc ^ 1].to;
+        }
+        value = value + network.augment(&path);
+    }
+    Ok(network.into_flow(graph, value))
+}
+///### Dinic
+/// Flujo máximo por fases: cada fase arma el grafo de niveles y lo satura con caminos encontrados por un DFS iterativo
+/// que recuerda el siguiente arco por probar de cada nodo, así cada arco muerto se descarta una sola vez por fase.
+/// # Errores
+/// Si `source` o `sink` no existen, son iguales o alguna capacidad es negativa.
+pub fn dinic<N, E, S, C, F>(graph : &Graph<N, E, S> , source : NodeId , sink : NodeId , capacity : F) -> Result<MaxFlow<C>, String>
+where S : GraphStorage , C : Copy + Ord + Zero + Sub<Output = C> , F : Fn(&E) -> C{
+    let mut network: Residual<C> = Residual::new(graph, source, sink, &capacity)?;
+    let mut value: C = C::zero();
+    loop{
+        let level: Vec<Option<usize>> = network.levels();
+        if level[network.sink].is_none(){
+            break;
+        }
+        let mut next_arc: Vec<usize> = vec![0; network.adjacency.len()];
+        let mut path: Vec<usize> = Vec::new();
+        let mut node: usize = network.source;
+        loop{
+            if node == network.sink{
+                value = value + network.augment(&path);
+                path.clear();
+                node = network.source;
+                continue;
+            }
+            let usable = |arc : usize| {
+                let next: usize = network.arcs[arc].to;
+                network.arcs[arc].residual > C::zero() && level[next].is_some() && level[next] == level[node].map(|depth| depth + 1)
+            };
+            while next_arc[node] < network.adjacency[node].len() && !usable(network.adjacency[node][next_arc[node]]){
+                next_arc[node] += 1;
+            }
+            if let Some(&arc) = network.adjacency[node].get(next_arc[node]){
+                path.push(arc);
+                node = network.arcs[arc].to;
+                continue;
+            }
+            //Callejón sin salida: retroceder y descartar el arco que llevó hasta aquí
+            let Some(arc) = path.pop() else { break };
+            node = network.arcs[arc ^ 1].to;
+            next_arc[node] += 1;
+        }
+    }
+    Ok(network.into_flow(graph, value))
+}
+///### Matching
+/// Pares `(izquierda, derecha, arista)` de un emparejamiento.
+#[derive(Debug, Clone)]
+pub struct Matching{
+    pub pairs : Vec<(NodeId, NodeId, EdgeId)>,
+    mate : Vec<Option<NodeId>>
+}
+impl Matching{
+    pub fn len(&self) -> usize{
+        self.pairs.len()
+    }
+    pub fn empty(&self) -> bool{
+        self.pairs.is_empty()
+    }
+    ///Pareja de `node` en cualquiera de los dos lados.
+    pub fn mate(&self , node : NodeId) -> Option<NodeId>{
+        self.mate.get(node.index()).copied().flatten()
+    }
+}
+///### Hopcroft-Karp
+/// Emparejamiento máximo de un grafo bipartito, los lados se obtienen con `bipartition` y la dirección de las aristas
+/// se ignora. Cada fase calcula por BFS la distancia de cada nodo izquierdo a los libres y luego aumenta por caminos
+/// alternantes que respetan esas distancias.
+/// # Errores
+/// Si el grafo no es bipartito.
+pub fn hopcroft_karp<N, E, S : GraphStorage>(graph : &Graph<N, E, S>) -> Result<Matching, String>{
+    let sides = bipartition(graph).map_err(|cycle| format!("El grafo no es bipartito, tiene el ciclo impar {:?}", cycle))?;
+    let bound: usize = graph.node_bound();
+    let mut is_left: Vec<bool> = vec![false; bound];
+    for node in sides.left.iter(){
+        is_left[node.index()] = true;
+    }
+    let mut adjacency: Vec<Vec<(NodeId, EdgeId)>> = vec![Vec::new(); bound];
+    for edge in graph.edge_ids(){
+        let (from, to) = graph.edge_endpoints(edge).expect("id vivo");
+        let (left, right) = if is_left[from.index()] { (from, to) } else { (to, from) };
+        adjacency[left.index()].push((right, edge));
+    }
+    let mut left_mate: Vec<Option<(NodeId, EdgeId)>> = vec![None; bound];
+    let mut right_mate: Vec<Option<NodeId>> = vec![None; bound];
+    loop{
+        //Distancias alternantes desde los nodos izquierdos libres
+        let mut distance: Vec<Option<usize>> = vec![None; bound];
+        let mut queue: Queue<NodeId> = Queue::new();
+        for &node in sides.left.iter().filter(|node| left_mate[node.index()].is_none()){
+            distance[node.index()] = Some(0);
+            queue.push_back(node);
+        }
+        let mut augmentable: bool = false;
+        while let Some(node) = queue.pop_front(){
+            let depth: usize = distance[node.index()].expect("los nodos encolados tienen distancia");
+            for &(right, _) in adjacency[node.index()].iter(){
+                match right_mate[right.index()]{
+                    None => augmentable = true,
+                    Some(next) if distance[next.index()].is_none() => {
+                        distance[next.index()] = Some(depth + 1);
+                        queue.push_back(next);
+                    },
+                    Some(_) => {}
+                }
+            }
+        }
+        if !augmentable{
+            break;
+        }
+        let mut next_arc: Vec<usize> = vec![0; bound];
+        let free: Vec<NodeId> = sides.left.iter().copied().filter(|node| left_mate[node.index()].is_none()).collect();
+        for start in free{
+            let mut path: Vec<NodeId> = vec![start];
+            while let Some(&node) = path.last(){
+                let Some(&(right, _)) = adjacency[node.index()].get(next_arc[node.index()]) else {
+                    //Sin caminos por este nodo durante la fase
+                    distance[node.index()] = None;
+                    path.pop();
+                    if let Some(previous) = path.last(){
+                        next_arc[previous.index()] += 1;
+                    }
+                    continue;
+                };
+                match right_mate[right.index()]{
+                    None => {
+                        for &member in path.iter(){
+                            let (right, edge) = adjacency[member.index()][next_arc[member.index()]];
+                            left_mate[member.index()] = Some((right, edge));
+                            right_mate[right.index()] = Some(member);
+                        }
+                        break;
+                    },
+                    Some(next) if distance[next.index()].is_some() && distance[next.index()] == distance[node.index()].map(|depth| depth + 1) => path.push(next),
+                    Some(_) => next_arc[node.index()] += 1
+                }
+            }
+        }
+    }
+    let mut mate: Vec<Option<NodeId>> = vec![None; bound];
+    let mut pairs: Vec<(NodeId, NodeId, EdgeId)> = Vec::new();
+    for &left in sides.left.iter(){
+        if let Some((right, edge)) = left_mate[left.index()]{
+            mate[left.index()] = Some(right);
+            mate[right.index()] = Some(left);
+            pairs.push((left, right, edge));
+        }
+    }
+    Ok(Matching{ pairs, mate })
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::no_lineal::safety::adjacency_matrix::AdjacencyMatrix;
+    use crate::random::XorShift64;
+
+    ///Red del capitulo de flujo de Cormen et al., su flujo máximo es 23.
+    fn textbook() -> (Graph<usize, u32>, NodeId, NodeId){
+        let mut graph: Graph<usize, u32> = Graph::directed();
+        let ids: Vec<NodeId> = (0..6).map(|value| graph.add_node(value)).collect();
+        for (from, to, capacity) in [(0, 1, 16), (0, 2, 13), (2, 1, 4), (1, 3, 12), (3, 2, 9), (2, 4, 14), (4, 3, 7), (3, 5, 20), (4, 5, 4)]{
+            graph.add_edge(ids[from], ids[to], capacity).unwrap();
+        }
+        (graph, ids[0], ids[5])
+    }
+    ///Capacidades respetadas, conservación en los nodos internos y corte de igual capacidad que el flujo.
+    fn check_flow<S : GraphStorage>(graph : &Graph<usize, u32, S> , source : NodeId , sink : NodeId , flow : &MaxFlow<u32>){
+        let mut balance: Vec<i64> = vec![0; graph.node_bound()];
+        for edge in graph.edge_ids(){
+            let (from, to) = graph.edge_endpoints(edge).unwrap();
+            let carried: u32 = flow.flow(edge).unwrap();
+            assert!(carried <= *graph.edge(edge).unwrap());
+            balance[from.index()] -= carried as i64;
+            balance[to.index()] += carried as i64;
+        }
+        for node in graph.node_ids().filter(|&node| node != source && node != sink){
+            assert_eq!(balance[node.index()] , 0);
+        }
+        assert_eq!(balance[sink.index()] , flow.value() as i64);
+        let cut: u32 = flow.min_cut().iter().map(|&edge| *graph.edge(edge).unwrap()).sum();
+        assert_eq!(cut , flow.value());
+        assert!(flow.source_side().contains(&source));
+        assert!(!flow.source_side().contains(&sink));
+    }
+    #[test]
+    fn textbook_network(){
+        let (graph, source, sink) = textbook();
+        for flow in [edmonds_karp(&graph, source, sink, |capacity| *capacity).unwrap(), dinic(&graph, source, sink, |capacity| *capacity).unwrap()]{
+            assert_eq!(flow.value() , 23);
+            check_flow(&graph, source, sink, &flow);
+        }
+        assert!(dinic(&graph, source, source, |capacity| *capacity).is_err());
+        assert!(edmonds_karp(&graph, source, NodeId(99), |capacity| *capacity).is_err());
+    }
+    #[test]
+    fn undirected_network(){
+        let mut graph: Graph<usize, u32, AdjacencyMatrix> = Graph::undirected();
+        let ids: Vec<NodeId> = (0..4).map(|value| graph.add_node(value)).collect();
+        //La arista 1 -- 2 se usa en sentido contrario a como se agregó
+        for (from, to, capacity) in [(0, 2, 5), (2, 1, 4), (1, 3, 6), (2, 3, 2)]{
+            graph.add_edge(ids[from], ids[to], capacity).unwrap();
+        }
+        for flow in [edmonds_karp(&graph, ids[0], ids[3], |capacity| *capacity).unwrap(), dinic(&graph, ids[0], ids[3], |capacity| *capacity).unwrap()]{
+            assert_eq!(flow.value() , 5);
+            assert_eq!(flow.flow(graph.find_edge(ids[1], ids[2]).unwrap()) , Some(3));
+            let cut: u32 = flow.min_cut().iter().map(|&edge| *graph.edge(edge).unwrap()).sum();
+            assert_eq!(cut , 5);
+        }
+    }
+    #[test]
+    fn algorithms_agree_on_random_networks(){
+        for seed in 1..=20{
+            let mut rng: XorShift64 = XorShift64::new(seed);
+            let mut graph: Graph<usize, u32> = Graph::directed();
+            let ids: Vec<NodeId> = (0..15).map(|value| graph.add_node(value)).collect();
+            for _ in 0..45{
+                let (from, to) = (rng.next_u64() as usize % 15, rng.next_u64() as usize % 15);
+                graph.add_edge(ids[from], ids[to], (rng.next_u64() % 10) as u32).unwrap();
+            }
+            let slow: MaxFlow<u32> = edmonds_karp(&graph, ids[0], ids[14], |capacity| *capacity).unwrap();
+            let fast: MaxFlow<u32> = dinic(&graph, ids[0], ids[14], |capacity| *capacity).unwrap();
+            assert_eq!(slow.value() , fast.value());
+            check_flow(&graph, ids[0], ids[14], &slow);
+            check_flow(&graph, ids[0], ids[14], &fast);
+        }
+    }
+    #[test]
+    fn known_matchings(){
+        //Solo L3 llega a R2 y L1 a R0, el resto se acomoda alrededor: emparejamiento perfecto
+        for (edges, expected) in [
+            (vec![(0, 0), (0, 1), (1, 0), (2, 1), (2, 2), (2, 3), (3, 2)], 4),
+            (vec![(0, 0), (0, 1), (1, 0), (1, 1), (2, 0), (2, 1), (3, 2)], 3)
+        ]{
+            let mut graph: Graph<(), ()> = Graph::undirected();
+            let left: Vec<NodeId> = (0..4).map(|_| graph.add_node(())).collect();
+            let right: Vec<NodeId> = (0..4).map(|_| graph.add_node(())).collect();
+            for (from, to) in edges{
+                graph.add_edge(left[from], right[to], ()).unwrap();
+            }
+            let matching: Matching = hopcroft_karp(&graph).unwrap();
+            assert_eq!(matching.len() , expected);
+            for &(first, second, edge) in matching.pairs.iter(){
+                assert_eq!(matching.mate(first) , Some(second));
+                assert_eq!(matching.mate(second) , Some(first));
+                let (from, to) = graph.edge_endpoints(edge).unwrap();
+                assert!((from, to) == (first, second) || (to, from) == (first, second));
+            }
+        }
+        let mut triangle: Graph<(), ()> = Graph::undirected();
+        let ids: Vec<NodeId> = (0..3).map(|_| triangle.add_node(())).collect();
+        for index in 0..3{
+            triangle.add_edge(ids[index], ids[(index + 1) % 3], ()).unwrap();
+        }
+        assert!(hopcroft_karp(&triangle).is_err());
+    }
+    #[test]
+    fn matching_size_equals_unit_flow(){
+        for seed in 1..=20{
+            let mut rng: XorShift64 = XorShift64::new(seed);
+            let mut graph: Graph<(), ()> = Graph::directed();
+            let left: Vec<NodeId> = (0..12).map(|_| graph.add_node(())).collect();
+            let right: Vec<NodeId> = (0..10).map(|_| graph.add_node(())).collect();
+            let mut network: Graph<(), u32> = Graph::directed();
+            let nodes: Vec<NodeId> = (0..24).map(|_| network.add_node(())).collect();
+            let (source, sink) = (nodes[22], nodes[23]);
+            for index in 0..12{
+                network.add_edge(source, nodes[index], 1).unwrap();
+            }
+            for index in 0..10{
+                network.add_edge(nodes[12 + index], sink, 1).unwrap();
+            }
+            for _ in 0..25{
+                let (from, to) = (rng.next_u64() as usize % 12, rng.next_u64() as usize % 10);
+                //Algunas aristas van de derecha a izquierda, la dirección no importa en el emparejamiento
+                if rng.next_u64() % 2 == 0{
+                    graph.add_edge(left[from], right[to], ()).unwrap();
+                }else{
+                    graph.add_edge(right[to], left[from], ()).unwrap();
+                }
+                network.add_edge(nodes[from], nodes[12 + to], 1).unwrap();
+            }
+            let matching: Matching = hopcroft_karp(&graph).unwrap();
+            let flow: MaxFlow<u32> = dinic(&network, source, sink, |capacity| *capacity).unwrap();
+            assert_eq!(matching.len() as u32 , flow.value());
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! ### Sobre los montículos de emparejamiento (Pairing Heap)
+//! Un pairing heap es un árbol general donde cada nodo es menor que sus hijos, los hijos de un nodo se guardan como una
+//! lista enlazada (`child` apunta al primero y `sibling` al siguiente hermano).
+//! - `push` y `meld` solo enlazan dos raíces: la mayor pasa a ser el primer hijo de la menor. `meld` además copia el
+//!   arena más chico dentro del más grande, O(min(n, m)) (ver `priority_queue`).
+//! - `pop_min` retira la raíz y combina a sus hijos en dos pasadas, primero por pares de izquierda a derecha y luego
+//!   acumulando de derecha a izquierda.
+//! - `decrease_key` corta el subárbol del nodo y lo enlaza con la raíz.
+//! ```text
+//!        2                    pop_min            pares: (5 , 4) (9 , 7)        derecha a izquierda
+//!    /  |  |  \              ========>            4         7                       4
+//!   5   4  9   7                                  |         |                     /   \
+//!                                                 5         9                    7     5
+//!                                                                                |
+//!                                                                                9
+//! ```
+//! `prev` apunta al padre en el primer hijo y al hermano anterior en el resto, así cortar un nodo es O(1).
+use std::mem;
+use super::priority_queue::{Handle, HeapId, PriorityQueue};
+
+#[derive(Debug)]
+struct PairingNode<T>{
+    value : Option<T>,
+    child : Option<usize>,
+    sibling : Option<usize>,
+    prev : Option<usize>,
+    generation : usize
+}
+///### PairingHeap
+/// Montículo de minimos con los nodos en un arena, los nodos retirados se reutilizan en los siguientes `push`.
+#[derive(Debug)]
+pub struct PairingHeap<T>{
+    nodes : Vec<PairingNode<T>>,
+    free : Vec<usize>,
+    root : Option<usize>,
+    len : usize,
+    id : HeapId
+}
+impl<T : Ord> Default for PairingHeap<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T : Ord> PairingHeap<T>{
+    pub fn new() -> Self{
+        Self{ nodes : Vec::new(), free : Vec::new(), root : None, len : 0, id : HeapId::new() }
+    }
+    fn value(&self , node : usize) -> &T{
+        self.nodes[node].value.as_ref().expect("Los nodos enlazados en el montículo siempre tienen valor")
+    }
+    fn resolve(&self , handle : Handle) -> Option<usize>{
+        let node: usize = self.id.offset_of(handle.heap)? + handle.index;
+        self.nodes.get(node).filter(|n| n.value.is_some() && n.generation == handle.generation).map(|_| node)
+    }
+    ///Nodo suelto con `value`, reutiliza uno libre manteniendo su generación o agrega uno nuevo al arena.
+    fn allocate(&mut self , value : T) -> usize{
+        let node: usize = self.free.pop().unwrap_or(self.nodes.len());
+        let generation: usize = self.nodes.get(node).map_or(0, |n| n.generation);
+        let fresh: PairingNode<T> = PairingNode{ value : Some(value), child : None, sibling : None, prev : None, generation };
+        if node == self.nodes.len(){
+            self.nodes.push(fresh);
+        }else {
+            self.nodes[node] = fresh;
+        }
+        node
+    }
+    ///Enlaza dos raíces sueltas, la mayor pasa a ser el primer hijo de la menor, retorna la nueva raíz.
+    fn link(&mut self , a : usize , b : usize) -> usize{
+        let (parent, child): (usize, usize) = if self.value(b) < self.value(a) { (b, a) } else { (a, b) };
+        let first: Option<usize> = self.nodes[parent].child;
+        if let Some(first) = first{
+            self.nodes[first].prev = Some(child);
+        }
+        self.nodes[child].sibling = first;
+        self.nodes[child].prev = Some(parent);
+        self.nodes[parent].child = Some(child);
+        parent
+    }
+    ///Separa al nodo de la lista de hermanos en la que se encuentra junto con todo su subárbol.
+    fn cut(&mut self , node : usize){
+        let prev: usize = match self.nodes[node].prev {
+            Some(prev) => prev,
+            None => return
+        };
+        let sibling: Option<usize> = self.nodes[node].sibling;
+        if self.nodes[prev].child == Some(node){
+            self.nodes[prev].child = sibling;
+        }else {
+            self.nodes[prev].sibling = sibling;
+        }
+        if let Some(sibling) = sibling{
+            self.nodes[sibling].prev = Some(prev);
+        }
+        self.nodes[node].prev = None;
+        self.nodes[node].sibling = None;
+    }
+    ///Combinación en dos pasadas de la lista de hijos que comienza en `first`.
+    fn merge_pairs(&mut self , first : Option<usize>) -> Option<usize>{
+        let mut children: Vec<usize> = Vec::new();
+        let mut current: Option<usize> = first;
+        while let Some(node) = current{
+            current = self.nodes[node].sibling;
+            self.nodes[node].sibling = None;
+            self.nodes[node].prev = None;
+            children.push(node);
+        }
+        let mut pairs: Vec<usize> = Vec::with_capacity(children.len() / 2 + 1);
+        for pair in children.chunks(2){
+            match pair {
+                [a, b] => pairs.push(self.link(*a, *b)),
+                [a] => pairs.push(*a),
+                _ => unreachable!()
+            }
+        }
+        let mut root: Option<usize> = pairs.pop();
+        while let Some(node) = pairs.pop(){
+            root = root.map(|root| self.link(node, root));
+        }
+        root
+    }
+}
+impl<T : Ord> PriorityQueue<T> for PairingHeap<T>{
+    type Handle = Handle;
+    fn push(&mut self , value : T) -> Handle{
+        let node: usize = self.allocate(value);
+        self.root = Some(match self.root {
+            Some(root) => self.link(root, node),
+            None => node
+        });
+        self.len += 1;
+        self.id.handle(node, self.nodes[node].generation)
+    }
+    fn pop_min(&mut self) -> Option<T>{
+        let root: usize = self.root?;
+        let value: Option<T> = self.nodes[root].value.take();
+        let first: Option<usize> = self.nodes[root].child.take();
+        self.root = self.merge_pairs(first);
+        //La nueva generación invalida a los manejadores que todavía apuntan al nodo
+        self.nodes[root].generation += 1;
+        self.free.push(root);
+        self.len -= 1;
+        value
+    }
+    fn peek_min(&self) -> Option<&T>{
+        self.root.map(|root| self.value(root))
+    }
+    fn meld(&mut self , mut other : Self){
+        //Se copia el arena más chico dentro del más grande, cada elemento cambia de arena a lo más O(log n) veces
+        if other.nodes.len() > self.nodes.len(){
+            mem::swap(self, &mut other);
+        }
+        if other.len == 0{
+            return;
+        }
+        let offset: usize = self.nodes.len();
+        let shift = |index : Option<usize>| index.map(|index| index + offset);
+        for node in other.nodes{
+            self.nodes.push(PairingNode{
+                value : node.value,
+                child : shift(node.child),
+                sibling : shift(node.sibling),
+                prev : shift(node.prev),
+                generation : node.generation
+            });
+        }
+        self.free.extend(other.free.into_iter().map(|node| node + offset));
+        self.id.absorb(other.id, offset);
+        let other_root: usize = other.root.expect("Un montículo con elementos tiene raíz") + offset;
+        self.root = Some(match self.root {
+            Some(root) => self.link(root, other_root),
+            None => other_root
+        });
+        self.len += other.len;
+    }
+    fn decrease_key(&mut self , handle : Handle , value : T) -> Result<(), String>{
+        let node: usize = self.resolve(handle).ok_or("El manejador no pertenece al montículo")?;
+        if value > *self.value(node){
+            return Err(String::from("El nuevo valor es mayor al actual"));
+        }
+        self.nodes[node].value = Some(value);
+        let root: usize = self.root.expect("Un montículo con elementos tiene raíz");
+        if node != root{
+            self.cut(node);
+            self.root = Some(self.link(root, node));
+        }
+        Ok(())
+    }
+    fn len(&self) -> usize{
+        self.len
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    crate::no_lineal::priority_queue_tests::priority_queue_tests!(PairingHeap, arena: |heap| heap.nodes.len());
+    #[test]
+    fn freed_node_is_reused(){
+        let mut heap : PairingHeap<i32> = PairingHeap::new();
+        let stale : Handle = heap.push(1);
+        heap.pop_min();
+        let fresh : Handle = heap.push(2);
+        assert_eq!(heap.nodes.len() , 1);
+        assert_ne!(stale , fresh);
+        assert!(heap.decrease_key(stale, 0).is_err());
+    }
+}
@@ -0,0 +1,91 @@
+//! ### Colas de prioridad fusionables
+//! Contrato común de los montículos fusionables del crate (`BinomialHeap`, `PairingHeap` y `FibonacciHeap`), todos son
+//! montículos de minimos y permiten fusionar (`meld`) dos montículos y disminuir la prioridad de un elemento ya insertado.
+//!
+//! | Operación      | Binomial  | Pairing (amortizado) | Fibonacci (amortizado) |
+//! |----------------|-----------|----------------------|------------------------|
+//! | `push`         | O(log n)  | O(1)                 | O(1)                   |
+//! | `peek_min`     | O(log n)  | O(1)                 | O(1)                   |
+//! | `pop_min`      | O(log n)  | O(log n)             | O(log n)               |
+//! | `meld`         | O(log n) + copia | O(1) + copia  | O(1) + copia           |
+//! | `decrease_key` | O(log n)  | o(log n)             | O(1)                   |
+//!
+//! Las versiones safe guardan los nodos en un arena (`Vec`), fusionar copia el arena más chico al final del más grande,
+//! así la "copia" de la tabla cuesta O(min(n, m)) y `meld` no es O(1). Un elemento solo se copia cuando su arena se
+//! suma a otro al menos igual de grande, por lo que en cualquier secuencia de fusiones cada elemento se copia O(log n)
+//! veces. La versión con punteros crudos `unsafety::fibonacci_heap` sí fusiona en O(1).
+//!
+//! Cada montículo tiene un `HeapId` que recuerda desde qué posición quedaron los elementos absorbidos, así los
+//! manejadores entregados por cualquiera de los dos montículos siguen siendo validos despues de `meld`. Las posiciones
+//! que libera `pop_min` se reutilizan en el siguiente `push`, así el arena crece con los elementos vivos y no con el
+//! total de inserciones. Cada reutilización incrementa la `generation` de la posición y un manejador viejo, cuya
+//! generación ya no coincide, es rechazado en lugar de llegar al elemento nuevo.
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+///### PriorityQueue
+/// Cola de prioridad de minimos que admite fusión y disminución de prioridad.
+pub trait PriorityQueue<T : Ord>{
+    ///Identifica un elemento insertado, deja de ser valido cuando el elemento sale del montículo.
+    type Handle : Copy;
+    ///Inserta un elemento y retorna su manejador.
+    fn push(&mut self , value : T) -> Self::Handle;
+    ///Elimina y retorna el menor elemento.
+    fn pop_min(&mut self) -> Option<T>;
+    ///El menor elemento sin retirarlo.
+    fn peek_min(&self) -> Option<&T>;
+    ///Absorbe todos los elementos de `other`, los manejadores de `other` pasan a referirse a este montículo.
+    fn meld(&mut self , other : Self) where Self : Sized;
+    ///### Decrease key
+    /// Reemplaza el valor de `handle` por uno menor o igual.
+    /// # Errores
+    /// Si el manejador no pertenece al montículo (o su elemento ya salió) o si el nuevo valor es mayor al actual.
+    fn decrease_key(&mut self , handle : Self::Handle , value : T) -> Result<(), String>;
+    fn len(&self) -> usize;
+    fn empty(&self) -> bool{
+        self.len() == 0
+    }
+}
+///Manejador de los montículos safe, el `index` es relativo al montículo `heap` que lo entregó.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Handle{
+    pub(crate) heap : usize,
+    pub(crate) index : usize,
+    ///Generación de la posición `index` cuando se entregó el manejador.
+    pub(crate) generation : usize
+}
+static NEXT_HEAP_ID : AtomicUsize = AtomicUsize::new(0);
+///Identidad de un montículo y de los montículos que absorbió junto con el desplazamiento de sus indices.
+#[derive(Debug)]
+pub(crate) struct HeapId{
+    id : usize,
+    absorbed : HashMap<usize, usize>
+}
+impl HeapId{
+    pub(crate) fn new() -> Self{
+        Self{
+            id : NEXT_HEAP_ID.fetch_add(1, Ordering::Relaxed),
+            absorbed : HashMap::new()
+        }
+    }
+    pub(crate) fn handle(&self , index : usize , generation : usize) -> Handle{
+        Handle{ heap : self.id, index, generation }
+    }
+    pub(crate) fn id(&self) -> usize{
+        self.id
+    }
+    ///Desplazamiento que hay que sumar a los indices entregados por el montículo `heap`, `None` si no le pertenece.
+    pub(crate) fn offset_of(&self , heap : usize) -> Option<usize>{
+        if heap == self.id{
+            return Some(0);
+        }
+        self.absorbed.get(&heap).copied()
+    }
+    ///Registra que los elementos de `other` (y los que este había absorbido) quedaron desde la posición `offset`.
+    pub(crate) fn absorb(&mut self , other : HeapId , offset : usize){
+        self.absorbed.insert(other.id, offset);
+        for (heap, inner_offset) in other.absorbed{
+            self.absorbed.insert(heap, inner_offset + offset);
+        }
+    }
+}
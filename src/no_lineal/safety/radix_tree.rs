@@ -0,0 +1,347 @@
+//! ### Sobre los Radix Tree (tries comprimidos)
+//! Un radix tree es un trie donde cada cadena de nodos con un solo hijo y sin valor se comprime en una única arista
+//! etiquetada con varios caracteres, así la cantidad de nodos queda acotada por el doble de la cantidad de claves.
+//! ```text
+//!   claves : "car" , "cas" , "cama" , "sol"
+//!
+//!              (raíz)
+//!          "ca"/    \"sol"
+//!            ( )    [ ]
+//!       "ma"/ "r"| \"s"
+//!         [ ]   [ ]  [ ]              [ ] = nodo con valor
+//! ```
+//! Al insertar una clave que diverge a mitad de una etiqueta la arista se parte en dos, y al eliminar una clave un
+//! nodo que queda sin valor y con un solo hijo se fusiona con él.
+//!
+//! Las etiquetas se guardan como bytes UTF-8: el orden de los bytes coincide con el de los caracteres, y un corte a
+//! mitad de un carácter solo ocurre en nodos internos, nunca en una clave completa.
+use std::fmt::{self, Debug};
+
+const ROOT: usize = 0;
+
+#[derive(Debug, Clone)]
+struct RadixNode<V>{
+    label : Vec<u8>,
+    ///Hijos ordenados por el primer byte de su etiqueta, que es distinto entre hermanos.
+    children : Vec<usize>,
+    value : Option<V>
+}
+impl<V> RadixNode<V>{
+    fn new(label : Vec<u8> , value : Option<V>) -> Self{
+        Self{ label, children : Vec::new(), value }
+    }
+}
+fn common_prefix(first : &[u8] , second : &[u8]) -> usize{
+    first.iter().zip(second).take_while(|(a, b)| a == b).count()
+}
+///### RadixTree
+/// Diccionario de cadenas a valores con aristas comprimidas.
+#[derive(Clone)]
+pub struct RadixTree<V>{
+    nodes : Vec<RadixNode<V>>,
+    free : Vec<usize>,
+    len : usize
+}
+impl<V> Default for RadixTree<V>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<V> RadixTree<V>{
+    pub fn new() -> Self{
+        Self{ nodes : vec![RadixNode::new(Vec::new(), None)], free : Vec::new(), len : 0 }
+    }
+    pub fn len(&self) -> usize{
+        self.len
+    }
+    pub fn empty(&self) -> bool{
+        self.len == 0
+    }
+    ///Nodos en uso incluyendo la raíz.
+    pub fn node_count(&self) -> usize{
+        self.nodes.len() - self.free.len()
+    }
+    pub fn clear(&mut self){
+        *self = Self::new();
+    }
+    fn allocate(&mut self , node : RadixNode<V>) -> usize{
+        match self.free.pop(){
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            },
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+    fn release(&mut self , index : usize){
+        self.nodes[index].label = Vec::new();
+        self.nodes[index].children = Vec::new();
+        self.nodes[index].value = None;
+        self.free.push(index);
+    }
+    ///Posición entre los hijos de `node` del que empieza con `byte`.
+    fn child(&self , node : usize , byte : u8) -> Result<usize, usize>{
+        self.nodes[node].children.binary_search_by_key(&byte, |&child| self.nodes[child].label[0])
+    }
+    ///Nodo cuya clave es exactamente `key`.
+    fn locate(&self , key : &[u8]) -> Option<usize>{
+        let mut node: usize = ROOT;
+        let mut rest: &[u8] = key;
+        while !rest.is_empty(){
+            let position: usize = self.child(node, rest[0]).ok()?;
+            let child: usize = self.nodes[node].children[position];
+            rest = rest.strip_prefix(self.nodes[child].label.as_slice())?;
+            node = child;
+        }
+        Some(node)
+    }
+    ///Inserta `value` en `key`, retorna el valor anterior si existía.
+    pub fn insert(&mut self , key : &str , value : V) -> Option<V>{
+        let mut node: usize = ROOT;
+        let mut rest: &[u8] = key.as_bytes();
+        while !rest.is_empty(){
+            let position: usize = match self.child(node, rest[0]){
+                Ok(position) => position,
+                Err(position) => {
+                    let leaf: usize = self.allocate(RadixNode::new(rest.to_vec(), Some(value)));
+                    self.nodes[node].children.insert(position, leaf);
+                    self.len += 1;
+                    return None;
+                }
+            };
+            let child: usize = self.nodes[node].children[position];
+            let shared: usize = common_prefix(&self.nodes[child].label, rest);
+            if shared < self.nodes[child].label.len(){
+                //Partir la arista: el nodo intermedio toma la parte común y el hijo conserva el resto
+                let suffix: Vec<u8> = self.nodes[child].label.split_off(shared);
+                let prefix: Vec<u8> = std::mem::replace(&mut self.nodes[child].label, suffix);
+                let middle: usize = self.allocate(RadixNode::new(prefix, None));
+                self.nodes[middle].children.push(child);
+                self.nodes[node].children[position] = middle;
+                node = middle;
+            }else{
+                node = child;
+            }
+            rest = &rest[shared..];
+        }
+        let previous: Option<V> = self.nodes[node].value.replace(value);
+        if previous.is_none(){
+            self.len += 1;
+        }
+        previous
+    }
+    pub fn get(&self , key : &str) -> Option<&V>{
+        self.nodes[self.locate(key.as_bytes())?].value.as_ref()
+    }
+    pub fn get_mut(&mut self , key : &str) -> Option<&mut V>{
+        let node: usize = self.locate(key.as_bytes())?;
+        self.nodes[node].value.as_mut()
+    }
+    pub fn contains_key(&self , key : &str) -> bool{
+        self.get(key).is_some()
+    }
+    ///Elimina `key`, luego quita el nodo si quedó sin hijos o lo fusiona con su único hijo.
+    pub fn remove(&mut self , key : &str) -> Option<V>{
+        let mut path: Vec<(usize, usize)> = Vec::new();
+        let mut node: usize = ROOT;
+        let mut rest: &[u8] = key.as_bytes();
+        while !rest.is_empty(){
+            let position: usize = self.child(node, rest[0]).ok()?;
+            let child: usize = self.nodes[node].children[position];
+            rest = rest.strip_prefix(self.nodes[child].label.as_slice())?;
+            path.push((node, position));
+            node = child;
+        }
+        let value: V = self.nodes[node].value.take()?;
+        self.len -= 1;
+        if let Some(&(parent, position)) = path.last(){
+            if self.nodes[node].children.is_empty(){
+                self.nodes[parent].children.remove(position);
+                self.release(node);
+                //El padre pudo quedar sin valor y con un solo hijo
+                if parent != ROOT{
+                    self.merge_single_child(parent);
+                }
+            }else{
+                self.merge_single_child(node);
+            }
+        }
+        Some(value)
+    }
+    ///Fusiona `node` con su hijo si no tiene valor y tiene exactamente uno.
+    fn merge_single_child(&mut self , node : usize){
+        if self.nodes[node].value.is_some() || self.nodes[node].children.len() != 1{
+            return;
+        }
+        let child: usize = self.nodes[node].children[0];
+        let label: Vec<u8> = std::mem::take(&mut self.nodes[child].label);
+        let children: Vec<usize> = std::mem::take(&mut self.nodes[child].children);
+        let value: Option<V> = self.nodes[child].value.take();
+        self.nodes[node].label.extend(label);
+        self.nodes[node].children = children;
+        self.nodes[node].value = value;
+        self.release(child);
+    }
+    ///Claves que empiezan con `prefix` en orden lexicográfico.
+    pub fn starts_with(&self , prefix : &str) -> Prefixed<'_, V>{
+        let mut node: usize = ROOT;
+        let mut key: Vec<u8> = Vec::new();
+        let mut rest: &[u8] = prefix.as_bytes();
+        while !rest.is_empty(){
+            let Ok(position) = self.child(node, rest[0]) else {
+                return Prefixed{ tree : self, stack : Vec::new() };
+            };
+            let child: usize = self.nodes[node].children[position];
+            let label: &[u8] = &self.nodes[child].label;
+            //El prefijo puede terminar a mitad de la etiqueta
+            if label.starts_with(rest){
+                rest = &[];
+            }else if let Some(remaining) = rest.strip_prefix(label){
+                rest = remaining;
+            }else{
+                return Prefixed{ tree : self, stack : Vec::new() };
+            }
+            key.extend_from_slice(label);
+            node = child;
+        }
+        Prefixed{ tree : self, stack : vec![(node, key)] }
+    }
+    ///Todas las claves en orden lexicográfico.
+    pub fn iter(&self) -> Prefixed<'_, V>{
+        self.starts_with("")
+    }
+    ///La clave más larga que es prefijo de `query`.
+    pub fn longest_prefix<'q>(&self , query : &'q str) -> Option<(&'q str, &V)>{
+        let mut node: usize = ROOT;
+        let mut consumed: usize = 0;
+        let mut best: Option<(&'q str, &V)> = self.nodes[ROOT].value.as_ref().map(|value| (&query[..0], value));
+        let bytes: &[u8] = query.as_bytes();
+        while consumed < bytes.len(){
+            let Ok(position) = self.child(node, bytes[consumed]) else { break };
+            let child: usize = self.nodes[node].children[position];
+            if !bytes[consumed..].starts_with(&self.nodes[child].label){
+                break;
+            }
+            consumed += self.nodes[child].label.len();
+            node = child;
+            //Un nodo con valor corresponde a una clave completa, por eso el corte cae en un limite de carácter
+            if let Some(value) = self.nodes[node].value.as_ref(){
+                best = Some((&query[..consumed], value));
+            }
+        }
+        best
+    }
+}
+///Recorrido en preorden de un subárbol, los hijos se apilan en orden inverso para salir de menor a mayor.
+pub struct Prefixed<'a, V>{
+    tree : &'a RadixTree<V>,
+    stack : Vec<(usize, Vec<u8>)>
+}
+impl<'a, V> Iterator for Prefixed<'a, V>{
+    type Item = (String, &'a V);
+    fn next(&mut self) -> Option<Self::Item>{
+        while let Some((node, key)) = self.stack.pop(){
+            let current: &'a RadixNode<V> = &self.tree.nodes[node];
+            for &child in current.children.iter().rev(){
+                let mut extended: Vec<u8> = key.clone();
+                extended.extend_from_slice(&self.tree.nodes[child].label);
+                self.stack.push((child, extended));
+            }
+            if let Some(value) = current.value.as_ref(){
+                return Some((String::from_utf8(key).expect("las claves completas son UTF-8 valido"), value));
+            }
+        }
+        None
+    }
+}
+impl<V : Debug> Debug for RadixTree<V>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+impl<'k, V> FromIterator<(&'k str, V)> for RadixTree<V>{
+    fn from_iter<I : IntoIterator<Item = (&'k str, V)>>(iter : I) -> Self{
+        let mut tree: RadixTree<V> = RadixTree::new();
+        tree.extend(iter);
+        tree
+    }
+}
+impl<'k, V> Extend<(&'k str, V)> for RadixTree<V>{
+    fn extend<I : IntoIterator<Item = (&'k str, V)>>(&mut self , iter : I){
+        for (key, value) in iter{
+            self.insert(key, value);
+        }
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use std::collections::BTreeMap;
+    use crate::no_lineal::safety::trie::Trie;
+    use crate::random::XorShift64;
+
+    #[test]
+    fn split_and_merge_edges(){
+        let mut tree: RadixTree<u32> = [("car", 1), ("cas", 2), ("cama", 3), ("sol", 4)].into_iter().collect();
+        //raíz, "ca", "r", "s", "ma" y "sol"
+        assert_eq!(tree.node_count() , 6);
+        assert_eq!(tree.get("ca") , None);
+        assert_eq!(tree.get("cama") , Some(&3));
+        let keys: Vec<String> = tree.starts_with("c").map(|(key, _)| key).collect();
+        assert_eq!(keys , vec!["cama", "car", "cas"]);
+        assert_eq!(tree.starts_with("so").count() , 1);
+        assert_eq!(tree.starts_with("sa").count() , 0);
+        assert_eq!(tree.longest_prefix("casamiento") , Some(("cas", &2)));
+        assert_eq!(tree.longest_prefix("cam") , None);
+        //Quitar "car" y "cas" deja "ca" con un solo hijo, que se fusiona en "cama"
+        tree.remove("car");
+        tree.remove("cas");
+        assert_eq!(tree.node_count() , 3);
+        assert_eq!(tree.get("cama") , Some(&3));
+        tree.insert("ca", 5);
+        assert_eq!(tree.node_count() , 4);
+        assert_eq!(tree.remove("ca") , Some(5));
+        assert_eq!(tree.node_count() , 3);
+        let unicode: RadixTree<u8> = [("ñandú", 1), ("ñu", 2)].into_iter().collect();
+        assert_eq!(unicode.iter().map(|(key, _)| key).collect::<Vec<String>>() , vec!["ñandú", "ñu"]);
+        assert_eq!(unicode.longest_prefix("ñuñoa") , Some(("ñu", &2)));
+    }
+    #[test]
+    fn matches_btree_map_with_fewer_nodes_than_trie(){
+        let mut rng: XorShift64 = XorShift64::new(21);
+        let mut tree: RadixTree<u64> = RadixTree::new();
+        let mut trie: Trie<u64> = Trie::new();
+        let mut expected: BTreeMap<String, u64> = BTreeMap::new();
+        for _ in 0..4000{
+            let length: usize = (rng.next_u64() % 8) as usize;
+            let key: String = (0..length).map(|_| (b'a' + (rng.next_u64() % 3) as u8) as char).collect();
+            let value: u64 = rng.next_u64();
+            if rng.next_u64() % 3 == 0{
+                assert_eq!(tree.remove(&key) , expected.remove(&key));
+                trie.remove(&key);
+            }else{
+                assert_eq!(tree.insert(&key, value) , expected.insert(key.clone(), value));
+                trie.insert(&key, value);
+            }
+            assert_eq!(tree.len() , expected.len());
+            //Cada nodo sin valor tiene al menos dos hijos
+            assert!(tree.node_count() <= 2 * tree.len() + 1);
+        }
+        assert!(tree.node_count() < trie.node_count());
+        let all: Vec<(String, &u64)> = tree.iter().collect();
+        assert_eq!(all , expected.iter().map(|(key, value)| (key.clone(), value)).collect::<Vec<_>>());
+        for prefix in ["", "a", "ab", "cab", "bbbbbbbbb"]{
+            let found: Vec<String> = tree.starts_with(prefix).map(|(key, _)| key).collect();
+            assert_eq!(found , expected.keys().filter(|key| key.starts_with(prefix)).cloned().collect::<Vec<String>>());
+        }
+        for query in ["abcabcab", "ccc", "b"]{
+            let expected_match: Option<(&str, &u64)> = (0..=query.len()).rev()
+                .find_map(|end| expected.get_key_value(&query[..end]).map(|(_, value)| (&query[..end], value)));
+            assert_eq!(tree.longest_prefix(query) , expected_match);
+            assert_eq!(trie.longest_prefix(query) , expected_match);
+        }
+    }
+}
@@ -81,6 +81,26 @@ where T : Integer + Clone + Copy + Display + Debug + Ord{
     }
     
 }
+///### Drop
+/// Los hijos se enlazan con `Rc<T>` y el padre con `Weak<T>`, por lo que cada nodo solo es mantenido vivo por su padre.
+/// Se desenlazan los hijos de cada nodo con una pila propia para no depender de la altura del arbol al liberarlo.
+impl<T> Drop for RedBlackTree<T>{
+    fn drop(&mut self){
+        let mut stack: Vec<Rc<RefCell<RbNode<T>>>> = Vec::new();
+        if let Some(root) = self.root.take(){
+            stack.push(root);
+        }
+        while let Some(node) = stack.pop(){
+            let mut node_borrow = node.borrow_mut();
+            if let Some(left) = node_borrow.left.take(){
+                stack.push(left);
+            }
+            if let Some(right) = node_borrow.right.take(){
+                stack.push(right);
+            }
+        }
+    }
+}
 #[cfg(test)]
 mod tests{
     use super::*;
@@ -104,4 +124,20 @@ mod tests{
             n.borrow_mut().parent = Some(Rc::downgrade(&root.as_ref().and_then(|r| {Some(r.clone())}).unwrap()))
         }
     }
-}
\ No newline at end of file
+    #[test]
+    fn drop_degenerate_million_nodes(){
+        let mut tree : RedBlackTree<i32> = RedBlackTree::new();
+        let mut root: Option<Rc<RefCell<RbNode<i32>>>> = None;
+        for i in (1..=1_000_000).rev(){
+            let node: Rc<RefCell<RbNode<i32>>> = Rc::new(RefCell::new(RbNode::new(i)));
+            if let Some(ref child) = root{
+                child.borrow_mut().parent = Some(Rc::downgrade(&node));
+            }
+            node.borrow_mut().right = root.take();
+            root = Some(node);
+        }
+        tree.root = root;
+        tree.size = 1_000_000;
+        drop(tree);
+    }
+}
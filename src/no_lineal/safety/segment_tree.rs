@@ -0,0 +1,416 @@
+//! ### Sobre los Segment Tree
+//! Un segment tree guarda en cada nodo la combinación de un rango contiguo del arreglo, la raíz cubre todo el arreglo
+//! y cada hijo la mitad de su padre. Cualquier rango se descompone en O(log n) nodos.
+//! ```text
+//!                      [0, 8) = 36
+//!               /                      \
+//!         [0, 4) = 10              [4, 8) = 26
+//!         /         \              /         \
+//!   [0, 2) = 3  [2, 4) = 7   [4, 6) = 11  [6, 8) = 15
+//!    /   \        /   \        /   \        /   \
+//!   1     2      3     4      5     6      7     8
+//! ```
+//! La operación se describe con un `Monoid`: un elemento neutro y una combinación asociativa (no necesariamente
+//! conmutativa, el orden de los elementos se respeta).
+//!
+//! - `SegmentTree` es la versión iterativa de abajo hacia arriba sobre un arreglo de `2n` nodos: actualización
+//!   puntual y consulta por rango en O(log n).
+//! - `LazySegmentTree` es recursiva sobre `4n` nodos y posterga las actualizaciones de rango (sumar o asignar) en
+//!   marcas perezosas que solo bajan a los hijos cuando una operación los necesita, ambas en O(log n).
+use std::marker::PhantomData;
+use std::ops::{Add, Bound, Mul, RangeBounds};
+use std::fmt::{self, Debug};
+use num::{Bounded, FromPrimitive, Zero};
+
+///Operación asociativa con elemento neutro: `combine(identity, a) == combine(a, identity) == a`.
+pub trait Monoid<T>{
+    fn identity() -> T;
+    fn combine(left : &T , right : &T) -> T;
+}
+///Suma, su neutro es cero.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sum;
+///Mínimo, su neutro es el mayor valor representable.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Min;
+///Máximo, su neutro es el menor valor representable.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Max;
+impl<T : Zero + Clone> Monoid<T> for Sum{
+    fn identity() -> T{
+        T::zero()
+    }
+    fn combine(left : &T , right : &T) -> T{
+        left.clone() + right.clone()
+    }
+}
+impl<T : Bounded + Ord + Clone> Monoid<T> for Min{
+    fn identity() -> T{
+        T::max_value()
+    }
+    fn combine(left : &T , right : &T) -> T{
+        left.min(right).clone()
+    }
+}
+impl<T : Bounded + Ord + Clone> Monoid<T> for Max{
+    fn identity() -> T{
+        T::min_value()
+    }
+    fn combine(left : &T , right : &T) -> T{
+        left.max(right).clone()
+    }
+}
+///Convierte un rango cualquiera en `[start, end)`, con las mismas reglas que indexar un slice.
+pub(crate) fn bounds<R : RangeBounds<usize>>(range : R , len : usize) -> (usize, usize){
+    let start: usize = match range.start_bound(){
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).expect("attempted to index slice from after maximum usize"),
+        Bound::Unbounded => 0
+    };
+    let end: usize = match range.end_bound(){
+        Bound::Included(&end) => end.checked_add(1).expect("attempted to index slice up to maximum usize"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len
+    };
+    assert!(start <= end && end <= len, "el rango {}..{} no cabe en una estructura de largo {}", start, end, len);
+    (start, end)
+}
+///### SegmentTree
+/// Las hojas viven en `tree[n..2n]` y el nodo `i` combina a `2i` y `2i + 1`.
+pub struct SegmentTree<T, Op>{
+    tree : Vec<T>,
+    len : usize,
+    operation : PhantomData<Op>
+}
+impl<T : Clone , Op : Monoid<T>> SegmentTree<T, Op>{
+    ///`len` posiciones con el neutro.
+    pub fn new(len : usize) -> Self{
+        Self{ tree : vec![Op::identity(); 2 * len], len, operation : PhantomData }
+    }
+    ///Construye el arbol en O(n).
+    pub fn from_slice(values : &[T]) -> Self{
+        let len: usize = values.len();
+        let mut tree: Vec<T> = Vec::with_capacity(2 * len);
+        tree.resize(len, Op::identity());
+        tree.extend_from_slice(values);
+        for index in (1..len).rev(){
+            tree[index] = Op::combine(&tree[2 * index], &tree[2 * index + 1]);
+        }
+        Self{ tree, len, operation : PhantomData }
+    }
+    pub fn len(&self) -> usize{
+        self.len
+    }
+    pub fn empty(&self) -> bool{
+        self.len == 0
+    }
+    pub fn get(&self , index : usize) -> Option<&T>{
+        (index < self.len).then(|| &self.tree[self.len + index])
+    }
+    ///Reemplaza la posición `index` y recalcula sus ancestros.
+    pub fn set(&mut self , index : usize , value : T){
+        assert!(index < self.len, "indice {} fuera de rango para largo {}", index, self.len);
+        let mut node: usize = self.len + index;
+        self.tree[node] = value;
+        while node > 1{
+            node /= 2;
+            self.tree[node] = Op::combine(&self.tree[2 * node], &self.tree[2 * node + 1]);
+        }
+    }
+    ///Combinación de los elementos del rango en orden, el neutro si está vacío.
+    pub fn query<R : RangeBounds<usize>>(&self , range : R) -> T{
+        let (start, end) = bounds(range, self.len);
+        let (mut left, mut right): (usize, usize) = (start + self.len, end + self.len);
+        //Acumuladores separados para no alterar el orden de una operación no conmutativa
+        let (mut prefix, mut suffix): (T, T) = (Op::identity(), Op::identity());
+        while left < right{
+            if left % 2 == 1{
+                prefix = Op::combine(&prefix, &self.tree[left]);
+                left += 1;
+            }
+            if right % 2 == 1{
+                right -= 1;
+                suffix = Op::combine(&self.tree[right], &suffix);
+            }
+            left /= 2;
+            right /= 2;
+        }
+        Op::combine(&prefix, &suffix)
+    }
+}
+impl<T : Clone , Op : Monoid<T>> FromIterator<T> for SegmentTree<T, Op>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        Self::from_slice(&iter.into_iter().collect::<Vec<T>>())
+    }
+}
+impl<T : Debug, Op> Debug for SegmentTree<T, Op>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_list().entries(self.tree[self.len..].iter()).finish()
+    }
+}
+///Actualización de rango de `LazySegmentTree`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeUpdate<T>{
+    ///Suma el valor a cada elemento del rango.
+    Add(T),
+    ///Reemplaza cada elemento del rango por el valor.
+    Assign(T)
+}
+impl<T : Clone + Add<Output = T>> RangeUpdate<T>{
+    ///Actualización equivalente a aplicar `self` y luego `next`.
+    fn then(self , next : RangeUpdate<T>) -> RangeUpdate<T>{
+        match (self, next){
+            (_, RangeUpdate::Assign(value)) => RangeUpdate::Assign(value),
+            (RangeUpdate::Add(first), RangeUpdate::Add(second)) => RangeUpdate::Add(first + second),
+            (RangeUpdate::Assign(value), RangeUpdate::Add(delta)) => RangeUpdate::Assign(value + delta)
+        }
+    }
+}
+///Monoides que saben aplicar una `RangeUpdate` a la combinación de un rango de largo `length` sin recorrerlo.
+pub trait LazyMonoid<T> : Monoid<T>{
+    fn apply(value : &T , update : &RangeUpdate<T> , length : usize) -> T;
+}
+impl<T : Zero + Clone + Mul<Output = T> + FromPrimitive> LazyMonoid<T> for Sum{
+    fn apply(value : &T , update : &RangeUpdate<T> , length : usize) -> T{
+        let length: T = T::from_usize(length).expect("el largo del rango debe ser representable en T");
+        match update{
+            RangeUpdate::Add(delta) => value.clone() + delta.clone() * length,
+            RangeUpdate::Assign(assigned) => assigned.clone() * length
+        }
+    }
+}
+impl<T : Bounded + Ord + Clone + Add<Output = T>> LazyMonoid<T> for Min{
+    fn apply(value : &T , update : &RangeUpdate<T> , _length : usize) -> T{
+        match update{
+            RangeUpdate::Add(delta) => value.clone() + delta.clone(),
+            RangeUpdate::Assign(assigned) => assigned.clone()
+        }
+    }
+}
+impl<T : Bounded + Ord + Clone + Add<Output = T>> LazyMonoid<T> for Max{
+    fn apply(value : &T , update : &RangeUpdate<T> , _length : usize) -> T{
+        match update{
+            RangeUpdate::Add(delta) => value.clone() + delta.clone(),
+            RangeUpdate::Assign(assigned) => assigned.clone()
+        }
+    }
+}
+///### LazySegmentTree
+/// El nodo `1` cubre `[0, n)` y sus hijos `2i` y `2i + 1` cada mitad. `pending[i]` es una actualización ya aplicada a
+/// `tree[i]` que aún no baja a sus hijos.
+pub struct LazySegmentTree<T, Op>{
+    tree : Vec<T>,
+    pending : Vec<Option<RangeUpdate<T>>>,
+    len : usize,
+    operation : PhantomData<Op>
+}
+impl<T : Clone + Add<Output = T> , Op : LazyMonoid<T>> LazySegmentTree<T, Op>{
+    ///`len` posiciones con el neutro, con `Min` o `Max` el neutro es el extremo del tipo y sumarle desborda, conviene
+    ///asignar antes.
+    pub fn new(len : usize) -> Self{
+        Self::from_slice(&vec![Op::identity(); len])
+    }
+    pub fn from_slice(values : &[T]) -> Self{
+        let mut tree: LazySegmentTree<T, Op> = Self{
+            tree : vec![Op::identity(); 4 * values.len().max(1)],
+            pending : vec![None; 4 * values.len().max(1)],
+            len : values.len(),
+            operation : PhantomData
+        };
+        if !values.is_empty(){
+            tree.build(1, 0, values.len(), values);
+        }
+        tree
+    }
+    fn build(&mut self , node : usize , start : usize , end : usize , values : &[T]){
+        if end - start == 1{
+            self.tree[node] = values[start].clone();
+            return;
+        }
+        let middle: usize = (start + end) / 2;
+        self.build(2 * node, start, middle, values);
+        self.build(2 * node + 1, middle, end, values);
+        self.tree[node] = Op::combine(&self.tree[2 * node], &self.tree[2 * node + 1]);
+    }
+    pub fn len(&self) -> usize{
+        self.len
+    }
+    pub fn empty(&self) -> bool{
+        self.len == 0
+    }
+    fn mark(&mut self , node : usize , length : usize , update : &RangeUpdate<T>){
+        self.tree[node] = Op::apply(&self.tree[node], update, length);
+        self.pending[node] = Some(match self.pending[node].take(){
+            Some(previous) => previous.then(update.clone()),
+            None => update.clone()
+        });
+    }
+    fn push_down(&mut self , node : usize , start : usize , end : usize){
+        if let Some(update) = self.pending[node].take(){
+            let middle: usize = (start + end) / 2;
+            self.mark(2 * node, middle - start, &update);
+            self.mark(2 * node + 1, end - middle, &update);
+        }
+    }
+    ///Aplica `update` a cada elemento del rango.
+    pub fn update<R : RangeBounds<usize>>(&mut self , range : R , update : RangeUpdate<T>){
+        let (from, to) = bounds(range, self.len);
+        if from < to{
+            self.update_node(1, 0, self.len, from, to, &update);
+        }
+    }
+    pub fn add<R : RangeBounds<usize>>(&mut self , range : R , delta : T){
+        self.update(range, RangeUpdate::Add(delta));
+    }
+    pub fn assign<R : RangeBounds<usize>>(&mut self , range : R , value : T){
+        self.update(range, RangeUpdate::Assign(value));
+    }
+    fn update_node(&mut self , node : usize , start : usize , end : usize , from : usize , to : usize , update : &RangeUpdate<T>){
+        if to <= start || end <= from{
+            return;
+        }
+        if from <= start && end <= to{
+            self.mark(node, end - start, update);
+            return;
+        }
+        self.push_down(node, start, end);
+        let middle: usize = (start + end) / 2;
+        self.update_node(2 * node, start, middle, from, to, update);
+        self.update_node(2 * node + 1, middle, end, from, to, update);
+        self.tree[node] = Op::combine(&self.tree[2 * node], &self.tree[2 * node + 1]);
+    }
+    ///Combinación de los elementos del rango en orden, el neutro si está vacío.
+    pub fn query<R : RangeBounds<usize>>(&mut self , range : R) -> T{
+        let (from, to) = bounds(range, self.len);
+        if from == to{
+            return Op::identity();
+        }
+        self.query_node(1, 0, self.len, from, to)
+    }
+    fn query_node(&mut self , node : usize , start : usize , end : usize , from : usize , to : usize) -> T{
+        if to <= start || end <= from{
+            return Op::identity();
+        }
+        if from <= start && end <= to{
+            return self.tree[node].clone();
+        }
+        self.push_down(node, start, end);
+        let middle: usize = (start + end) / 2;
+        let left: T = self.query_node(2 * node, start, middle, from, to);
+        let right: T = self.query_node(2 * node + 1, middle, end, from, to);
+        Op::combine(&left, &right)
+    }
+    pub fn get(&mut self , index : usize) -> T{
+        self.query(index..=index)
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::random::XorShift64;
+
+    ///Concatenación de cadenas: asociativa pero no conmutativa.
+    struct Concat;
+    impl Monoid<String> for Concat{
+        fn identity() -> String{
+            String::new()
+        }
+        fn combine(left : &String , right : &String) -> String{
+            format!("{}{}", left, right)
+        }
+    }
+    #[test]
+    fn point_updates_match_brute_force(){
+        let mut rng: XorShift64 = XorShift64::new(31);
+        for len in [1, 2, 7, 16, 33]{
+            let mut values: Vec<i64> = (0..len).map(|_| (rng.next_u64() % 100) as i64 - 50).collect();
+            let mut sums: SegmentTree<i64, Sum> = SegmentTree::from_slice(&values);
+            let mut minimums: SegmentTree<i64, Min> = values.iter().copied().collect();
+            let mut maximums: SegmentTree<i64, Max> = SegmentTree::new(len);
+            for (index, &value) in values.iter().enumerate(){
+                maximums.set(index, value);
+            }
+            for _ in 0..200{
+                let (a, b) = (rng.next_u64() as usize % (len + 1), rng.next_u64() as usize % (len + 1));
+                let (start, end) = (a.min(b), a.max(b));
+                let slice: &[i64] = &values[start..end];
+                assert_eq!(sums.query(start..end) , slice.iter().sum::<i64>());
+                assert_eq!(minimums.query(start..end) , slice.iter().copied().min().unwrap_or(i64::MAX));
+                assert_eq!(maximums.query(start..end) , slice.iter().copied().max().unwrap_or(i64::MIN));
+                let index: usize = rng.next_u64() as usize % len;
+                let value: i64 = (rng.next_u64() % 100) as i64 - 50;
+                values[index] = value;
+                sums.set(index, value);
+                minimums.set(index, value);
+                maximums.set(index, value);
+            }
+            assert_eq!(sums.query(..) , values.iter().sum::<i64>());
+            assert_eq!(sums.get(len) , None);
+        }
+    }
+    #[test]
+    fn keeps_order_of_non_commutative_operations(){
+        let letters: Vec<String> = "abcdefghijk".chars().map(String::from).collect();
+        let mut tree: SegmentTree<String, Concat> = SegmentTree::from_slice(&letters);
+        assert_eq!(tree.query(2..9) , "cdefghi");
+        tree.set(4, String::from("E"));
+        assert_eq!(tree.query(..=5) , "abcdEf");
+        assert_eq!(tree.query(3..3) , "");
+    }
+    #[test]
+    #[should_panic]
+    fn range_out_of_bounds_panics(){
+        let tree: SegmentTree<i32, Sum> = SegmentTree::new(4);
+        tree.query(2..5);
+    }
+    #[test]
+    #[should_panic(expected = "attempted to index slice up to maximum usize")]
+    fn inclusive_range_to_usize_max_panics_without_overflow(){
+        let tree: SegmentTree<i32, Sum> = SegmentTree::new(4);
+        tree.query(1..=usize::MAX);
+    }
+    #[test]
+    fn lazy_updates_match_brute_force(){
+        let mut rng: XorShift64 = XorShift64::new(47);
+        for len in [1, 5, 16, 37]{
+            let mut values: Vec<i64> = (0..len).map(|_| (rng.next_u64() % 100) as i64).collect();
+            let mut sums: LazySegmentTree<i64, Sum> = LazySegmentTree::from_slice(&values);
+            let mut minimums: LazySegmentTree<i64, Min> = LazySegmentTree::from_slice(&values);
+            let mut maximums: LazySegmentTree<i64, Max> = LazySegmentTree::from_slice(&values);
+            for _ in 0..300{
+                let (a, b) = (rng.next_u64() as usize % (len + 1), rng.next_u64() as usize % (len + 1));
+                let (start, end) = (a.min(b), a.max(b));
+                let amount: i64 = (rng.next_u64() % 21) as i64 - 10;
+                match rng.next_u64() % 3{
+                    0 => {
+                        for value in values[start..end].iter_mut(){
+                            *value += amount;
+                        }
+                        sums.add(start..end, amount);
+                        minimums.add(start..end, amount);
+                        maximums.add(start..end, amount);
+                    },
+                    1 => {
+                        for value in values[start..end].iter_mut(){
+                            *value = amount;
+                        }
+                        sums.assign(start..end, amount);
+                        minimums.assign(start..end, amount);
+                        maximums.assign(start..end, amount);
+                    },
+                    _ => {
+                        let slice: &[i64] = &values[start..end];
+                        assert_eq!(sums.query(start..end) , slice.iter().sum::<i64>());
+                        assert_eq!(minimums.query(start..end) , slice.iter().copied().min().unwrap_or(i64::MAX));
+                        assert_eq!(maximums.query(start..end) , slice.iter().copied().max().unwrap_or(i64::MIN));
+                    }
+                }
+            }
+            for (index, &value) in values.iter().enumerate(){
+                assert_eq!(sums.get(index) , value);
+            }
+        }
+        let mut empty: LazySegmentTree<i64, Sum> = LazySegmentTree::new(0);
+        assert_eq!(empty.query(..) , 0);
+    }
+}
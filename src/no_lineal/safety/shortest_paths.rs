@@ -0,0 +1,399 @@
+//! ### Caminos mínimos
+//! Todas las funciones reciben una función `weight` que extrae el peso `W` del dato de cada arista, así el mismo grafo
+//! puede medirse por distancia, tiempo o cualquier otro campo.
+//!
+//! | Algoritmo      | Pesos negativos        | Costo             | Estructura                  |
+//! |----------------|------------------------|-------------------|-----------------------------|
+//! | Dijkstra       | No                     | O((V + E) log V)  | `IndexedHeap::decrease_key` |
+//! | Bellman-Ford   | Si, detecta ciclos     | O(V * E)          |                             |
+//! | Floyd-Warshall | Si, detecta ciclos     | O(V³)             | matriz V x V                |
+//! | A*             | No                     | depende de `h`    | `IndexedHeap`               |
+//!
+//! Las respuestas guardan el predecesor `(nodo, arista)` de cada nodo, `path_to` recorre esos predecesores para
+//! reconstruir el camino completo.
+//! ```text
+//!   predecesores : A <- B <- D          path_to(D) = A -> B -> D
+//!                  A <- C
+//! ```
+use num::Zero;
+use super::binary_heap::{Handle, IndexedHeap};
+use super::graph::{EdgeId, Graph, GraphStorage, NodeId};
+use super::graph_traversal::walk_back;
+
+///Camino reconstruido, `nodes` tiene una entrada más que `edges`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<W>{
+    pub cost : W,
+    pub nodes : Vec<NodeId>,
+    pub edges : Vec<EdgeId>
+}
+///### ShortestPaths
+/// Distancias desde un origen y el arbol de predecesores que las realiza.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<W>{
+    source : NodeId,
+    distance : Vec<Option<W>>,
+    predecessor : Vec<Option<(NodeId, EdgeId)>>
+}
+impl<W : Copy> ShortestPaths<W>{
+    fn new(source : NodeId , bound : usize) -> Self{
+        Self{ source, distance : vec![None; bound], predecessor : vec![None; bound] }
+    }
+    pub fn source(&self) -> NodeId{
+        self.source
+    }
+    ///Costo del camino mínimo hasta `node`, `None` si no es alcanzable.
+    pub fn distance(&self , node : NodeId) -> Option<W>{
+        self.distance.get(node.index()).copied().flatten()
+    }
+    pub fn predecessor(&self , node : NodeId) -> Option<(NodeId, EdgeId)>{
+        self.predecessor.get(node.index()).copied().flatten()
+    }
+    pub fn path_to(&self , node : NodeId) -> Option<Path<W>>{
+        let cost: W = self.distance(node)?;
+        let (nodes, edges) = walk_back(&self.predecessor, node);
+        Some(Path{ cost, nodes, edges })
+    }
+}
+///Ciclo de costo negativo en orden de recorrido, la ultima arista vuelve al primer nodo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeCycle{
+    pub nodes : Vec<NodeId>,
+    pub edges : Vec<EdgeId>
+}
+///### Dijkstra
+/// Caminos mínimos desde `source` con pesos no negativos. Cada nodo entra una sola vez al `IndexedHeap` y las mejoras
+/// posteriores usan `decrease_key`.
+/// # Errores
+/// Si alguna arista alcanzada tiene peso negativo.
+pub fn dijkstra<N, E, S, W, F>(graph : &Graph<N, E, S> , source : NodeId , weight : F) -> Result<ShortestPaths<W>, String>
+where S : GraphStorage , W : Copy + Ord + Zero , F : Fn(&E) -> W{
+    let mut paths: ShortestPaths<W> = ShortestPaths::new(source, graph.node_bound());
+    if !graph.contains_node(source){
+        return Ok(paths);
+    }
+    let mut heap: IndexedHeap<(W, NodeId)> = IndexedHeap::new_min();
+    let mut handles: Vec<Option<Handle>> = vec![None; graph.node_bound()];
+    let mut settled: Vec<bool> = vec![false; graph.node_bound()];
+    paths.distance[source.index()] = Some(W::zero());
+    handles[source.index()] = Some(heap.push((W::zero(), source)));
+    while let Some((_, (distance, node))) = heap.pop(){
+        handles[node.index()] = None;
+        settled[node.index()] = true;
+        for (next, edge, data) in graph.edges(node){
+            let step: W = weight(data);
+            if step < W::zero(){
+                return Err(format!("Dijkstra no admite pesos negativos, la arista {:?} lo es", edge));
+            }
+            if settled[next.index()]{
+                continue;
+            }
+            let candidate: W = distance + step;
+            if paths.distance[next.index()].is_some_and(|current| current <= candidate){
+                continue;
+            }
+            paths.distance[next.index()] = Some(candidate);
+            paths.predecessor[next.index()] = Some((node, edge));
+            match handles[next.index()]{
+                Some(handle) => heap.decrease_key(handle, (candidate, next))?,
+                None => handles[next.index()] = Some(heap.push((candidate, next)))
+            }
+        }
+    }
+    Ok(paths)
+}
+///### Bellman-Ford
+/// Caminos mínimos desde `source` admitiendo pesos negativos. Relaja todas las aristas hasta `V - 1` veces (o hasta
+/// que una ronda no cambie nada), si la ronda `V` aún mejora algo existe un ciclo negativo alcanzable.
+/// # Errores
+/// El ciclo negativo encontrado. Retroceder `V` predecesores desde el ultimo nodo relajado garantiza caer dentro de él.
+pub fn bellman_ford<N, E, S, W, F>(graph : &Graph<N, E, S> , source : NodeId , weight : F) -> Result<ShortestPaths<W>, NegativeCycle>
+where S : GraphStorage , W : Copy + Ord + Zero , F : Fn(&E) -> W{
+    let mut paths: ShortestPaths<W> = ShortestPaths::new(source, graph.node_bound());
+    if !graph.contains_node(source){
+        return Ok(paths);
+    }
+    paths.distance[source.index()] = Some(W::zero());
+    for round in 1..=graph.node_count(){
+        let mut relaxed: Option<NodeId> = None;
+        for node in graph.node_ids(){
+            let Some(distance) = paths.distance[node.index()] else { continue };
+            for (next, edge, data) in graph.edges(node){
+                let candidate: W = distance + weight(data);
+                if paths.distance[next.index()].is_none_or(|current| candidate < current){
+                    paths.distance[next.index()] = Some(candidate);
+                    paths.predecessor[next.index()] = Some((node, edge));
+                    relaxed = Some(next);
+                }
+            }
+        }
+        let Some(last) = relaxed else { break };
+        if round == graph.node_count(){
+            return Err(negative_cycle(&paths.predecessor, last, graph.node_count()));
+        }
+    }
+    Ok(paths)
+}
+fn negative_cycle(predecessor : &[Option<(NodeId, EdgeId)>] , last : NodeId , steps : usize) -> NegativeCycle{
+    let mut start: NodeId = last;
+    for _ in 0..steps{
+        start = predecessor[start.index()].expect("un nodo dentro de un ciclo negativo siempre tiene predecesor").0;
+    }
+    let mut nodes: Vec<NodeId> = Vec::new();
+    let mut edges: Vec<EdgeId> = Vec::new();
+    let mut current: NodeId = start;
+    loop{
+        let (previous, edge) = predecessor[current.index()].expect("un nodo dentro de un ciclo negativo siempre tiene predecesor");
+        nodes.push(current);
+        edges.push(edge);
+        current = previous;
+        if current == start{
+            break;
+        }
+    }
+    //Se recorrió hacia atrás, al invertir edges[i] queda entrando a nodes[i] y se corre una posición para que salga
+    nodes.reverse();
+    edges.reverse();
+    edges.rotate_left(1);
+    NegativeCycle{ nodes, edges }
+}
+///### AllPairs
+/// Distancias entre todos los pares y la primera arista de cada camino mínimo.
+#[derive(Debug, Clone)]
+pub struct AllPairs<W>{
+    distance : Vec<Vec<Option<W>>>,
+    next : Vec<Vec<Option<(NodeId, EdgeId)>>>
+}
+impl<W : Copy + Zero> AllPairs<W>{
+    pub fn distance(&self , from : NodeId , to : NodeId) -> Option<W>{
+        self.distance.get(from.index())?.get(to.index()).copied().flatten()
+    }
+    pub fn path(&self , from : NodeId , to : NodeId) -> Option<Path<W>>{
+        let cost: W = self.distance(from, to)?;
+        let mut nodes: Vec<NodeId> = vec![from];
+        let mut edges: Vec<EdgeId> = Vec::new();
+        let mut current: NodeId = from;
+        while current != to{
+            let (next, edge) = self.next[current.index()][to.index()]?;
+            nodes.push(next);
+            edges.push(edge);
+            current = next;
+        }
+        Some(Path{ cost, nodes, edges })
+    }
+}
+///### Floyd-Warshall
+/// Caminos mínimos entre todos los pares en O(V³) tiempo y O(V²) memoria, admite pesos negativos.
+/// # Errores
+/// Si existe un ciclo negativo, en ese caso la distancia de algún nodo a si mismo queda negativa. Se retorna apenas
+/// ocurre porque cada pasada siguiente sumaría otra vuelta al ciclo y la distancia podría desbordar `W`.
+pub fn floyd_warshall<N, E, S, W, F>(graph : &Graph<N, E, S> , weight : F) -> Result<AllPairs<W>, String>
+where S : GraphStorage , W : Copy + Ord + Zero , F : Fn(&E) -> W{
+    let bound: usize = graph.node_bound();
+    let mut distance: Vec<Vec<Option<W>>> = vec![vec![None; bound]; bound];
+    let mut next: Vec<Vec<Option<(NodeId, EdgeId)>>> = vec![vec![None; bound]; bound];
+    let nodes: Vec<NodeId> = graph.node_ids().collect();
+    for &node in nodes.iter(){
+        distance[node.index()][node.index()] = Some(W::zero());
+        for (neighbor, edge, data) in graph.edges(node){
+            let step: W = weight(data);
+            if distance[node.index()][neighbor.index()].is_none_or(|current| step < current){
+                distance[node.index()][neighbor.index()] = Some(step);
+                next[node.index()][neighbor.index()] = Some((neighbor, edge));
+            }
+        }
+    }
+    let negative_cycle = |node : NodeId| format!("el grafo tiene un ciclo negativo que pasa por {:?}", node);
+    for &middle in nodes.iter(){
+        //Un lazo negativo en `middle` ya es un ciclo y usarlo como intermedio duplicaría su costo
+        if distance[middle.index()][middle.index()].is_some_and(|cost| cost < W::zero()){
+            return Err(negative_cycle(middle));
+        }
+        for &from in nodes.iter(){
+            let Some(first) = distance[from.index()][middle.index()] else { continue };
+            for &to in nodes.iter(){
+                let Some(second) = distance[middle.index()][to.index()] else { continue };
+                let cost: W = first + second;
+                if distance[from.index()][to.index()].is_none_or(|current| cost < current){
+                    if from == to && cost < W::zero(){
+                        return Err(negative_cycle(from));
+                    }
+                    distance[from.index()][to.index()] = Some(cost);
+                    next[from.index()][to.index()] = next[from.index()][middle.index()];
+                }
+            }
+        }
+    }
+    Ok(AllPairs{ distance, next })
+}
+///### A*
+/// Camino mínimo de `source` a `target` guiado por `heuristic`, que estima el costo restante hasta `target`. Si la
+/// heurística nunca sobreestima (admisible) el camino es óptimo, con `heuristic = 0` se comporta como Dijkstra.
+/// Un nodo ya cerrado se reabre si se encuentra un camino mejor, así las heurísticas admisibles pero inconsistentes
+/// también dan el óptimo.
+/// # Errores
+/// Si alguna arista alcanzada tiene peso negativo.
+pub fn astar<N, E, S, W, F, H>(graph : &Graph<N, E, S> , source : NodeId , target : NodeId , weight : F , heuristic : H) -> Result<Option<Path<W>>, String>
+where S : GraphStorage , W : Copy + Ord + Zero , F : Fn(&E) -> W , H : Fn(NodeId) -> W{
+    if !graph.contains_node(source) || !graph.contains_node(target){
+        return Ok(None);
+    }
+    let mut paths: ShortestPaths<W> = ShortestPaths::new(source, graph.node_bound());
+    let mut heap: IndexedHeap<(W, NodeId)> = IndexedHeap::new_min();
+    let mut handles: Vec<Option<Handle>> = vec![None; graph.node_bound()];
+    paths.distance[source.index()] = Some(W::zero());
+    handles[source.index()] = Some(heap.push((heuristic(source), source)));
+    while let Some((_, (_, node))) = heap.pop(){
+        handles[node.index()] = None;
+        if node == target{
+            return Ok(paths.path_to(target));
+        }
+        let distance: W = paths.distance[node.index()].expect("los nodos en el montículo tienen distancia");
+        for (next, edge, data) in graph.edges(node){
+            let step: W = weight(data);
+            if step < W::zero(){
+                return Err(format!("A* no admite pesos negativos, la arista {:?} lo es", edge));
+            }
+            let candidate: W = distance + step;
+            if paths.distance[next.index()].is_some_and(|current| current <= candidate){
+                continue;
+            }
+            paths.distance[next.index()] = Some(candidate);
+            paths.predecessor[next.index()] = Some((node, edge));
+            let estimate: W = candidate + heuristic(next);
+            match handles[next.index()]{
+                Some(handle) => heap.decrease_key(handle, (estimate, next))?,
+                None => handles[next.index()] = Some(heap.push((estimate, next)))
+            }
+        }
+    }
+    Ok(None)
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::no_lineal::safety::adjacency_matrix::AdjacencyMatrix;
+    use crate::random::XorShift64;
+
+    fn random_graph(seed : u64 , nodes : usize , edges : usize , directed : bool , min_weight : i64) -> Graph<usize, i64>{
+        let mut rng: XorShift64 = XorShift64::new(seed);
+        let mut graph: Graph<usize, i64> = if directed { Graph::directed() } else { Graph::undirected() };
+        let ids: Vec<NodeId> = (0..nodes).map(|value| graph.add_node(value)).collect();
+        for _ in 0..edges{
+            let from: NodeId = ids[rng.next_u64() as usize % nodes];
+            let to: NodeId = ids[rng.next_u64() as usize % nodes];
+            graph.add_edge(from, to, min_weight + (rng.next_u64() % 20) as i64).unwrap();
+        }
+        graph
+    }
+    ///Verifica que el camino sea continuo y que su costo sea la suma de sus aristas.
+    fn check_path<S : GraphStorage>(graph : &Graph<usize, i64, S> , path : &Path<i64>){
+        assert_eq!(path.nodes.len() , path.edges.len() + 1);
+        let mut cost: i64 = 0;
+        for (index, &edge) in path.edges.iter().enumerate(){
+            let (from, to) = graph.edge_endpoints(edge).unwrap();
+            let (current, next) = (path.nodes[index], path.nodes[index + 1]);
+            assert!((from, to) == (current, next) || !graph.is_directed() && (to, from) == (current, next));
+            cost += graph.edge(edge).unwrap();
+        }
+        assert_eq!(cost , path.cost);
+    }
+    #[test]
+    fn algorithms_agree_on_random_graphs(){
+        for seed in 1..=12{
+            let graph: Graph<usize, i64> = random_graph(seed, 30, 90, seed % 2 == 0, 0);
+            let all_pairs: AllPairs<i64> = floyd_warshall(&graph, |weight| *weight).unwrap();
+            for source in graph.node_ids().step_by(7){
+                let fast: ShortestPaths<i64> = dijkstra(&graph, source, |weight| *weight).unwrap();
+                let slow: ShortestPaths<i64> = bellman_ford(&graph, source, |weight| *weight).unwrap();
+                for target in graph.node_ids(){
+                    assert_eq!(fast.distance(target) , slow.distance(target));
+                    assert_eq!(fast.distance(target) , all_pairs.distance(source, target));
+                    let guided: Option<Path<i64>> = astar(&graph, source, target, |weight| *weight, |_| 0).unwrap();
+                    assert_eq!(guided.as_ref().map(|path| path.cost) , fast.distance(target));
+                    for path in [fast.path_to(target), all_pairs.path(source, target), guided].into_iter().flatten(){
+                        check_path(&graph, &path);
+                        assert_eq!((path.nodes[0], *path.nodes.last().unwrap()) , (source, target));
+                    }
+                }
+            }
+        }
+    }
+    #[test]
+    fn negative_weights(){
+        //Aristas negativas sin ciclos: solo de indices menores a mayores
+        let mut graph: Graph<usize, i64, AdjacencyMatrix> = Graph::directed();
+        let ids: Vec<NodeId> = (0..6).map(|value| graph.add_node(value)).collect();
+        for (from, to, weight) in [(0, 1, 4), (0, 2, 2), (2, 1, -3), (1, 3, 2), (3, 4, -1), (2, 4, 5), (4, 5, -2)]{
+            graph.add_edge(ids[from], ids[to], weight).unwrap();
+        }
+        assert!(dijkstra(&graph, ids[0], |weight| *weight).is_err());
+        let paths: ShortestPaths<i64> = bellman_ford(&graph, ids[0], |weight| *weight).unwrap();
+        assert_eq!(paths.distance(ids[5]) , Some(-2));
+        assert_eq!(paths.path_to(ids[5]).unwrap().nodes , vec![ids[0], ids[2], ids[1], ids[3], ids[4], ids[5]]);
+        let all_pairs: AllPairs<i64> = floyd_warshall(&graph, |weight| *weight).unwrap();
+        assert_eq!(all_pairs.distance(ids[2], ids[5]) , Some(-4));
+        assert_eq!(all_pairs.distance(ids[5], ids[0]) , None);
+        //Cerrar el ciclo 1 -> 3 -> 4 -> 1 con costo negativo
+        let closing: EdgeId = graph.add_edge(ids[4], ids[1], -2).unwrap();
+        let cycle: NegativeCycle = bellman_ford(&graph, ids[0], |weight| *weight).unwrap_err();
+        assert_eq!(cycle.nodes.len() , 3);
+        assert!(cycle.edges.contains(&closing));
+        let mut cost: i64 = 0;
+        for (index, &edge) in cycle.edges.iter().enumerate(){
+            let (from, to) = graph.edge_endpoints(edge).unwrap();
+            assert_eq!((from, to) , (cycle.nodes[index], cycle.nodes[(index + 1) % cycle.nodes.len()]));
+            cost += graph.edge(edge).unwrap();
+        }
+        assert!(cost < 0);
+        assert!(floyd_warshall(&graph, |weight| *weight).is_err());
+        //Un ciclo negativo no alcanzable no afecta
+        assert!(bellman_ford(&graph, ids[5], |weight| *weight).is_ok());
+    }
+    #[test]
+    fn floyd_warshall_stops_before_overflowing(){
+        //Ciclo 0 -> 1 -> ... -> 7 -> 0 de costo -24_000, cada camino simple cabe en i16 pero dar dos vueltas no
+        let mut graph: Graph<usize, i16> = Graph::directed();
+        let ids: Vec<NodeId> = (0..12).map(|value| graph.add_node(value)).collect();
+        for index in 0..8{
+            graph.add_edge(ids[index], ids[(index + 1) % 8], -3_000).unwrap();
+        }
+        //Nodos procesados despues del ciclo que seguirían sumando vueltas
+        for index in 8..12{
+            graph.add_edge(ids[index - 1], ids[index], 1).unwrap();
+            graph.add_edge(ids[index], ids[0], 1).unwrap();
+        }
+        assert!(floyd_warshall(&graph, |weight| *weight).is_err());
+    }
+    #[test]
+    fn astar_on_grid(){
+        const SIDE: i64 = 12;
+        let mut graph: Graph<(i64, i64), i64> = Graph::undirected();
+        let ids: Vec<NodeId> = (0..SIDE * SIDE).map(|cell| graph.add_node((cell / SIDE, cell % SIDE))).collect();
+        let mut rng: XorShift64 = XorShift64::new(99);
+        for row in 0..SIDE{
+            for column in 0..SIDE{
+                let cell: usize = (row * SIDE + column) as usize;
+                //Pesos de al menos 1 para que Manhattan sea admisible
+                if column + 1 < SIDE{
+                    graph.add_edge(ids[cell], ids[cell + 1], 1 + (rng.next_u64() % 5) as i64).unwrap();
+                }
+                if row + 1 < SIDE{
+                    graph.add_edge(ids[cell], ids[cell + SIDE as usize], 1 + (rng.next_u64() % 5) as i64).unwrap();
+                }
+            }
+        }
+        let target: NodeId = *ids.last().unwrap();
+        let (target_row, target_column) = *graph.node(target).unwrap();
+        let manhattan = |node : NodeId| {
+            let (row, column) = *graph.node(node).unwrap();
+            (target_row - row).abs() + (target_column - column).abs()
+        };
+        let path: Path<i64> = astar(&graph, ids[0], target, |weight| *weight, manhattan).unwrap().unwrap();
+        let expected: ShortestPaths<i64> = dijkstra(&graph, ids[0], |weight| *weight).unwrap();
+        assert_eq!(Some(path.cost) , expected.distance(target));
+        let mut disconnected: Graph<(), i64> = Graph::directed();
+        let (a, b) = (disconnected.add_node(()), disconnected.add_node(()));
+        assert_eq!(astar(&disconnected, a, b, |weight| *weight, |_| 0).unwrap() , None);
+    }
+}
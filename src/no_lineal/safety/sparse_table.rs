@@ -0,0 +1,96 @@
+//! ### Sobre las Sparse Table
+//! Para un arreglo que no cambia, una sparse table precalcula la combinación de cada rango de largo potencia de dos:
+//! `table[k][i]` combina `[i, i + 2^k)`. Se construye en O(n log n) a partir del nivel anterior.
+//! ```text
+//!   valores   :  5   2   7   1   8   3
+//!   k = 0     :  5   2   7   1   8   3
+//!   k = 1     :  2   2   1   1   3           min de [i, i + 2)
+//!   k = 2     :  1   1   1   1               min de [i, i + 4)
+//!
+//!   min [1, 6) = min(table[2][1], table[2][2]) = min([1, 5) , [2, 6))
+//! ```
+//! Cualquier rango `[l, r)` queda cubierto por los dos bloques de largo `2^k` (con `2^k <= r - l`) que empiezan en `l` y
+//! terminan en `r`. Se solapan, por eso la operación debe ser idempotente (`combine(a, a) == a`) y la consulta es O(1).
+use std::marker::PhantomData;
+use std::ops::RangeBounds;
+use std::fmt::{self, Debug};
+use num::Bounded;
+use super::segment_tree::{bounds, Monoid, Min, Max};
+
+///Monoides donde combinar un elemento consigo mismo no lo cambia, contar dos veces una posición no altera el resultado.
+pub trait Idempotent<T> : Monoid<T>{}
+impl<T : Bounded + Ord + Clone> Idempotent<T> for Min{}
+impl<T : Bounded + Ord + Clone> Idempotent<T> for Max{}
+
+///### SparseTable
+pub struct SparseTable<T, Op>{
+    ///`table[k]` tiene `len - 2^k + 1` entradas.
+    table : Vec<Vec<T>>,
+    operation : PhantomData<Op>
+}
+impl<T : Clone , Op : Idempotent<T>> SparseTable<T, Op>{
+    pub fn from_slice(values : &[T]) -> Self{
+        let mut table: Vec<Vec<T>> = vec![values.to_vec()];
+        let mut width: usize = 1;
+        while 2 * width <= values.len(){
+            let previous: &Vec<T> = &table[table.len() - 1];
+            let level: Vec<T> = (0..=values.len() - 2 * width)
+                .map(|index| Op::combine(&previous[index], &previous[index + width]))
+                .collect();
+            table.push(level);
+            width *= 2;
+        }
+        Self{ table, operation : PhantomData }
+    }
+    pub fn len(&self) -> usize{
+        self.table[0].len()
+    }
+    pub fn empty(&self) -> bool{
+        self.len() == 0
+    }
+    pub fn get(&self , index : usize) -> Option<&T>{
+        self.table[0].get(index)
+    }
+    ///Combinación de los elementos del rango, el neutro si está vacío.
+    pub fn query<R : RangeBounds<usize>>(&self , range : R) -> T{
+        let (start, end) = bounds(range, self.len());
+        if start == end{
+            return Op::identity();
+        }
+        let level: usize = (end - start).ilog2() as usize;
+        Op::combine(&self.table[level][start], &self.table[level][end - (1 << level)])
+    }
+}
+impl<T : Clone , Op : Idempotent<T>> FromIterator<T> for SparseTable<T, Op>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        Self::from_slice(&iter.into_iter().collect::<Vec<T>>())
+    }
+}
+impl<T : Debug, Op> Debug for SparseTable<T, Op>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_list().entries(self.table[0].iter()).finish()
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::random::XorShift64;
+
+    #[test]
+    fn matches_brute_force(){
+        let mut rng: XorShift64 = XorShift64::new(21);
+        for len in [0, 1, 2, 3, 7, 8, 33, 100]{
+            let values: Vec<i32> = (0..len).map(|_| (rng.next_u64() % 1000) as i32 - 500).collect();
+            let minimum: SparseTable<i32, Min> = SparseTable::from_slice(&values);
+            let maximum: SparseTable<i32, Max> = values.iter().copied().collect();
+            assert_eq!(minimum.len() , len);
+            for start in 0..=len{
+                for end in start..=len{
+                    let window: &[i32] = &values[start..end];
+                    assert_eq!(minimum.query(start..end) , window.iter().copied().min().unwrap_or(i32::MAX));
+                    assert_eq!(maximum.query(start..end) , window.iter().copied().max().unwrap_or(i32::MIN));
+                }
+            }
+        }
+    }
+}
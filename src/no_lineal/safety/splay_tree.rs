@@ -0,0 +1,425 @@
+//! ### Sobre los arboles Splay
+//! Un splay tree es un BST sin información de balance: cada acceso (`search`, `insert`, `remove`) lleva el nodo
+//! accedido a la raíz con rotaciones, los mismos `simple_rotation_left/right` del AVL pero sin alturas. Una operación
+//! aislada puede costar O(n), pero cualquier secuencia de `m` operaciones cuesta O(m log(n)) (costo amortizado), y los
+//! valores consultados seguido quedan cerca de la raíz, ideal para patrones de acceso tipo cache.
+//!
+//! ###### Splay top-down
+//! En lugar de bajar y luego subir rotando, se baja una sola vez partiendo el arbol en tres: un arbol izquierdo `L` con
+//! los valores menores a la clave, un arbol derecho `R` con los mayores y el arbol del medio `t` que se sigue recorriendo.
+//! - `zig`: el hijo en dirección de la clave es el buscado, `t` se cuelga de `R` (o `L`) y se baja al hijo.
+//! - `zig-zig`: la clave sigue en la misma dirección dos veces, primero se rota `t` y luego se cuelga.
+//! ```text
+//!          L            t             R                 L          t            R
+//!                       30                                         20          30
+//!                      /  \         buscar 10                     /  \        /
+//!                    20    40      ===========>                 10    25     ...  40
+//!                   /  \            (zig-zig)
+//!                 10    25
+//! ```
+//! Al terminar se reensambla: los hijos de `t` pasan a ser el maximo de `L` y el minimo de `R`, y `L`, `R` quedan como
+//! hijos de `t`.
+//!
+//! Sobre la raíz splayada `join` es O(1) más el splay, `split` además cuenta los nodos del arbol que separa.
+use std::cmp::Ordering;
+use std::fmt::{self, Debug};
+
+#[derive(Debug)]
+pub struct SplayNode<T>{
+    left : Option<Box<SplayNode<T>>>,
+    value : T,
+    right : Option<Box<SplayNode<T>>>
+}
+impl<T> SplayNode<T>{
+    pub fn new(value : T) -> Self{
+        Self{ left : None, value, right : None }
+    }
+}
+///### SplayTree
+/// Conjunto ordenado autoajustable, no acepta repetidos.
+pub struct SplayTree<T>{
+    root : Option<Box<SplayNode<T>>>,
+    size : usize
+}
+impl<T> SplayTree<T>{
+    pub fn new() -> Self{
+        Self{ root : None, size : 0 }
+    }
+    pub fn len(&self) -> usize{
+        self.size
+    }
+    pub fn empty(&self) -> bool{
+        self.size == 0
+    }
+    ///Valor en la raíz, tras un acceso es el último valor consultado (o su vecino si no existía).
+    pub fn root(&self) -> Option<&T>{
+        self.root.as_ref().map(|node| &node.value)
+    }
+    ///Altura del arbol (un solo nodo tiene altura 0), calculada por niveles para no depender de la recursión.
+    pub fn height(&self) -> Option<usize>{
+        let mut level: Vec<&SplayNode<T>> = self.root.iter().map(|node| node.as_ref()).collect();
+        let mut height: Option<usize> = None;
+        while !level.is_empty(){
+            height = Some(height.map_or(0, |h| h + 1));
+            level = level.iter().flat_map(|node| node.left.iter().chain(node.right.iter())).map(|node| node.as_ref()).collect();
+        }
+        height
+    }
+    ///### Rotación simple a la derecha
+    /// Igual que en `AVLTree::simple_rotation_right`: el hijo izquierdo sube, el nodo baja a su derecha y el subarbol
+    /// derecho del hijo pasa a ser el izquierdo del nodo.
+    /// ```text
+    ///        y              x
+    ///       / \            / \
+    ///      x   C   ==>    A   y
+    ///     / \                / \
+    ///    A   B              B   C
+    /// ```
+    pub(crate) fn simple_rotation_right(mut node : Option<Box<SplayNode<T>>>) -> Option<Box<SplayNode<T>>>{
+        match node{
+            None => None,
+            Some(ref mut n) => {
+                let mut left_child = n.left.take();
+                let split_subtree = left_child.as_mut().and_then(|left| left.right.take());
+                n.left = split_subtree;
+                match left_child{
+                    Some(ref mut left) => {
+                        left.right = node;
+                        left_child
+                    },
+                    //Sin hijo izquierdo no hay rotación
+                    None => node
+                }
+            }
+        }
+    }
+    ///### Rotación simple a la izquierda
+    /// Simétrica a `simple_rotation_right`.
+    /// ```text
+    ///      x                  y
+    ///     / \                / \
+    ///    A   y     ==>      x   C
+    ///       / \            / \
+    ///      B   C          A   B
+    /// ```
+    pub(crate) fn simple_rotation_left(mut node : Option<Box<SplayNode<T>>>) -> Option<Box<SplayNode<T>>>{
+        match node{
+            None => None,
+            Some(ref mut n) => {
+                let mut right_child = n.right.take();
+                let split_subtree = right_child.as_mut().and_then(|right| right.left.take());
+                n.right = split_subtree;
+                match right_child{
+                    Some(ref mut right) => {
+                        right.left = node;
+                        right_child
+                    },
+                    None => node
+                }
+            }
+        }
+    }
+    ///### Splay top-down
+    /// `direction` indica hacia donde está la clave buscada respecto a un valor del arbol (`Less` = a la izquierda).
+    /// Los nodos que se cuelgan de `L` y `R` se acumulan en vectores y se reensamblan al final, así no hacen falta
+    /// punteros al maximo de `L` ni al minimo de `R`.
+    fn splay_by(root : Option<Box<SplayNode<T>>> , direction : impl Fn(&T) -> Ordering) -> Option<Box<SplayNode<T>>>{
+        let mut t: Box<SplayNode<T>> = root?;
+        let mut left_tree: Vec<Box<SplayNode<T>>> = Vec::new();
+        let mut right_tree: Vec<Box<SplayNode<T>>> = Vec::new();
+        loop{
+            match direction(&t.value){
+                Ordering::Less => {
+                    let Some(left) = t.left.as_ref() else { break };
+                    if direction(&left.value) == Ordering::Less{
+                        //zig-zig: se rota antes de colgar
+                        t = Self::simple_rotation_right(Some(t)).expect("la rotación conserva la raíz");
+                        if t.left.is_none(){
+                            break;
+                        }
+                    }
+                    let left: Box<SplayNode<T>> = t.left.take().expect("se verificó el hijo izquierdo");
+                    right_tree.push(t);
+                    t = left;
+                },
+                Ordering::Greater => {
+                    let Some(right) = t.right.as_ref() else { break };
+                    if direction(&right.value) == Ordering::Greater{
+                        t = Self::simple_rotation_left(Some(t)).expect("la rotación conserva la raíz");
+                        if t.right.is_none(){
+                            break;
+                        }
+                    }
+                    let right: Box<SplayNode<T>> = t.right.take().expect("se verificó el hijo derecho");
+                    left_tree.push(t);
+                    t = right;
+                },
+                Ordering::Equal => break
+            }
+        }
+        //Cada nodo de L es el hijo derecho del anterior, el ultimo recibe el subarbol izquierdo de t
+        let mut assembled: Option<Box<SplayNode<T>>> = t.left.take();
+        for mut node in left_tree.into_iter().rev(){
+            node.right = assembled;
+            assembled = Some(node);
+        }
+        t.left = assembled;
+        let mut assembled: Option<Box<SplayNode<T>>> = t.right.take();
+        for mut node in right_tree.into_iter().rev(){
+            node.left = assembled;
+            assembled = Some(node);
+        }
+        t.right = assembled;
+        Some(t)
+    }
+    ///Lleva el minimo a la raíz y lo retorna.
+    pub fn min(&mut self) -> Option<&T>{
+        self.root = Self::splay_by(self.root.take(), |_| Ordering::Less);
+        self.root()
+    }
+    ///Lleva el maximo a la raíz y lo retorna.
+    pub fn max(&mut self) -> Option<&T>{
+        self.root = Self::splay_by(self.root.take(), |_| Ordering::Greater);
+        self.root()
+    }
+    ///Valores en orden ascendente, recorrido inorder con una pila propia.
+    pub fn inorder(&self) -> Vec<&T>{
+        let mut values: Vec<&T> = Vec::with_capacity(self.size);
+        let mut stack: Vec<&SplayNode<T>> = Vec::new();
+        let mut current: &Option<Box<SplayNode<T>>> = &self.root;
+        while current.is_some() || !stack.is_empty(){
+            while let Some(node) = current{
+                stack.push(node);
+                current = &node.left;
+            }
+            if let Some(node) = stack.pop(){
+                values.push(&node.value);
+                current = &node.right;
+            }
+        }
+        values
+    }
+    pub fn clear(&mut self){
+        *self = Self::new();
+    }
+}
+impl<T : Ord> SplayTree<T>{
+    fn splay(&mut self , value : &T){
+        self.root = Self::splay_by(self.root.take(), |current| value.cmp(current));
+    }
+    ///Busca el valor y lo deja en la raíz, si no existe queda en la raíz el ultimo nodo visitado.
+    pub fn search(&mut self , value : &T) -> Option<&T>{
+        self.splay(value);
+        self.root().filter(|root| *root == value)
+    }
+    ///Inserta el valor como nueva raíz, retorna `false` si ya existía.
+    /// ```text
+    ///   splay(x) deja a r en la raíz       x > r                x
+    ///              r                       ====>               / \
+    ///             / \                                         r   B
+    ///            A   B                                       /
+    ///                                                       A
+    /// ```
+    pub fn insert(&mut self , value : T) -> bool{
+        self.splay(&value);
+        let mut node: Box<SplayNode<T>> = Box::new(SplayNode::new(value));
+        match self.root.take(){
+            None => {},
+            Some(mut root) => match node.value.cmp(&root.value){
+                Ordering::Equal => {
+                    self.root = Some(root);
+                    return false;
+                },
+                Ordering::Less => {
+                    node.left = root.left.take();
+                    node.right = Some(root);
+                },
+                Ordering::Greater => {
+                    node.right = root.right.take();
+                    node.left = Some(root);
+                }
+            }
+        }
+        self.root = Some(node);
+        self.size += 1;
+        true
+    }
+    ///Elimina el valor y lo retorna, la raíz pasa a ser su predecesor (o su sucesor si no tenía).
+    pub fn remove(&mut self , value : &T) -> Option<T>{
+        self.search(value)?;
+        let mut root: Box<SplayNode<T>> = self.root.take().expect("search dejó el valor en la raíz");
+        let right: Option<Box<SplayNode<T>>> = root.right.take();
+        self.root = match Self::splay_by(root.left.take(), |_| Ordering::Greater){
+            //El maximo del lado izquierdo no tiene hijo derecho
+            Some(mut max) => {
+                max.right = right;
+                Some(max)
+            },
+            None => right
+        };
+        self.size -= 1;
+        Some(root.value)
+    }
+    ///### Split
+    /// Parte el arbol: `self` se queda con los valores `<= value` y se retorna un arbol con los mayores.
+    pub fn split(&mut self , value : &T) -> SplayTree<T>{
+        self.splay(value);
+        let Some(root) = self.root.as_mut() else { return SplayTree::new() };
+        let greater: Option<Box<SplayNode<T>>> = if root.value <= *value{
+            root.right.take()
+        }else {
+            //La raíz es el sucesor, ella y su subarbol derecho se van
+            let left: Option<Box<SplayNode<T>>> = root.left.take();
+            std::mem::replace(&mut self.root, left)
+        };
+        //Los nodos no guardan el tamaño de su subarbol, el arbol nuevo se cuenta en O(k)
+        let mut other: SplayTree<T> = SplayTree{ root : greater, size : 0 };
+        other.size = other.inorder().len();
+        self.size -= other.size;
+        other
+    }
+    ///### Join
+    /// Une dos arboles donde todos los valores de `self` son menores a los de `other`: se splaya el maximo de `self` y
+    /// `other` se cuelga como su hijo derecho.
+    pub fn join(&mut self , mut other : SplayTree<T>) -> Result<(), String>{
+        if let (Some(max), Some(min)) = (self.max(), other.min()){
+            if max >= min{
+                return Err(String::from("join requiere que todos los valores de self sean menores a los de other"));
+            }
+        }
+        match self.root.as_mut(){
+            Some(root) => root.right = other.root.take(),
+            None => self.root = other.root.take()
+        }
+        self.size += std::mem::take(&mut other.size);
+        Ok(())
+    }
+}
+impl<T> Default for SplayTree<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T : Debug> Debug for SplayTree<T>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_set().entries(self.inorder()).finish()
+    }
+}
+impl<T : Ord> FromIterator<T> for SplayTree<T>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        let mut tree: SplayTree<T> = SplayTree::new();
+        for value in iter{
+            tree.insert(value);
+        }
+        tree
+    }
+}
+///### Drop
+/// Insertar en orden deja un arbol degenerado de altura O(n), se libera con una pila propia como en `AVLTree`.
+impl<T> Drop for SplayTree<T>{
+    fn drop(&mut self){
+        let mut stack: Vec<Box<SplayNode<T>>> = Vec::new();
+        if let Some(root) = self.root.take(){
+            stack.push(root);
+        }
+        while let Some(mut node) = stack.pop(){
+            if let Some(left) = node.left.take(){
+                stack.push(left);
+            }
+            if let Some(right) = node.right.take(){
+                stack.push(right);
+            }
+        }
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use std::collections::BTreeSet;
+    use crate::random::XorShift64;
+
+    fn is_sorted(tree : &SplayTree<i32>) -> bool{
+        tree.inorder().windows(2).all(|pair| pair[0] < pair[1])
+    }
+    #[test]
+    fn accessed_values_end_at_the_root(){
+        let mut tree: SplayTree<i32> = (1..=100).collect();
+        //Cada inserción deja el valor nuevo en la raíz
+        assert_eq!(tree.root() , Some(&100));
+        for value in [37, 5, 99, 37, 64]{
+            assert_eq!(tree.search(&value) , Some(&value));
+            assert_eq!(tree.root() , Some(&value));
+        }
+        //Un valor ausente deja en la raíz a un vecino
+        assert_eq!(tree.search(&1_000) , None);
+        assert_eq!(tree.root() , Some(&100));
+        tree.insert(0);
+        assert_eq!(tree.root() , Some(&0));
+        assert_eq!(tree.min() , Some(&0));
+        assert_eq!(tree.max() , Some(&100));
+        assert!(is_sorted(&tree));
+    }
+    #[test]
+    fn splaying_shortens_a_degenerate_tree(){
+        //Insertar en orden creciente deja una cadena hacia la izquierda
+        let mut tree: SplayTree<i32> = (0..1_000).collect();
+        assert_eq!(tree.height() , Some(999));
+        //Acceder al extremo profundo reduce la altura aproximadamente a la mitad
+        tree.search(&0);
+        assert!(tree.height().unwrap() < 600);
+        assert_eq!(tree.len() , 1_000);
+        assert!(is_sorted(&tree));
+    }
+    #[test]
+    fn remove_leaves_the_neighbour_at_the_root(){
+        let mut tree: SplayTree<i32> = [50, 30, 70, 20, 40, 60, 80].into_iter().collect();
+        assert_eq!(tree.remove(&50) , Some(50));
+        assert_eq!(tree.root() , Some(&40));
+        assert_eq!(tree.remove(&50) , None);
+        assert_eq!(tree.remove(&20) , Some(20));
+        assert_eq!(tree.len() , 5);
+        assert_eq!(tree.inorder() , vec![&30, &40, &60, &70, &80]);
+    }
+    #[test]
+    fn split_and_join(){
+        let mut tree: SplayTree<i32> = (0..20).map(|v| v * 2).collect();
+        let mut greater: SplayTree<i32> = tree.split(&15);
+        assert_eq!(tree.len() , 8);
+        assert_eq!(greater.len() , 12);
+        assert_eq!(tree.max() , Some(&14));
+        assert_eq!(greater.min() , Some(&16));
+        let mut exact: SplayTree<i32> = greater.split(&30);
+        assert_eq!(greater.max() , Some(&30));
+        assert_eq!(exact.min() , Some(&32));
+        assert!(exact.join(SplayTree::from_iter([0])).is_err());
+        greater.join(exact).unwrap();
+        tree.join(greater).unwrap();
+        assert_eq!(tree.len() , 20);
+        assert!(tree.inorder().into_iter().copied().eq((0..20).map(|v| v * 2)));
+        let mut empty: SplayTree<i32> = SplayTree::new();
+        assert!(empty.split(&3).empty());
+        empty.join(tree).unwrap();
+        assert_eq!(empty.len() , 20);
+    }
+    #[test]
+    fn matches_btree_set_on_random_operations(){
+        let mut tree: SplayTree<i32> = SplayTree::new();
+        let mut expected: BTreeSet<i32> = BTreeSet::new();
+        let mut rng: XorShift64 = XorShift64::new(17);
+        for step in 0..5_000{
+            let value: i32 = (rng.next_u64() % 700) as i32;
+            match step % 4{
+                0 | 1 => assert_eq!(tree.insert(value) , expected.insert(value)),
+                2 => assert_eq!(tree.remove(&value).is_some() , expected.remove(&value)),
+                _ => assert_eq!(tree.search(&value).is_some() , expected.contains(&value))
+            }
+        }
+        assert_eq!(tree.len() , expected.len());
+        assert!(tree.inorder().into_iter().eq(expected.iter()));
+    }
+    #[test]
+    fn drop_degenerate_tree(){
+        let tree: SplayTree<u32> = (0..200_000).collect();
+        assert_eq!(tree.len() , 200_000);
+    }
+}
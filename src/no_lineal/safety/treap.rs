@@ -0,0 +1,515 @@
+//! ### Sobre los Treaps
+//! Un treap (tree + heap) es un BST donde cada nodo recibe además una prioridad aleatoria, y el arbol se mantiene
+//! ordenado por valor (como un BST) y por prioridad (como un montículo de maximos) al mismo tiempo.
+//! ```text
+//!                  (40 , p=97)
+//!                 /           \
+//!         (20 , p=80)      (60 , p=91)
+//!         /        \               \
+//!  (10 , p=12)  (30 , p=55)     (70 , p=30)
+//! ```
+//! Con prioridades aleatorias la forma es la de un BST construido insertando en orden aleatorio, su altura esperada es
+//! O(log(n)) sin guardar alturas ni colores: llena el espacio entre `BinarySearchTree` (sin balance) y `AVLTree`
+//! (balance estricto).
+//!
+//! Todas las operaciones se escriben con dos primitivas:
+//! - `split`: parte el arbol en los valores que cumplen un predicado y el resto, bajando por un solo camino.
+//! - `merge`: une dos treaps donde todos los valores del primero son menores, la raíz es la de mayor prioridad.
+//!
+//! `ImplicitTreap` usa la misma idea sin clave: la posición de un nodo es el tamaño de lo que tiene a su izquierda, así
+//! funciona como una secuencia con insertar en una posición, invertir un rango y concatenar en O(log(n)).
+use std::fmt::{self, Debug};
+use std::ops::Range;
+use crate::random::XorShift64;
+
+type Link<T> = Option<Box<TreapNode<T>>>;
+
+#[derive(Debug)]
+pub struct TreapNode<T>{
+    value : T,
+    priority : u64,
+    left : Link<T>,
+    right : Link<T>
+}
+///### Treap
+/// Conjunto ordenado aleatorizado, no acepta repetidos.
+pub struct Treap<T>{
+    root : Link<T>,
+    size : usize,
+    rng : XorShift64
+}
+impl<T> Treap<T>{
+    pub fn new() -> Self{
+        Self::with_rng(XorShift64::from_entropy())
+    }
+    ///Misma semilla y mismas operaciones producen la misma forma.
+    pub fn with_seed(seed : u64) -> Self{
+        Self::with_rng(XorShift64::new(seed))
+    }
+    fn with_rng(rng : XorShift64) -> Self{
+        Self{ root : None, size : 0, rng }
+    }
+    pub fn len(&self) -> usize{
+        self.size
+    }
+    pub fn empty(&self) -> bool{
+        self.size == 0
+    }
+    ///### Split
+    /// Retorna `(izquierda, derecha)` donde la izquierda tiene los valores que cumplen `goes_left`, el predicado debe ser
+    /// monotono respecto al orden (verdadero para un prefijo de los valores).
+    fn split_by(link : Link<T> , goes_left : &impl Fn(&T) -> bool) -> (Link<T>, Link<T>){
+        match link{
+            None => (None, None),
+            Some(mut node) => {
+                if goes_left(&node.value){
+                    let (left, right): (Link<T>, Link<T>) = Self::split_by(node.right.take(), goes_left);
+                    node.right = left;
+                    (Some(node), right)
+                }else {
+                    let (left, right): (Link<T>, Link<T>) = Self::split_by(node.left.take(), goes_left);
+                    node.left = right;
+                    (left, Some(node))
+                }
+            }
+        }
+    }
+    ///### Merge
+    /// Todos los valores de `left` deben ser menores a los de `right`, sube la raíz de mayor prioridad.
+    fn merge_links(left : Link<T> , right : Link<T>) -> Link<T>{
+        match (left, right){
+            (None, right) => right,
+            (left, None) => left,
+            (Some(mut left), Some(mut right)) => {
+                if left.priority > right.priority{
+                    left.right = Self::merge_links(left.right.take(), Some(right));
+                    Some(left)
+                }else {
+                    right.left = Self::merge_links(Some(left), right.left.take());
+                    Some(right)
+                }
+            }
+        }
+    }
+    fn count(link : &Link<T>) -> usize{
+        link.as_ref().map_or(0, |node| 1 + Self::count(&node.left) + Self::count(&node.right))
+    }
+    ///Altura del arbol, un solo nodo tiene altura 0.
+    pub fn height(&self) -> Option<usize>{
+        fn height<T>(link : &Link<T>) -> Option<usize>{
+            link.as_ref().map(|node| height(&node.left).max(height(&node.right)).map_or(0, |h| h + 1))
+        }
+        height(&self.root)
+    }
+    pub fn min(&self) -> Option<&T>{
+        let mut node: &TreapNode<T> = self.root.as_deref()?;
+        while let Some(left) = node.left.as_deref(){
+            node = left;
+        }
+        Some(&node.value)
+    }
+    pub fn max(&self) -> Option<&T>{
+        let mut node: &TreapNode<T> = self.root.as_deref()?;
+        while let Some(right) = node.right.as_deref(){
+            node = right;
+        }
+        Some(&node.value)
+    }
+    ///Valores en orden ascendente.
+    pub fn inorder(&self) -> Vec<&T>{
+        let mut values: Vec<&T> = Vec::with_capacity(self.size);
+        let mut stack: Vec<&TreapNode<T>> = Vec::new();
+        let mut current: &Link<T> = &self.root;
+        while current.is_some() || !stack.is_empty(){
+            while let Some(node) = current{
+                stack.push(node);
+                current = &node.left;
+            }
+            if let Some(node) = stack.pop(){
+                values.push(&node.value);
+                current = &node.right;
+            }
+        }
+        values
+    }
+    ///Verifica que cada nodo tenga prioridad mayor o igual a la de sus hijos.
+    #[cfg(test)]
+    pub(crate) fn is_heap_ordered(&self) -> bool{
+        fn check<T>(link : &Link<T> , parent : u64) -> bool{
+            link.as_ref().is_none_or(|node| node.priority <= parent && check(&node.left, node.priority) && check(&node.right, node.priority))
+        }
+        check(&self.root, u64::MAX)
+    }
+}
+impl<T : Ord> Treap<T>{
+    pub fn contains(&self , value : &T) -> bool{
+        let mut current: &Link<T> = &self.root;
+        while let Some(node) = current{
+            match value.cmp(&node.value){
+                std::cmp::Ordering::Equal => return true,
+                std::cmp::Ordering::Less => current = &node.left,
+                std::cmp::Ordering::Greater => current = &node.right
+            }
+        }
+        false
+    }
+    ///Inserta el valor, retorna `false` si ya existía.
+    /// ```text
+    ///   split(< x)  ==>  L , R        merge(merge(L , x) , R)
+    /// ```
+    pub fn insert(&mut self , value : T) -> bool{
+        if self.contains(&value){
+            return false;
+        }
+        let (left, right): (Link<T>, Link<T>) = Self::split_by(self.root.take(), &|v: &T| *v < value);
+        let node: Link<T> = Some(Box::new(TreapNode{ value, priority : self.rng.next_u64(), left : None, right : None }));
+        self.root = Self::merge_links(Self::merge_links(left, node), right);
+        self.size += 1;
+        true
+    }
+    ///Elimina el valor y lo retorna: se aisla con dos `split` y los lados se vuelven a unir con `merge`.
+    pub fn remove(&mut self , value : &T) -> Option<T>{
+        let (left, rest): (Link<T>, Link<T>) = Self::split_by(self.root.take(), &|v: &T| v < value);
+        let (middle, right): (Link<T>, Link<T>) = Self::split_by(rest, &|v: &T| v <= value);
+        self.root = Self::merge_links(left, right);
+        let node: Box<TreapNode<T>> = middle?;
+        self.size -= 1;
+        Some(node.value)
+    }
+    ///### Split
+    /// `self` se queda con los valores `<= value` y se retorna un treap con los mayores.
+    pub fn split(&mut self , value : &T) -> Treap<T>{
+        let (left, right): (Link<T>, Link<T>) = Self::split_by(self.root.take(), &|v: &T| v <= value);
+        let moved: usize = Self::count(&right);
+        self.root = left;
+        self.size -= moved;
+        Treap{ root : right, size : moved, rng : XorShift64::new(self.rng.next_u64()) }
+    }
+    ///### Merge
+    /// Une dos treaps donde todos los valores de `self` son menores a los de `other`.
+    pub fn merge(&mut self , mut other : Treap<T>) -> Result<(), String>{
+        if let (Some(max), Some(min)) = (self.max(), other.min()){
+            if max >= min{
+                return Err(String::from("merge requiere que todos los valores de self sean menores a los de other"));
+            }
+        }
+        self.root = Self::merge_links(self.root.take(), other.root.take());
+        self.size += other.size;
+        Ok(())
+    }
+}
+impl<T> Default for Treap<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T : Debug> Debug for Treap<T>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_set().entries(self.inorder()).finish()
+    }
+}
+impl<T : Ord> FromIterator<T> for Treap<T>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        let mut treap: Treap<T> = Treap::new();
+        for value in iter{
+            treap.insert(value);
+        }
+        treap
+    }
+}
+
+type ImplicitLink<T> = Option<Box<ImplicitNode<T>>>;
+
+#[derive(Debug)]
+pub struct ImplicitNode<T>{
+    value : T,
+    priority : u64,
+    ///Cantidad de nodos del subarbol, define la posición de cada nodo.
+    size : usize,
+    ///Inversión pendiente del subarbol (propagación perezosa).
+    reversed : bool,
+    left : ImplicitLink<T>,
+    right : ImplicitLink<T>
+}
+impl<T> ImplicitNode<T>{
+    fn size(link : &ImplicitLink<T>) -> usize{
+        link.as_ref().map_or(0, |node| node.size)
+    }
+    fn update(&mut self){
+        self.size = 1 + Self::size(&self.left) + Self::size(&self.right);
+    }
+    ///Aplica la inversión pendiente intercambiando los hijos y se la pasa a ellos.
+    fn push_down(&mut self){
+        if self.reversed{
+            std::mem::swap(&mut self.left, &mut self.right);
+            for child in [&mut self.left, &mut self.right].into_iter().flatten(){
+                child.reversed ^= true;
+            }
+            self.reversed = false;
+        }
+    }
+    ///Hijos en el orden real considerando las inversiones pendientes `flipped` de los ancestros y la propia.
+    fn children(&self , flipped : bool) -> (bool, &ImplicitLink<T>, &ImplicitLink<T>){
+        let flipped: bool = flipped ^ self.reversed;
+        if flipped{ (flipped, &self.right, &self.left) } else { (flipped, &self.left, &self.right) }
+    }
+}
+///### ImplicitTreap
+/// Secuencia indexada sobre un treap de clave implicita.
+/// ```text
+///   indices :   0   1   2   3   4            reverse(1..4)        0   1   2   3   4
+///   valores : [ a , b , c , d , e ]         =============>      [ a , d , c , b , e ]
+/// ```
+pub struct ImplicitTreap<T>{
+    root : ImplicitLink<T>,
+    rng : XorShift64
+}
+impl<T> ImplicitTreap<T>{
+    pub fn new() -> Self{
+        Self{ root : None, rng : XorShift64::from_entropy() }
+    }
+    pub fn with_seed(seed : u64) -> Self{
+        Self{ root : None, rng : XorShift64::new(seed) }
+    }
+    pub fn len(&self) -> usize{
+        ImplicitNode::size(&self.root)
+    }
+    pub fn empty(&self) -> bool{
+        self.root.is_none()
+    }
+    ///Separa los primeros `k` elementos del resto.
+    fn split_at(link : ImplicitLink<T> , k : usize) -> (ImplicitLink<T>, ImplicitLink<T>){
+        match link{
+            None => (None, None),
+            Some(mut node) => {
+                node.push_down();
+                let left_size: usize = ImplicitNode::size(&node.left);
+                if k <= left_size{
+                    let (left, right): (ImplicitLink<T>, ImplicitLink<T>) = Self::split_at(node.left.take(), k);
+                    node.left = right;
+                    node.update();
+                    (left, Some(node))
+                }else {
+                    let (left, right): (ImplicitLink<T>, ImplicitLink<T>) = Self::split_at(node.right.take(), k - left_size - 1);
+                    node.right = left;
+                    node.update();
+                    (Some(node), right)
+                }
+            }
+        }
+    }
+    fn merge_links(left : ImplicitLink<T> , right : ImplicitLink<T>) -> ImplicitLink<T>{
+        match (left, right){
+            (None, right) => right,
+            (left, None) => left,
+            (Some(mut left), Some(mut right)) => {
+                if left.priority > right.priority{
+                    left.push_down();
+                    left.right = Self::merge_links(left.right.take(), Some(right));
+                    left.update();
+                    Some(left)
+                }else {
+                    right.push_down();
+                    right.left = Self::merge_links(Some(left), right.left.take());
+                    right.update();
+                    Some(right)
+                }
+            }
+        }
+    }
+    ///Inserta `value` para que quede en la posición `index`, entra en pánico si `index > len`.
+    pub fn insert(&mut self , index : usize , value : T){
+        assert!(index <= self.len(), "indice {index} fuera de rango para un largo de {}", self.len());
+        let (left, right): (ImplicitLink<T>, ImplicitLink<T>) = Self::split_at(self.root.take(), index);
+        let node: ImplicitLink<T> = Some(Box::new(ImplicitNode{
+            value, priority : self.rng.next_u64(), size : 1, reversed : false, left : None, right : None
+        }));
+        self.root = Self::merge_links(Self::merge_links(left, node), right);
+    }
+    pub fn push_back(&mut self , value : T){
+        self.insert(self.len(), value);
+    }
+    ///Elimina y retorna el elemento de la posición `index`, entra en pánico si `index >= len`.
+    pub fn remove(&mut self , index : usize) -> T{
+        assert!(index < self.len(), "indice {index} fuera de rango para un largo de {}", self.len());
+        let (left, rest): (ImplicitLink<T>, ImplicitLink<T>) = Self::split_at(self.root.take(), index);
+        let (middle, right): (ImplicitLink<T>, ImplicitLink<T>) = Self::split_at(rest, 1);
+        self.root = Self::merge_links(left, right);
+        middle.expect("el rango se verificó").value
+    }
+    ///Elemento en la posición `index`, baja considerando las inversiones pendientes sin aplicarlas.
+    pub fn get(&self , mut index : usize) -> Option<&T>{
+        let mut current: &ImplicitLink<T> = &self.root;
+        let mut flipped: bool = false;
+        while let Some(node) = current{
+            let (node_flipped, left, right) = node.children(flipped);
+            let left_size: usize = ImplicitNode::size(left);
+            flipped = node_flipped;
+            match index.cmp(&left_size){
+                std::cmp::Ordering::Less => current = left,
+                std::cmp::Ordering::Equal => return Some(&node.value),
+                std::cmp::Ordering::Greater => {
+                    index -= left_size + 1;
+                    current = right;
+                }
+            }
+        }
+        None
+    }
+    ///### Reverse
+    /// Invierte el rango separandolo con dos `split_at` y marcando su raíz, O(log(n)).
+    pub fn reverse(&mut self , range : Range<usize>){
+        assert!(range.start <= range.end && range.end <= self.len(), "rango {range:?} invalido para un largo de {}", self.len());
+        let (left, rest): (ImplicitLink<T>, ImplicitLink<T>) = Self::split_at(self.root.take(), range.start);
+        let (mut middle, right): (ImplicitLink<T>, ImplicitLink<T>) = Self::split_at(rest, range.end - range.start);
+        if let Some(node) = middle.as_mut(){
+            node.reversed ^= true;
+        }
+        self.root = Self::merge_links(Self::merge_links(left, middle), right);
+    }
+    ///Agrega todos los elementos de `other` al final.
+    pub fn concat(&mut self , mut other : ImplicitTreap<T>){
+        self.root = Self::merge_links(self.root.take(), other.root.take());
+    }
+    ///Separa los elementos desde `index`, `self` se queda con los primeros `index`.
+    pub fn split_off(&mut self , index : usize) -> ImplicitTreap<T>{
+        assert!(index <= self.len(), "indice {index} fuera de rango para un largo de {}", self.len());
+        let (left, right): (ImplicitLink<T>, ImplicitLink<T>) = Self::split_at(self.root.take(), index);
+        self.root = left;
+        ImplicitTreap{ root : right, rng : XorShift64::new(self.rng.next_u64()) }
+    }
+    ///Elementos en orden de posición.
+    pub fn to_vec(&self) -> Vec<&T>{
+        let mut values: Vec<&T> = Vec::with_capacity(self.len());
+        let mut stack: Vec<(&ImplicitNode<T>, bool)> = Vec::new();
+        let mut current: Option<(&ImplicitNode<T>, bool)> = self.root.as_deref().map(|node| (node, false));
+        while current.is_some() || !stack.is_empty(){
+            while let Some((node, inherited)) = current{
+                let (flipped, left, _) = node.children(inherited);
+                stack.push((node, inherited));
+                current = left.as_deref().map(|child| (child, flipped));
+            }
+            if let Some((node, inherited)) = stack.pop(){
+                values.push(&node.value);
+                let (flipped, _, right) = node.children(inherited);
+                current = right.as_deref().map(|child| (child, flipped));
+            }
+        }
+        values
+    }
+}
+impl<T> Default for ImplicitTreap<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T : Debug> Debug for ImplicitTreap<T>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_list().entries(self.to_vec()).finish()
+    }
+}
+impl<T> FromIterator<T> for ImplicitTreap<T>{
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self{
+        let mut treap: ImplicitTreap<T> = ImplicitTreap::new();
+        for value in iter{
+            treap.push_back(value);
+        }
+        treap
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn treap_matches_btree_set(){
+        let mut treap: Treap<i32> = Treap::with_seed(3);
+        let mut expected: BTreeSet<i32> = BTreeSet::new();
+        let mut rng: XorShift64 = XorShift64::new(8);
+        for step in 0..5_000{
+            let value: i32 = (rng.next_u64() % 800) as i32;
+            if step % 3 == 2{
+                assert_eq!(treap.remove(&value).is_some() , expected.remove(&value));
+            }else {
+                assert_eq!(treap.insert(value) , expected.insert(value));
+            }
+        }
+        assert_eq!(treap.len() , expected.len());
+        assert!(treap.inorder().into_iter().eq(expected.iter()));
+        assert!(treap.is_heap_ordered());
+        assert!((0..800).all(|value| treap.contains(&value) == expected.contains(&value)));
+    }
+    #[test]
+    fn sorted_input_stays_shallow(){
+        //Un BinarySearchTree tendría altura 9_999 con esta entrada
+        let mut treap: Treap<u32> = Treap::with_seed(1);
+        for value in 0..10_000{
+            treap.insert(value);
+        }
+        assert!(treap.height().unwrap() < 60, "altura {:?}", treap.height());
+        assert!(treap.is_heap_ordered());
+    }
+    #[test]
+    fn split_and_merge(){
+        let mut treap: Treap<i32> = (0..50).collect();
+        let mut greater: Treap<i32> = treap.split(&19);
+        assert_eq!(treap.len() , 20);
+        assert_eq!(greater.len() , 30);
+        assert_eq!(treap.max() , Some(&19));
+        assert_eq!(greater.min() , Some(&20));
+        assert!(greater.merge(Treap::from_iter([5])).is_err());
+        treap.merge(greater).unwrap();
+        assert_eq!(treap.len() , 50);
+        assert!(treap.inorder().into_iter().copied().eq(0..50));
+        assert!(treap.is_heap_ordered());
+    }
+    #[test]
+    fn implicit_treap_matches_vec(){
+        let mut sequence: ImplicitTreap<u64> = ImplicitTreap::with_seed(5);
+        let mut expected: Vec<u64> = Vec::new();
+        let mut rng: XorShift64 = XorShift64::new(21);
+        for step in 0..3_000u64{
+            let len: usize = expected.len();
+            let a: usize = (rng.next_u64() as usize) % (len + 1);
+            let b: usize = (rng.next_u64() as usize) % (len + 1);
+            match step % 5{
+                0 | 1 => {
+                    sequence.insert(a, step);
+                    expected.insert(a, step);
+                },
+                2 if len > 0 => assert_eq!(sequence.remove(a % len) , expected.remove(a % len)),
+                _ => {
+                    let range: Range<usize> = a.min(b)..a.max(b);
+                    sequence.reverse(range.clone());
+                    expected[range].reverse();
+                }
+            }
+            if step % 100 == 0{
+                assert!(sequence.to_vec().into_iter().eq(expected.iter()));
+            }
+        }
+        assert_eq!(sequence.len() , expected.len());
+        assert!(sequence.to_vec().into_iter().eq(expected.iter()));
+        assert!((0..expected.len()).all(|index| sequence.get(index) == Some(&expected[index])));
+        assert_eq!(sequence.get(expected.len()) , None);
+    }
+    #[test]
+    fn implicit_concat_and_split_off(){
+        let mut first: ImplicitTreap<char> = "hola".chars().collect();
+        let second: ImplicitTreap<char> = " mundo".chars().collect();
+        first.concat(second);
+        first.reverse(0..4);
+        assert_eq!(first.to_vec().into_iter().collect::<String>() , "aloh mundo");
+        let tail: ImplicitTreap<char> = first.split_off(5);
+        assert_eq!(first.to_vec().into_iter().collect::<String>() , "aloh ");
+        assert_eq!(tail.to_vec().into_iter().collect::<String>() , "mundo");
+        assert_eq!(tail.get(0) , Some(&'m'));
+    }
+    #[test]
+    #[should_panic]
+    fn implicit_insert_out_of_range_panics(){
+        let mut sequence: ImplicitTreap<i32> = ImplicitTreap::new();
+        sequence.insert(1, 0);
+    }
+}
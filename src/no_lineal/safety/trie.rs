@@ -0,0 +1,261 @@
+//! ### Sobre los Tries (arboles de prefijos)
+//! Un trie guarda cadenas repartiendo sus caracteres por los niveles del arbol: cada arista es un carácter y la clave
+//! de un nodo es la concatenación de las aristas desde la raíz. Las claves que comparten prefijo comparten nodos.
+//! ```text
+//!   claves : "car" , "cas" , "cama" , "sol"
+//!
+//!              (raíz)
+//!             c/    \s
+//!            (a)     (o)
+//!        m/  r| \s     \l
+//!       (m)  [r] [s]   [l]          [x] = nodo con valor
+//!       a|
+//!       [a]
+//! ```
+//! Buscar, insertar o eliminar una clave de largo `m` cuesta O(m · σ) donde σ es la cantidad de hijos de cada nodo,
+//! los hijos se guardan ordenados por carácter para recorrer las claves en orden lexicográfico.
+//!
+//! Los nodos viven en un arreglo (arena) y se referencian por indice, los nodos liberados se reutilizan.
+use std::fmt::{self, Debug};
+
+const ROOT: usize = 0;
+
+#[derive(Debug, Clone)]
+struct TrieNode<V>{
+    ///Hijos ordenados por carácter.
+    children : Vec<(char, usize)>,
+    value : Option<V>
+}
+impl<V> TrieNode<V>{
+    fn new() -> Self{
+        Self{ children : Vec::new(), value : None }
+    }
+    fn child(&self , letter : char) -> Result<usize, usize>{
+        self.children.binary_search_by_key(&letter, |&(current, _)| current)
+    }
+}
+///### Trie
+/// Diccionario de cadenas a valores con consultas por prefijo.
+#[derive(Clone)]
+pub struct Trie<V>{
+    nodes : Vec<TrieNode<V>>,
+    free : Vec<usize>,
+    len : usize
+}
+impl<V> Default for Trie<V>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<V> Trie<V>{
+    pub fn new() -> Self{
+        Self{ nodes : vec![TrieNode::new()], free : Vec::new(), len : 0 }
+    }
+    ///Cantidad de claves.
+    pub fn len(&self) -> usize{
+        self.len
+    }
+    pub fn empty(&self) -> bool{
+        self.len == 0
+    }
+    ///Nodos en uso incluyendo la raíz, uno por cada prefijo distinto de las claves.
+    pub fn node_count(&self) -> usize{
+        self.nodes.len() - self.free.len()
+    }
+    pub fn clear(&mut self){
+        *self = Self::new();
+    }
+    fn allocate(&mut self) -> usize{
+        match self.free.pop(){
+            Some(index) => {
+                self.nodes[index] = TrieNode::new();
+                index
+            },
+            None => {
+                self.nodes.push(TrieNode::new());
+                self.nodes.len() - 1
+            }
+        }
+    }
+    ///Nodo cuya clave es exactamente `key`.
+    fn locate(&self , key : &str) -> Option<usize>{
+        let mut node: usize = ROOT;
+        for letter in key.chars(){
+            let position: usize = self.nodes[node].child(letter).ok()?;
+            node = self.nodes[node].children[position].1;
+        }
+        Some(node)
+    }
+    ///Inserta `value` en `key`, retorna el valor anterior si existía.
+    pub fn insert(&mut self , key : &str , value : V) -> Option<V>{
+        let mut node: usize = ROOT;
+        for letter in key.chars(){
+            node = match self.nodes[node].child(letter){
+                Ok(position) => self.nodes[node].children[position].1,
+                Err(position) => {
+                    let child: usize = self.allocate();
+                    self.nodes[node].children.insert(position, (letter, child));
+                    child
+                }
+            };
+        }
+        let previous: Option<V> = self.nodes[node].value.replace(value);
+        if previous.is_none(){
+            self.len += 1;
+        }
+        previous
+    }
+    pub fn get(&self , key : &str) -> Option<&V>{
+        self.nodes[self.locate(key)?].value.as_ref()
+    }
+    pub fn get_mut(&mut self , key : &str) -> Option<&mut V>{
+        let node: usize = self.locate(key)?;
+        self.nodes[node].value.as_mut()
+    }
+    pub fn contains_key(&self , key : &str) -> bool{
+        self.get(key).is_some()
+    }
+    ///Elimina `key` y poda los nodos que quedan sin valor ni hijos.
+    pub fn remove(&mut self , key : &str) -> Option<V>{
+        let mut path: Vec<(usize, usize)> = Vec::new();
+        let mut node: usize = ROOT;
+        for letter in key.chars(){
+            let position: usize = self.nodes[node].child(letter).ok()?;
+            path.push((node, position));
+            node = self.nodes[node].children[position].1;
+        }
+        let value: V = self.nodes[node].value.take()?;
+        self.len -= 1;
+        //Sube mientras el nodo actual quede vacío
+        while let Some((parent, position)) = path.pop(){
+            let child: usize = self.nodes[parent].children[position].1;
+            if self.nodes[child].value.is_some() || !self.nodes[child].children.is_empty(){
+                break;
+            }
+            self.nodes[parent].children.remove(position);
+            self.free.push(child);
+        }
+        Some(value)
+    }
+    ///Claves que empiezan con `prefix` en orden lexicográfico.
+    pub fn starts_with(&self , prefix : &str) -> Prefixed<'_, V>{
+        let stack: Vec<(usize, String)> = self.locate(prefix).map(|node| (node, prefix.to_string())).into_iter().collect();
+        Prefixed{ trie : self, stack }
+    }
+    ///Todas las claves en orden lexicográfico.
+    pub fn iter(&self) -> Prefixed<'_, V>{
+        self.starts_with("")
+    }
+    ///La clave más larga que es prefijo de `query`.
+    pub fn longest_prefix<'q>(&self , query : &'q str) -> Option<(&'q str, &V)>{
+        let mut node: usize = ROOT;
+        let mut best: Option<(&'q str, &V)> = self.nodes[ROOT].value.as_ref().map(|value| (&query[..0], value));
+        for (offset, letter) in query.char_indices(){
+            let Ok(position) = self.nodes[node].child(letter) else { break };
+            node = self.nodes[node].children[position].1;
+            if let Some(value) = self.nodes[node].value.as_ref(){
+                best = Some((&query[..offset + letter.len_utf8()], value));
+            }
+        }
+        best
+    }
+}
+///Recorrido en preorden de un subárbol, los hijos se apilan en orden inverso para salir de menor a mayor.
+pub struct Prefixed<'a, V>{
+    trie : &'a Trie<V>,
+    stack : Vec<(usize, String)>
+}
+impl<'a, V> Iterator for Prefixed<'a, V>{
+    type Item = (String, &'a V);
+    fn next(&mut self) -> Option<Self::Item>{
+        while let Some((node, key)) = self.stack.pop(){
+            let current: &'a TrieNode<V> = &self.trie.nodes[node];
+            for &(letter, child) in current.children.iter().rev(){
+                let mut extended: String = key.clone();
+                extended.push(letter);
+                self.stack.push((child, extended));
+            }
+            if let Some(value) = current.value.as_ref(){
+                return Some((key, value));
+            }
+        }
+        None
+    }
+}
+impl<V : Debug> Debug for Trie<V>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+impl<'k, V> FromIterator<(&'k str, V)> for Trie<V>{
+    fn from_iter<I : IntoIterator<Item = (&'k str, V)>>(iter : I) -> Self{
+        let mut trie: Trie<V> = Trie::new();
+        trie.extend(iter);
+        trie
+    }
+}
+impl<'k, V> Extend<(&'k str, V)> for Trie<V>{
+    fn extend<I : IntoIterator<Item = (&'k str, V)>>(&mut self , iter : I){
+        for (key, value) in iter{
+            self.insert(key, value);
+        }
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use std::collections::BTreeMap;
+    use crate::random::XorShift64;
+
+    #[test]
+    fn prefixes_and_order(){
+        let mut trie: Trie<u32> = [("car", 1), ("cas", 2), ("cama", 3), ("sol", 4), ("ñandú", 5)].into_iter().collect();
+        assert_eq!(trie.len() , 5);
+        assert_eq!(trie.get("cas") , Some(&2));
+        assert_eq!(trie.get("ca") , None);
+        assert_eq!(trie.insert("car", 10) , Some(1));
+        let keys: Vec<String> = trie.starts_with("ca").map(|(key, _)| key).collect();
+        assert_eq!(keys , vec!["cama", "car", "cas"]);
+        assert_eq!(trie.starts_with("x").count() , 0);
+        assert_eq!(trie.iter().last() , Some((String::from("ñandú"), &5)));
+        assert_eq!(trie.longest_prefix("carrera") , Some(("car", &10)));
+        assert_eq!(trie.longest_prefix("ca") , None);
+        assert_eq!(trie.longest_prefix("ñandúes") , Some(("ñandú", &5)));
+        *trie.get_mut("sol").unwrap() += 1;
+        assert_eq!(trie.remove("sol") , Some(5));
+        assert_eq!(trie.remove("sol") , None);
+        //Al borrar "cama" sobran sus nodos "m" y "a" pero "ca" sigue en uso
+        let before: usize = trie.node_count();
+        trie.remove("cama");
+        assert_eq!(trie.node_count() , before - 2);
+        assert!(trie.contains_key("cas"));
+        trie.insert("", 0);
+        assert_eq!(trie.longest_prefix("zzz") , Some(("", &0)));
+    }
+    #[test]
+    fn matches_btree_map(){
+        let mut rng: XorShift64 = XorShift64::new(8);
+        let mut trie: Trie<u64> = Trie::new();
+        let mut expected: BTreeMap<String, u64> = BTreeMap::new();
+        for _ in 0..3000{
+            let length: usize = (rng.next_u64() % 5) as usize;
+            let key: String = (0..length).map(|_| (b'a' + (rng.next_u64() % 3) as u8) as char).collect();
+            let value: u64 = rng.next_u64();
+            if rng.next_u64() % 3 == 0{
+                assert_eq!(trie.remove(&key) , expected.remove(&key));
+            }else{
+                assert_eq!(trie.insert(&key, value) , expected.insert(key, value));
+            }
+            assert_eq!(trie.len() , expected.len());
+        }
+        let all: Vec<(String, &u64)> = trie.iter().collect();
+        assert_eq!(all , expected.iter().map(|(key, value)| (key.clone(), value)).collect::<Vec<_>>());
+        let prefixed: Vec<String> = trie.starts_with("ab").map(|(key, _)| key).collect();
+        assert_eq!(prefixed , expected.keys().filter(|key| key.starts_with("ab")).cloned().collect::<Vec<String>>());
+        //Tras vaciarlo solo queda la raíz
+        for key in expected.keys(){
+            trie.remove(key);
+        }
+        assert_eq!((trie.len(), trie.node_count()) , (0, 1));
+    }
+}
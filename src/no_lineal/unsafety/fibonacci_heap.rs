@@ -0,0 +1,345 @@
+//! ### Montículo de Fibonacci con punteros crudos
+//! Mismo algoritmo que `safety::fibonacci_heap::FibonacciHeap` pero los nodos se enlazan con `*mut Node<T>` en lugar
+//! de indices de un arena, así `meld` solo une las dos listas de raíces sin copiar nodos.
+//!
+//! Para que un manejador nunca apunte a memoria liberada los nodos no se liberan al salir del montículo, se guardan en
+//! una lista de nodos libres y se reutilizan en los siguientes `push`. Cada reutilización incrementa la `generation`
+//! del nodo, un manejador solo es valido si su generación coincide con la del nodo y si el `HeapId` del montículo lo
+//! reconoce, de esta manera un manejador viejo o de otro montículo es rechazado sin desreferenciar memoria ajena.
+//! Toda la memoria se libera en el `Drop` del montículo.
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::{self, NonNull};
+use crate::no_lineal::safety::priority_queue::{HeapId, PriorityQueue};
+
+struct Node<T>{
+    value : Option<T>,
+    parent : *mut Node<T>,
+    child : *mut Node<T>,
+    left : *mut Node<T>,
+    right : *mut Node<T>,
+    degree : usize,
+    mark : bool,
+    generation : usize
+}
+///Manejador de un elemento de `FibonacciHeap`.
+pub struct Handle<T>{
+    heap : usize,
+    node : NonNull<Node<T>>,
+    generation : usize
+}
+impl<T> Clone for Handle<T>{
+    fn clone(&self) -> Self{
+        *self
+    }
+}
+impl<T> Copy for Handle<T>{}
+impl<T> PartialEq for Handle<T>{
+    fn eq(&self , other : &Self) -> bool{
+        self.heap == other.heap && self.node == other.node && self.generation == other.generation
+    }
+}
+impl<T> Eq for Handle<T>{}
+impl<T> Debug for Handle<T>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_struct("Handle").field("heap", &self.heap).field("node", &self.node).field("generation", &self.generation).finish()
+    }
+}
+///### FibonacciHeap
+/// Montículo de Fibonacci de minimos sobre punteros crudos.
+pub struct FibonacciHeap<T>{
+    min : *mut Node<T>,
+    len : usize,
+    //Todos los nodos reservados, vivos o libres, para liberarlos en el Drop
+    pool : Vec<NonNull<Node<T>>>,
+    free : Vec<NonNull<Node<T>>>,
+    id : HeapId,
+    _marker : PhantomData<Box<Node<T>>>
+}
+//SAFETY: el montículo es dueño exclusivo de todos sus nodos
+unsafe impl<T : Send> Send for FibonacciHeap<T>{}
+impl<T : Ord> Default for FibonacciHeap<T>{
+    fn default() -> Self{
+        Self::new()
+    }
+}
+impl<T : Ord> FibonacciHeap<T>{
+    pub fn new() -> Self{
+        Self{
+            min : ptr::null_mut(),
+            len : 0,
+            pool : Vec::new(),
+            free : Vec::new(),
+            id : HeapId::new(),
+            _marker : PhantomData
+        }
+    }
+    ///Cantidad de nodos reservados, incluye a los libres.
+    pub fn allocated(&self) -> usize{
+        self.pool.len()
+    }
+    ///Toma un nodo libre o reserva uno nuevo, el nodo queda enlazado consigo mismo.
+    fn allocate(&mut self , value : T) -> NonNull<Node<T>>{
+        let node: NonNull<Node<T>> = match self.free.pop() {
+            Some(node) => node,
+            None => {
+                let node: NonNull<Node<T>> = NonNull::from(Box::leak(Box::new(Node{
+                    value : None,
+                    parent : ptr::null_mut(),
+                    child : ptr::null_mut(),
+                    left : ptr::null_mut(),
+                    right : ptr::null_mut(),
+                    degree : 0,
+                    mark : false,
+                    generation : 0
+                })));
+                self.pool.push(node);
+                node
+            }
+        };
+        let raw: *mut Node<T> = node.as_ptr();
+        //SAFETY: el nodo pertenece al pool y no está enlazado en el montículo
+        unsafe {
+            (*raw).value = Some(value);
+            (*raw).parent = ptr::null_mut();
+            (*raw).child = ptr::null_mut();
+            (*raw).left = raw;
+            (*raw).right = raw;
+            (*raw).degree = 0;
+            (*raw).mark = false;
+        }
+        node
+    }
+    ///### Value
+    /// # Safety
+    /// `node` debe ser un nodo vivo de este montículo.
+    unsafe fn value<'a>(node : *mut Node<T>) -> &'a T{
+        (*node).value.as_ref().expect("Los nodos enlazados en el montículo siempre tienen valor")
+    }
+    fn resolve(&self , handle : Handle<T>) -> Option<*mut Node<T>>{
+        self.id.offset_of(handle.heap)?;
+        let node: *mut Node<T> = handle.node.as_ptr();
+        //SAFETY: el HeapId reconoce al manejador, por lo tanto el nodo está en el pool de este montículo y sigue reservado
+        let alive: bool = unsafe { (*node).generation == handle.generation && (*node).value.is_some() };
+        alive.then_some(node)
+    }
+    ///Une las listas circulares que contienen a `a` y a `b`.
+    unsafe fn splice(a : *mut Node<T> , b : *mut Node<T>){
+        let a_right: *mut Node<T> = (*a).right;
+        let b_left: *mut Node<T> = (*b).left;
+        (*a).right = b;
+        (*b).left = a;
+        (*b_left).right = a_right;
+        (*a_right).left = b_left;
+    }
+    unsafe fn unlink(node : *mut Node<T>){
+        (*(*node).left).right = (*node).right;
+        (*(*node).right).left = (*node).left;
+        (*node).left = node;
+        (*node).right = node;
+    }
+    unsafe fn add_root(&mut self , node : *mut Node<T>){
+        (*node).parent = ptr::null_mut();
+        if self.min.is_null(){
+            self.min = node;
+        }else {
+            Self::splice(self.min, node);
+            if Self::value(node) < Self::value(self.min){
+                self.min = node;
+            }
+        }
+    }
+    unsafe fn list(start : *mut Node<T>) -> Vec<*mut Node<T>>{
+        let mut nodes: Vec<*mut Node<T>> = vec![start];
+        let mut current: *mut Node<T> = (*start).right;
+        while current != start{
+            nodes.push(current);
+            current = (*current).right;
+        }
+        nodes
+    }
+    unsafe fn link(child : *mut Node<T> , parent : *mut Node<T>){
+        Self::unlink(child);
+        (*child).parent = parent;
+        (*child).mark = false;
+        if (*parent).child.is_null(){
+            (*parent).child = child;
+        }else {
+            Self::splice((*parent).child, child);
+        }
+        (*parent).degree += 1;
+    }
+    unsafe fn consolidate(&mut self , start : *mut Node<T>){
+        let mut by_degree: Vec<*mut Node<T>> = Vec::new();
+        for root in Self::list(start){
+            let mut node: *mut Node<T> = root;
+            let mut degree: usize = (*node).degree;
+            while degree < by_degree.len() && !by_degree[degree].is_null(){
+                let mut other: *mut Node<T> = mem::replace(&mut by_degree[degree], ptr::null_mut());
+                if Self::value(other) < Self::value(node){
+                    mem::swap(&mut node, &mut other);
+                }
+                Self::link(other, node);
+                degree += 1;
+            }
+            if by_degree.len() <= degree{
+                by_degree.resize(degree + 1, ptr::null_mut());
+            }
+            by_degree[degree] = node;
+        }
+        self.min = ptr::null_mut();
+        for root in by_degree.into_iter().filter(|root| !root.is_null()){
+            if self.min.is_null() || Self::value(root) < Self::value(self.min){
+                self.min = root;
+            }
+        }
+    }
+    unsafe fn cut(&mut self , node : *mut Node<T> , parent : *mut Node<T>){
+        if (*parent).child == node{
+            (*parent).child = if (*node).right == node { ptr::null_mut() } else { (*node).right };
+        }
+        Self::unlink(node);
+        (*parent).degree -= 1;
+        (*node).mark = false;
+        self.add_root(node);
+    }
+    unsafe fn cascading_cut(&mut self , mut node : *mut Node<T>){
+        while !(*node).parent.is_null(){
+            if !(*node).mark{
+                (*node).mark = true;
+                return;
+            }
+            let parent: *mut Node<T> = (*node).parent;
+            self.cut(node, parent);
+            node = parent;
+        }
+    }
+}
+impl<T : Ord> PriorityQueue<T> for FibonacciHeap<T>{
+    type Handle = Handle<T>;
+    fn push(&mut self , value : T) -> Handle<T>{
+        let node: NonNull<Node<T>> = self.allocate(value);
+        //SAFETY: el nodo recién preparado está enlazado consigo mismo
+        unsafe { self.add_root(node.as_ptr()) }
+        self.len += 1;
+        Handle{
+            heap : self.id.id(),
+            node,
+            //SAFETY: el nodo pertenece al pool
+            generation : unsafe { (*node.as_ptr()).generation }
+        }
+    }
+    fn pop_min(&mut self) -> Option<T>{
+        if self.min.is_null(){
+            return None;
+        }
+        let min: *mut Node<T> = self.min;
+        //SAFETY: min y todos los nodos alcanzables desde él son nodos vivos del pool
+        let value: Option<T> = unsafe {
+            let child: *mut Node<T> = mem::replace(&mut (*min).child, ptr::null_mut());
+            if !child.is_null(){
+                for node in Self::list(child){
+                    (*node).parent = ptr::null_mut();
+                }
+                Self::splice(min, child);
+            }
+            let next: *mut Node<T> = (*min).right;
+            Self::unlink(min);
+            if next == min{
+                self.min = ptr::null_mut();
+            }else {
+                self.consolidate(next);
+            }
+            //Los manejadores que apuntaban a este nodo quedan invalidados por la nueva generación
+            (*min).generation += 1;
+            (*min).degree = 0;
+            (*min).value.take()
+        };
+        self.free.push(NonNull::new(min).expect("min no es nulo"));
+        self.len -= 1;
+        value
+    }
+    fn peek_min(&self) -> Option<&T>{
+        //SAFETY: min es nulo o un nodo vivo, la referencia vive lo mismo que el préstamo de self
+        unsafe { self.min.as_ref().and_then(|min| min.value.as_ref()) }
+    }
+    fn meld(&mut self , mut other : Self){
+        //Los nodos de other pasan a este montículo, el Drop de other queda sin nada que liberar
+        self.pool.append(&mut other.pool);
+        self.free.append(&mut other.free);
+        let other_min: *mut Node<T> = mem::replace(&mut other.min, ptr::null_mut());
+        let other_id: HeapId = mem::replace(&mut other.id, HeapId::new());
+        self.id.absorb(other_id, 0);
+        self.len += mem::take(&mut other.len);
+        if other_min.is_null(){
+            return;
+        }
+        if self.min.is_null(){
+            self.min = other_min;
+            return;
+        }
+        //SAFETY: ambos minimos son raíces vivas que ahora pertenecen a este montículo
+        unsafe {
+            Self::splice(self.min, other_min);
+            if Self::value(other_min) < Self::value(self.min){
+                self.min = other_min;
+            }
+        }
+    }
+    fn decrease_key(&mut self , handle : Handle<T> , value : T) -> Result<(), String>{
+        let node: *mut Node<T> = self.resolve(handle).ok_or("El manejador no pertenece al montículo")?;
+        //SAFETY: resolve verificó que el nodo está vivo en este montículo
+        unsafe {
+            if value > *Self::value(node){
+                return Err(String::from("El nuevo valor es mayor al actual"));
+            }
+            (*node).value = Some(value);
+            let parent: *mut Node<T> = (*node).parent;
+            if !parent.is_null(){
+                if Self::value(node) < Self::value(parent){
+                    self.cut(node, parent);
+                    self.cascading_cut(parent);
+                }
+            }else if Self::value(node) < Self::value(self.min){
+                self.min = node;
+            }
+        }
+        Ok(())
+    }
+    fn len(&self) -> usize{
+        self.len
+    }
+}
+impl<T> Drop for FibonacciHeap<T>{
+    fn drop(&mut self){
+        for node in self.pool.drain(..){
+            //SAFETY: cada nodo del pool se reservo con Box y aparece una única vez
+            unsafe { drop(Box::from_raw(node.as_ptr())) }
+        }
+    }
+}
+impl<T : Debug> Debug for FibonacciHeap<T>{
+    fn fmt(&self , f : &mut fmt::Formatter<'_>) -> fmt::Result{
+        f.debug_struct("FibonacciHeap").field("len", &self.len).field("allocated", &self.pool.len()).finish_non_exhaustive()
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    crate::no_lineal::priority_queue_tests::priority_queue_tests!(FibonacciHeap);
+    #[test]
+    fn popped_nodes_are_reused(){
+        let mut heap : FibonacciHeap<i32> = FibonacciHeap::new();
+        let stale : Handle<i32> = heap.push(10);
+        heap.push(20);
+        heap.pop_min();
+        let fresh : Handle<i32> = heap.push(5);
+        assert_eq!(heap.allocated() , 2);
+        //El nodo se reutilizó pero la generación distingue al manejador viejo
+        assert_eq!(stale.node , fresh.node);
+        assert!(heap.decrease_key(stale, 0).is_err());
+        heap.decrease_key(fresh, 1).unwrap();
+        assert_eq!(heap.peek_min() , Some(&1));
+    }
+}
@@ -0,0 +1,34 @@
+//! ### XorShift64
+//! Las estructuras aleatorizadas (skip lists, treaps) necesitan una fuente de bits barata y, para las pruebas, reproducible
+//! a partir de una semilla. `xorshift64` de Marsaglia alcanza para eso, **no** es criptográficamente seguro.
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+#[derive(Debug, Clone)]
+pub(crate) struct XorShift64{
+    state : u64
+}
+impl XorShift64{
+    ///La semilla 0 es un punto fijo del generador, se reemplaza por una constante.
+    pub(crate) fn new(seed : u64) -> Self{
+        Self{ state : if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed } }
+    }
+    ///Semilla distinta en cada llamada, tomada de las claves aleatorias de `RandomState`.
+    pub(crate) fn from_entropy() -> Self{
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(0);
+        Self::new(hasher.finish())
+    }
+    pub(crate) fn next_u64(&mut self) -> u64{
+        let mut x: u64 = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+    ///Altura geometrica con p = 1/2 en `[1, max]`: cada bit en uno seguido suma un nivel.
+    pub(crate) fn level(&mut self , max : usize) -> usize{
+        (self.next_u64().trailing_ones() as usize + 1).min(max)
+    }
+}